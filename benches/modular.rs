@@ -0,0 +1,44 @@
+use std::{hint::black_box, time::Instant};
+
+use lk_math::modular::{Montgomery, mod_pow};
+
+const MODULUS: u64 = 1_000_000_007;
+const STEPS: u64 = 10_000_000;
+
+fn bench(name: &str, f: impl Fn() -> u64) {
+    let start = Instant::now();
+    let result = black_box(f());
+    let elapsed = start.elapsed();
+    println!(
+        "{name:>24}: {elapsed:>12?} ({:.2} ns/op, result {result})",
+        elapsed.as_nanos() as f64 / STEPS as f64
+    );
+}
+
+fn main() {
+    let x = black_box(123_456_789u64);
+
+    bench("naive multiplication", || {
+        let m = black_box(MODULUS);
+        let mut acc = 1u64;
+        for _ in 0..STEPS {
+            acc = (acc as u128 * x as u128 % m as u128) as u64;
+        }
+        acc
+    });
+
+    bench("montgomery multiplication", || {
+        let montgomery = Montgomery::new(black_box(MODULUS)).unwrap();
+        let x = montgomery.to_form(x);
+        let mut acc = montgomery.to_form(1);
+        for _ in 0..STEPS {
+            acc = montgomery.mul(acc, x);
+        }
+        montgomery.from_form(acc)
+    });
+
+    bench("mod_pow", || {
+        let m = black_box(MODULUS);
+        (0..STEPS / 64).fold(0, |acc, e| acc ^ mod_pow(x, e, m))
+    });
+}
@@ -1,7 +1,18 @@
-use std::ops::Sub;
+use std::ops::{Add, Mul, Sub};
 
-use super::{geometric_traits::CoverObject, vector::Vector};
+use super::{
+    geometric_traits::CoverObject, hilbert::HilbertPoints, linear_index::LinearIndex, math::Zero,
+    transformations::Transform, vector::Vector,
+};
 
+/// Axis-aligned bounding box.
+///
+/// Boxes are closed: both `min` and `max` are part of the box, for integer and float
+/// components alike. For integers this means `max` is the last cell *inside* the box
+/// (so a box covers `dim() + 1` cells along each axis), matching [`Aabb::covering`].
+/// For floats this means points exactly on the boundary are contained.
+///
+/// Consequently boxes that only share a face (or a corner) still intersect.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub struct Aabb<const C: usize, T> {
     pub min: Vector<C, T>,
@@ -18,15 +29,17 @@ impl<const C: usize, T: std::cmp::Ord + Copy> CoverObject<Vector<C, T>> for Aabb
     }
 }
 
+impl<const C: usize, T> Aabb<C, T> {
+    pub fn new(min: Vector<C, T>, max: Vector<C, T>) -> Self {
+        Self { min, max }
+    }
+}
+
 impl<const C: usize, T> Aabb<C, T>
 where
     T: std::cmp::Ord + Copy,
     Vector<C, T>: Sub<Output = Vector<C, T>>,
 {
-    pub fn new(min: Vector<C, T>, max: Vector<C, T>) -> Self {
-        Self { min, max }
-    }
-
     #[allow(dead_code)]
     pub fn covering(points: &[Vector<C, T>]) -> Option<Self> {
         let mut iter = points.iter();
@@ -47,13 +60,324 @@ where
     }
 }
 
+impl<const C: usize, T> Aabb<C, T>
+where
+    T: std::cmp::Ord + Copy,
+{
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut result = *self;
+        for x in 0..C {
+            result.min.values[x] = std::cmp::max(self.min.values[x], other.min.values[x]);
+            result.max.values[x] = std::cmp::min(self.max.values[x], other.max.values[x]);
+            if result.min.values[x] > result.max.values[x] {
+                return None;
+            }
+        }
+        Some(result)
+    }
+
+    /// Smallest box containing both boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = *self;
+        result.cover(&other.min);
+        result.cover(&other.max);
+        result
+    }
+}
+
+impl<const C: usize, T: Copy> Aabb<C, T> {
+    /// All `2^C` corners of the box. Bit `x` of the corner's index selects `max` on axis `x`.
+    pub fn corners(&self) -> impl Iterator<Item = Vector<C, T>> {
+        let (min, max) = (self.min, self.max);
+        (0..1usize << C).map(move |mask| {
+            let mut corner = min;
+            for x in 0..C {
+                if mask & (1 << x) != 0 {
+                    corner.values[x] = max.values[x];
+                }
+            }
+            corner
+        })
+    }
+}
+
+impl<const C: usize, T: PartialOrd + Copy> Aabb<C, T> {
+    /// Map all corners through `transform` and return the box bounding the result.
+    ///
+    /// For transforms that are not axis-aligned (e.g. rotations) the result is in general
+    /// larger than the tightest box around the transformed shape.
+    pub fn transformed<X: Transform<Vector<C, T>>>(&self, transform: &X) -> Self {
+        let mut corners = self.corners().map(|p| transform.apply(p));
+        let first = corners.next().unwrap();
+        let mut result = Self::new(first, first);
+        for p in corners {
+            for x in 0..C {
+                if p.values[x] < result.min.values[x] {
+                    result.min.values[x] = p.values[x];
+                }
+                if p.values[x] > result.max.values[x] {
+                    result.max.values[x] = p.values[x];
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<const C: usize, T: PartialOrd> Aabb<C, T> {
+    /// `min <= point <= max` on every axis.
+    pub fn contains_point(&self, point: &Vector<C, T>) -> bool {
+        (0..C)
+            .all(|x| self.min.values[x] <= point.values[x] && point.values[x] <= self.max.values[x])
+    }
+
+    pub fn contains_aabb(&self, other: &Self) -> bool {
+        (0..C).all(|x| {
+            self.min.values[x] <= other.min.values[x] && other.max.values[x] <= self.max.values[x]
+        })
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..C).all(|x| {
+            self.min.values[x] <= other.max.values[x] && other.min.values[x] <= self.max.values[x]
+        })
+    }
+}
+
+impl<const C: usize, T> Aabb<C, T>
+where
+    T: std::cmp::Ord + Copy,
+    T: Add<Output = T> + Sub<Output = T>,
+{
+    /// Grow the box by `lower` below `min` and by `upper` above `max`, per axis.
+    pub fn padded(&self, lower: Vector<C, T>, upper: Vector<C, T>) -> Self {
+        Self::new(self.min - lower, self.max + upper)
+    }
+
+    /// Grow the box by `margin` in every direction.
+    pub fn inflated(&self, margin: T) -> Self {
+        self.padded(Vector::all(margin), Vector::all(margin))
+    }
+
+    /// Minkowski sum, the box of all `a + b` with `a` in `self` and `b` in `other`.
+    pub fn minkowski_sum(&self, other: &Self) -> Self {
+        Self::new(self.min + other.min, self.max + other.max)
+    }
+
+    /// Minkowski sum with the reflection of `other`, the box of all `a - b`.
+    ///
+    /// `other` translated by `offset` intersects `self` exactly when `offset` is contained in
+    /// `self.expanded_by(other)`. This reduces box-vs-box collision (e.g. of a moving box) to a
+    /// point containment test (or a ray test, see [`Aabb::ray_intersection`]).
+    pub fn expanded_by(&self, other: &Self) -> Self {
+        Self::new(self.min - other.max, self.max - other.min)
+    }
+
+    /// Shrink the box by `margin` in every direction.
+    ///
+    /// Returns `None` if the box would invert (`min > max` on some axis).
+    pub fn deflated(&self, margin: T) -> Option<Self> {
+        let result = Self::new(
+            self.min + Vector::all(margin),
+            self.max - Vector::all(margin),
+        );
+        if (0..C).all(|x| result.min.values[x] <= result.max.values[x]) {
+            Some(result)
+        } else {
+            None
+        }
+    }
+}
+
+impl<const C: usize, T> Aabb<C, T>
+where
+    T: PartialOrd + Copy,
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Zero,
+{
+    /// Point of the box closest to `point` (componentwise clamp).
+    pub fn closest_point(&self, point: &Vector<C, T>) -> Vector<C, T> {
+        let mut result = *point;
+        for x in 0..C {
+            if result.values[x] < self.min.values[x] {
+                result.values[x] = self.min.values[x];
+            } else if result.values[x] > self.max.values[x] {
+                result.values[x] = self.max.values[x];
+            }
+        }
+        result
+    }
+
+    /// Squared euclidean distance from `point` to the box, zero if the point is inside.
+    pub fn distance_squared_to_point(&self, point: &Vector<C, T>) -> T {
+        let delta = self.closest_point(point) - *point;
+        delta.inner(delta)
+    }
+
+    /// Squared euclidean distance between the closest points of two boxes (like
+    /// [`Aabb::distance_squared_to_point`]), zero if they intersect.
+    pub fn distance_between_aabbs(&self, other: &Self) -> T {
+        let mut gap = Vector::<C, T>::all(T::zero());
+        for x in 0..C {
+            if other.min.values[x] > self.max.values[x] {
+                gap.values[x] = other.min.values[x] - self.max.values[x];
+            } else if self.min.values[x] > other.max.values[x] {
+                gap.values[x] = self.min.values[x] - other.max.values[x];
+            }
+        }
+        gap.inner(gap)
+    }
+}
+
+impl<const C: usize> Aabb<C, i32> {
+    // NOTE(lubo): Number of lattice points along each axis (zero for inverted boxes) and in
+    // total, None if that does not fit in a `usize`.
+    fn lattice_dims(&self) -> Option<(Vector<C, usize>, usize)> {
+        let mut dims = Vector::all(0);
+        for (d, (&a, &b)) in dims
+            .values
+            .iter_mut()
+            .zip(self.min.values.iter().zip(&self.max.values))
+        {
+            *d = usize::try_from((b as i64 - a as i64 + 1).max(0)).ok()?;
+        }
+        let count = dims.checked_cardinality()?;
+        Some((dims, count))
+    }
+
+    // NOTE(lubo): Inside the box, so the sum always fits in `i32`.
+    fn lattice_point(&self, local: Vector<C, usize>) -> Vector<C, i32> {
+        let mut p = self.min;
+        for (a, l) in p.values.iter_mut().zip(local.values) {
+            *a = (*a as i64 + l as i64) as i32;
+        }
+        p
+    }
+
+    /// Every lattice point inside the box (including the `max` corner), in linear index order.
+    ///
+    /// `None` when the number of points does not fit in a `usize`.
+    pub fn iter_points(&self) -> Option<impl Iterator<Item = Vector<C, i32>>> {
+        let aabb = *self;
+        let (dims, count) = self.lattice_dims()?;
+        Some((0..count).filter_map(move |i| Some(aabb.lattice_point(dims.unindex(i)?))))
+    }
+
+    /// Every lattice point inside the box in the order of the smallest covering
+    /// [`HilbertCurve`], nearby points stay close together in the sequence.
+    ///
+    /// `None` when the indices of that curve would not fit in `u128`.
+    pub fn iter_hilbert(&self) -> Option<HilbertPoints<C>> {
+        HilbertPoints::new(self)
+    }
+
+    /// Lattice points on the boundary of the box, in linear index order.
+    ///
+    /// `None` when the number of points inside the box does not fit in a `usize`.
+    pub fn iter_shell(&self) -> Option<impl Iterator<Item = Vector<C, i32>>> {
+        let aabb = *self;
+        let (dims, count) = self.lattice_dims()?;
+        let row_len = if C > 0 { dims.values[0] } else { 1 };
+        let rows = count.checked_div(row_len).unwrap_or(0);
+
+        // NOTE(lubo): Rows along the first axis either lie completely on the boundary,
+        // or only their two end points do.
+        Some((0..rows).flat_map(move |row| {
+            let start = row * row_len;
+            let on_boundary = dims.unindex(start).is_some_and(|p: Vector<C, usize>| {
+                (1..C).any(|x| p.values[x] == 0 || p.values[x] + 1 == dims.values[x])
+            });
+            let step = if on_boundary || row_len < 2 {
+                1
+            } else {
+                row_len - 1
+            };
+            (start..start + row_len)
+                .step_by(step)
+                .filter_map(move |i| Some(aabb.lattice_point(dims.unindex(i)?)))
+        }))
+    }
+}
+
+macro_rules! float_ray_intersection {
+    ($($t:ty),*) => {
+        $(
+        impl<const C: usize> Aabb<C, $t> {
+            /// Slab method. Returns the range of parameters `t` (entry, exit) for which
+            /// `origin + t * dir` lies inside the box, restricted to `t_range`.
+            ///
+            /// Axis-parallel rays (zero `dir` components) are handled explicitly, so no
+            /// `0 * inf` NaNs can appear.
+            fn clip(
+                &self,
+                origin: Vector<C, $t>,
+                dir: Vector<C, $t>,
+                t_range: ($t, $t),
+            ) -> Option<($t, $t)> {
+                let (mut t_min, mut t_max) = t_range;
+                for x in 0..C {
+                    let (o, d) = (origin.values[x], dir.values[x]);
+                    let (lo, hi) = (self.min.values[x], self.max.values[x]);
+                    if d == 0.0 {
+                        if o < lo || o > hi {
+                            return None;
+                        }
+                    } else {
+                        let inv = 1.0 / d;
+                        let mut t0 = (lo - o) * inv;
+                        let mut t1 = (hi - o) * inv;
+                        if t0 > t1 {
+                            std::mem::swap(&mut t0, &mut t1);
+                        }
+                        t_min = t_min.max(t0);
+                        t_max = t_max.min(t1);
+                        if t_min > t_max {
+                            return None;
+                        }
+                    }
+                }
+                Some((t_min, t_max))
+            }
+
+            /// Intersection of the ray `origin + t * dir` (`t >= 0`) with the box.
+            ///
+            /// Returns `(t_min, t_max)`, the parameters where the ray enters and leaves the box.
+            /// If `origin` is inside the box, `t_min` is `0`.
+            pub fn ray_intersection(
+                &self,
+                origin: Vector<C, $t>,
+                dir: Vector<C, $t>,
+            ) -> Option<($t, $t)> {
+                self.clip(origin, dir, (0.0, <$t>::INFINITY))
+            }
+
+            /// Intersection of the segment from `start` to `end` with the box.
+            ///
+            /// Returns `(t_min, t_max)` in `[0, 1]`, where `t = 0` is `start` and `t = 1` is `end`.
+            pub fn segment_intersection(
+                &self,
+                start: Vector<C, $t>,
+                end: Vector<C, $t>,
+            ) -> Option<($t, $t)> {
+                self.clip(start, end - start, (0.0, 1.0))
+            }
+        })*
+    };
+}
+
+float_ray_intersection!(f32, f64);
+
 pub type Aabb2<T> = Aabb<2, T>;
 pub type Aabb3<T> = Aabb<3, T>;
 pub type Aabb4<T> = Aabb<4, T>;
 
 #[cfg(test)]
 mod tests {
-    use crate::{aabb::Aabb2, vector::V2};
+    use crate::{
+        aabb::{Aabb, Aabb2, Aabb3},
+        symmetry::GridSymmetry,
+        transformations::Translation,
+        vector::{V2, V3, Vector},
+    };
 
     #[test]
     fn aabb_covering() {
@@ -64,4 +388,247 @@ mod tests {
             Aabb2::new(V2::from_xy(0, 0), V2::from_xy(2, 2))
         );
     }
+
+    #[test]
+    fn aabb_intersection_union() {
+        let a = Aabb2::new(V2::from_xy(0, 0), V2::from_xy(4, 4));
+        let b = Aabb2::new(V2::from_xy(2, -2), V2::from_xy(6, 2));
+        let c = Aabb2::new(V2::from_xy(5, 5), V2::from_xy(6, 6));
+        let d = Aabb2::new(V2::from_xy(4, 0), V2::from_xy(5, 1));
+
+        assert_eq!(
+            a.intersection(&b),
+            Some(Aabb2::new(V2::from_xy(2, 0), V2::from_xy(4, 2)))
+        );
+        assert_eq!(a.intersection(&b), b.intersection(&a));
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(
+            a.intersection(&d),
+            Some(Aabb2::new(V2::from_xy(4, 0), V2::from_xy(4, 1)))
+        );
+
+        assert!(a.intersects(&b));
+        assert!(a.intersects(&d));
+        assert!(!a.intersects(&c));
+        assert!(!c.intersects(&a));
+
+        assert_eq!(
+            a.union(&c),
+            Aabb2::new(V2::from_xy(0, 0), V2::from_xy(6, 6))
+        );
+        assert_eq!(
+            a.union(&b),
+            Aabb2::new(V2::from_xy(0, -2), V2::from_xy(6, 4))
+        );
+    }
+
+    #[test]
+    fn aabb_contains_aabb() {
+        let a = Aabb2::new(V2::from_xy(0, 0), V2::from_xy(4, 4));
+        let b = Aabb2::new(V2::from_xy(1, 1), V2::from_xy(4, 2));
+        assert!(a.contains_aabb(&a));
+        assert!(a.contains_aabb(&b));
+        assert!(!b.contains_aabb(&a));
+        assert!(a.union(&b).contains_aabb(&b));
+    }
+
+    #[test]
+    fn aabb_contains_point() {
+        let points = vec![V2::from_xy(0, 0), V2::from_xy(3, 2)];
+        let a = Aabb2::covering(&points).unwrap();
+        assert!(points.iter().all(|p| a.contains_point(p)));
+        assert!(a.contains_point(&V2::from_xy(3, 0)));
+        assert!(!a.contains_point(&V2::from_xy(4, 2)));
+        assert!(!a.contains_point(&V2::from_xy(3, 3)));
+        assert!(!a.contains_point(&V2::from_xy(-1, 0)));
+
+        let b = Aabb2::new(V2::from_xy(0.0, 0.0), V2::from_xy(1.0, 1.0));
+        assert!(b.contains_point(&V2::from_xy(1.0, 1.0)));
+        assert!(b.contains_point(&V2::from_xy(0.5, 0.0)));
+        assert!(!b.contains_point(&V2::from_xy(1.0, 1.0001)));
+        assert!(b.contains_aabb(&Aabb2::new(V2::from_xy(0.0, 0.5), V2::from_xy(1.0, 0.5))));
+    }
+
+    #[test]
+    fn aabb_closest_point() {
+        let a = Aabb2::new(V2::from_xy(0, 0), V2::from_xy(4, 2));
+        assert_eq!(a.closest_point(&V2::from_xy(1, 1)), V2::from_xy(1, 1));
+        assert_eq!(a.closest_point(&V2::from_xy(-3, 1)), V2::from_xy(0, 1));
+        assert_eq!(a.closest_point(&V2::from_xy(7, 6)), V2::from_xy(4, 2));
+
+        assert_eq!(a.distance_squared_to_point(&V2::from_xy(1, 1)), 0);
+        assert_eq!(a.distance_squared_to_point(&V2::from_xy(-3, 1)), 9);
+        assert_eq!(a.distance_squared_to_point(&V2::from_xy(7, 6)), 25);
+
+        let b = Aabb2::new(V2::from_xy(7, 6), V2::from_xy(8, 8));
+        let c = Aabb2::new(V2::from_xy(2, 1), V2::from_xy(8, 8));
+        assert_eq!(a.distance_between_aabbs(&b), 25);
+        assert_eq!(b.distance_between_aabbs(&a), 25);
+        assert_eq!(a.distance_between_aabbs(&c), 0);
+
+        let d = Aabb2::new(V2::from_xy(0.0, 0.0), V2::from_xy(1.0, 1.0));
+        assert_eq!(d.distance_squared_to_point(&V2::from_xy(0.5, 3.0)), 4.0);
+    }
+
+    #[test]
+    fn aabb_inflate_deflate() {
+        let a = Aabb2::new(V2::from_xy(0, 0), V2::from_xy(4, 2));
+        assert_eq!(
+            a.inflated(1),
+            Aabb2::new(V2::from_xy(-1, -1), V2::from_xy(5, 3))
+        );
+        assert_eq!(a.inflated(1).deflated(1), Some(a));
+        assert_eq!(
+            a.deflated(1),
+            Some(Aabb2::new(V2::from_xy(1, 1), V2::from_xy(3, 1)))
+        );
+        assert_eq!(a.deflated(2), None);
+        assert_eq!(
+            a.padded(V2::from_xy(0, 1), V2::from_xy(2, 0)),
+            Aabb2::new(V2::from_xy(0, -1), V2::from_xy(6, 2))
+        );
+    }
+
+    #[test]
+    fn aabb_minkowski() {
+        let a = Aabb2::new(V2::from_xy(0, 0), V2::from_xy(4, 2));
+        let b = Aabb2::new(V2::from_xy(-1, 0), V2::from_xy(1, 3));
+        assert_eq!(
+            a.minkowski_sum(&b),
+            Aabb2::new(V2::from_xy(-1, 0), V2::from_xy(5, 5))
+        );
+
+        let expanded = a.expanded_by(&b);
+        for x in -8..8 {
+            for y in -8..8 {
+                let offset = V2::from_xy(x, y);
+                let moved = Aabb2::new(b.min + offset, b.max + offset);
+                assert_eq!(a.intersects(&moved), expanded.contains_point(&offset));
+            }
+        }
+    }
+
+    #[test]
+    fn aabb_iter_points() {
+        let a = Aabb2::new(V2::from_xy(-1, 2), V2::from_xy(1, 3));
+        let points: Vec<_> = a.iter_points().unwrap().collect();
+        assert_eq!(
+            points,
+            vec![
+                V2::from_xy(-1, 2),
+                V2::from_xy(0, 2),
+                V2::from_xy(1, 2),
+                V2::from_xy(-1, 3),
+                V2::from_xy(0, 3),
+                V2::from_xy(1, 3),
+            ]
+        );
+
+        let inverted = Aabb2::new(V2::from_xy(1, 0), V2::from_xy(0, 0));
+        assert_eq!(inverted.iter_points().unwrap().count(), 0);
+        assert_eq!(inverted.iter_shell().unwrap().count(), 0);
+
+        let full = Aabb2::new(V2::all(i32::MIN), V2::all(i32::MAX));
+        assert!(full.iter_points().is_none());
+        assert!(full.iter_shell().is_none());
+    }
+
+    #[test]
+    fn aabb_iter_shell() {
+        let a = Aabb2::new(V2::from_xy(0, 0), V2::from_xy(3, 2));
+        let shell: Vec<_> = a.iter_shell().unwrap().collect();
+        assert_eq!(shell.len(), 10);
+        assert!(!shell.contains(&V2::from_xy(1, 1)));
+        assert!(!shell.contains(&V2::from_xy(2, 1)));
+
+        let b = Aabb3::new(V3::from_xyz(0, 0, 0), V3::from_xyz(4, 3, 2));
+        let expected: Vec<_> = b
+            .iter_points()
+            .unwrap()
+            .filter(|p| {
+                (0..3).any(|x| p.values[x] == b.min.values[x] || p.values[x] == b.max.values[x])
+            })
+            .collect();
+        assert_eq!(b.iter_shell().unwrap().collect::<Vec<_>>(), expected);
+
+        let line = Aabb::<1, i32>::new(Vector::new([2]), Vector::new([6]));
+        assert_eq!(
+            line.iter_shell().unwrap().collect::<Vec<_>>(),
+            vec![Vector::new([2]), Vector::new([6])]
+        );
+        let line = Aabb::<1, i32>::new(Vector::new([i32::MIN]), Vector::new([i32::MAX]));
+        assert_eq!(
+            line.iter_shell().unwrap().collect::<Vec<_>>(),
+            vec![Vector::new([i32::MIN]), Vector::new([i32::MAX])]
+        );
+    }
+
+    #[test]
+    fn aabb_corners() {
+        let a = Aabb2::new(V2::from_xy(0, 1), V2::from_xy(2, 3));
+        assert_eq!(
+            a.corners().collect::<Vec<_>>(),
+            vec![
+                V2::from_xy(0, 1),
+                V2::from_xy(2, 1),
+                V2::from_xy(0, 3),
+                V2::from_xy(2, 3)
+            ]
+        );
+        assert_eq!(Aabb3::new(V3::all(0), V3::all(1)).corners().count(), 8);
+    }
+
+    #[test]
+    fn aabb_transformed() {
+        let a = Aabb2::new(V2::from_xy(0, 1), V2::from_xy(2, 3));
+        let t = Translation::new(V2::from_xy(-5, 5));
+        assert_eq!(
+            a.transformed(&t),
+            Aabb2::new(V2::from_xy(-5, 6), V2::from_xy(-3, 8))
+        );
+
+        assert_eq!(
+            a.transformed(&GridSymmetry::Rotate90),
+            Aabb2::new(V2::from_xy(-3, 0), V2::from_xy(-1, 2))
+        );
+    }
+
+    #[test]
+    fn aabb_ray_intersection() {
+        let a = Aabb2::new(V2::from_xy(1.0f32, 1.0), V2::from_xy(3.0, 2.0));
+
+        let hit = a.ray_intersection(V2::from_xy(0.0, 1.5), V2::from_xy(1.0, 0.0));
+        assert_eq!(hit, Some((1.0, 3.0)));
+
+        let hit = a.ray_intersection(V2::from_xy(2.0, 1.5), V2::from_xy(-2.0, 0.0));
+        assert_eq!(hit, Some((0.0, 0.5)));
+
+        let miss = a.ray_intersection(V2::from_xy(0.0, 0.0), V2::from_xy(-1.0, -1.0));
+        assert_eq!(miss, None);
+
+        // NOTE(lubo): Axis-parallel, outside of the slab.
+        let miss = a.ray_intersection(V2::from_xy(0.0, 3.0), V2::from_xy(1.0, 0.0));
+        assert_eq!(miss, None);
+
+        // NOTE(lubo): Axis-parallel, exactly on the boundary.
+        let hit = a.ray_intersection(V2::from_xy(0.0, 2.0), V2::from_xy(1.0, 0.0));
+        assert_eq!(hit, Some((1.0, 3.0)));
+
+        let hit = a.ray_intersection(V2::from_xy(0.0, 0.0), V2::from_xy(2.0, 2.0));
+        assert_eq!(hit, Some((0.5, 1.0)));
+    }
+
+    #[test]
+    fn aabb_segment_intersection() {
+        let a = Aabb2::new(V2::from_xy(1.0f64, 1.0), V2::from_xy(3.0, 2.0));
+
+        let hit = a.segment_intersection(V2::from_xy(0.0, 1.5), V2::from_xy(4.0, 1.5));
+        assert_eq!(hit, Some((0.25, 0.75)));
+
+        let hit = a.segment_intersection(V2::from_xy(2.0, 1.5), V2::from_xy(2.5, 1.5));
+        assert_eq!(hit, Some((0.0, 1.0)));
+
+        let miss = a.segment_intersection(V2::from_xy(0.0, 1.5), V2::from_xy(0.5, 1.5));
+        assert_eq!(miss, None);
+    }
 }
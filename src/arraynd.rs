@@ -6,10 +6,11 @@ use std::{
 };
 
 use super::{
+    cli::Progress,
     geometric_traits::{IterateNeighbours, IterateNeighboursContext},
     line::Line,
     line_iterator::LineIterator,
-    linear_index::LinearIndex,
+    linear_index::{LinearIndex, StridedIndex},
     vector::Vector,
 };
 
@@ -165,7 +166,7 @@ impl<const N: usize, T> LinearIndex<usize> for ArrayNd<N, T> {
             None
         }
     }
-    unsafe fn cardinality(&self) -> Option<usize> {
+    fn checked_cardinality(&self) -> Option<usize> {
         Some(self.data.len())
     }
     fn is_in_bounds(&self, i: &usize) -> bool {
@@ -178,7 +179,7 @@ macro_rules! array_vector_linear_index {
         $(
 impl<const N: usize, T> LinearIndex<Vector<N, $t>> for ArrayNd<N, T> {
     fn index_unchecked(&self, i: Vector<N, $t>) -> Option<usize> {
-        Vector::new(self.dims).index_unchecked(i.try_into().unwrap())
+        Vector::new(self.dims).index_unchecked(i.try_into().ok()?)
     }
     fn unindex(&self, i: usize) -> Option<Vector<N, $t>> {
         if let Some(a) = Vector::new(self.dims).unindex(i) {
@@ -190,8 +191,8 @@ impl<const N: usize, T> LinearIndex<Vector<N, $t>> for ArrayNd<N, T> {
             None
         }
     }
-    unsafe fn cardinality(&self) -> Option<usize> {
-        Some(self.dims.iter().product())
+    fn checked_cardinality(&self) -> Option<usize> {
+        Vector::new(self.dims).checked_cardinality()
     }
     fn is_in_bounds(&self, i: &Vector<N, $t>) -> bool {
         #[allow(irrefutable_let_patterns)]
@@ -259,6 +260,24 @@ impl<const N: usize, T> ArrayNd<N, T> {
             None => false,
         }
     }
+
+    /// Like [`ArrayNd::get`], but `p` is mapped to the data through `index`, e.g. an
+    /// [`crate::linear_index::OffsetIndex`] for world coordinates.
+    pub fn get_in<I, L: LinearIndex<I>>(&self, index: &L, p: I) -> Option<&T> {
+        self.data.get(index.index(p)?)
+    }
+    pub fn get_mut_in<I, L: LinearIndex<I>>(&mut self, index: &L, p: I) -> Option<&mut T> {
+        self.data.get_mut(index.index(p)?)
+    }
+    pub fn set_in<I, L: LinearIndex<I>>(&mut self, index: &L, p: I, v: T) -> bool {
+        match self.get_mut_in(index, p) {
+            Some(a) => {
+                *a = v;
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 impl<const N: usize, T> ArrayNd<N, T> {
@@ -350,6 +369,27 @@ impl<const N: usize, T> ArrayNd<N, T> {
             dim_strides: self.dim_strides,
         }
     }
+
+    /// [`ArrayNd::map`] for expensive `f`, ticking `progress` once per element.
+    pub fn map_with_progress<F, U>(&self, f: F, progress: &mut Progress<usize>) -> ArrayNd<N, U>
+    where
+        F: Fn(&T) -> U,
+    {
+        let data = self
+            .data
+            .iter()
+            .map(|x| {
+                progress.tick();
+                f(x)
+            })
+            .collect();
+
+        ArrayNd::<N, U> {
+            data,
+            dims: self.dims,
+            dim_strides: self.dim_strides,
+        }
+    }
 }
 
 impl<const N: usize, T> ArrayNd<N, T> {
@@ -484,6 +524,101 @@ impl<const C: usize, T: Display> Display for ArrayNd<C, T> {
     }
 }
 
+/// Borrowed strided part of an [`ArrayNd`], see [`ArrayNd::view`].
+#[derive(Debug, Clone, Copy)]
+pub struct ArrayView<'a, const N: usize, T> {
+    data: &'a [T],
+    pub index: StridedIndex<N>,
+}
+
+impl<'a, const N: usize, T> ArrayView<'a, N, T> {
+    pub fn dims(&self) -> [usize; N] {
+        self.index.extent
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    pub fn get(&self, p: Vector<N, i32>) -> Option<&'a T> {
+        let p: Vector<N, usize> = p.try_into().ok()?;
+        self.data.get(self.index.index(p.values)?)
+    }
+
+    /// Values in order, the first axis varying fastest.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.index.iter().map(|i| &self.data[i])
+    }
+
+    /// Part of this view, see [`StridedIndex::slice`].
+    pub fn slice(
+        &self,
+        start: Vector<N, usize>,
+        extent: Vector<N, usize>,
+        step: Vector<N, usize>,
+    ) -> Option<Self> {
+        Some(Self {
+            data: self.data,
+            index: self.index.slice(start.values, extent.values, step.values)?,
+        })
+    }
+
+    pub fn to_array(&self) -> ArrayNd<N, T>
+    where
+        T: Clone,
+    {
+        let data: Vec<T> = self.iter().cloned().collect();
+        ArrayNd {
+            data,
+            dims: self.dims(),
+            dim_strides: StridedIndex::contiguous(self.dims()).strides,
+        }
+    }
+}
+
+impl<const N: usize, T> ArrayNd<N, T> {
+    /// The whole array as an [`ArrayView`].
+    pub fn view(&self) -> ArrayView<'_, N, T> {
+        ArrayView {
+            data: &self.data,
+            index: StridedIndex::new(0, self.dim_strides, self.dims),
+        }
+    }
+
+    /// Box of `extent` cells starting at `start`, `None` unless it fits.
+    pub fn sub_view(
+        &self,
+        start: Vector<N, usize>,
+        extent: Vector<N, usize>,
+    ) -> Option<ArrayView<'_, N, T>> {
+        self.view().slice(start, extent, Vector::all(1))
+    }
+
+    /// Every `step`-th cell along each axis, `extent` cells starting at `start`.
+    pub fn strided_view(
+        &self,
+        start: Vector<N, usize>,
+        extent: Vector<N, usize>,
+        step: Vector<N, usize>,
+    ) -> Option<ArrayView<'_, N, T>> {
+        self.view().slice(start, extent, step)
+    }
+}
+
+impl<T> Array2d<T> {
+    pub fn row(&self, y: usize) -> Option<ArrayView<'_, 2, T>> {
+        self.sub_view(Vector::new([0, y]), Vector::new([self.width(), 1]))
+    }
+
+    pub fn column(&self, x: usize) -> Option<ArrayView<'_, 2, T>> {
+        self.sub_view(Vector::new([x, 0]), Vector::new([1, self.height()]))
+    }
+}
+
 // NOTE(lubo): Specific lower dimensional arrays
 
 pub type Array2d<T> = ArrayNd<2, T>;
@@ -607,7 +742,7 @@ impl<T: Copy> Array3d<T> {
 
 #[cfg(test)]
 mod tests {
-    use crate::vector::V2i32;
+    use crate::{linear_index::OffsetIndex, vector::V2i32};
 
     use super::*;
 
@@ -632,6 +767,68 @@ mod tests {
         assert_eq!(Some(&'4'), map.get(V2i32::from_xy(0, 0)));
     }
 
+    #[test]
+    fn offset_index() {
+        let index = OffsetIndex::new(V2i32::from_xy(-5, -5), [11, 11]);
+        let mut map = Array2d::new(index.dims, '.');
+        assert!(map.set_in(&index, V2i32::from_xy(-5, 0), '#'));
+        assert!(map.set_in(&index, V2i32::from_xy(0, 0), 'o'));
+        assert!(!map.set_in(&index, V2i32::from_xy(6, 0), '#'));
+        assert_eq!(map.get_in(&index, V2i32::from_xy(0, 0)), Some(&'o'));
+        assert_eq!(map.get(V2i32::from_xy(5, 5)), Some(&'o'));
+        assert_eq!(map.get_in(&index, V2i32::from_xy(-6, 0)), None);
+        assert_eq!(
+            map.find_item(&'#').map(|p| p + index.origin),
+            Some(V2i32::from_xy(-5, 0))
+        );
+    }
+
+    #[test]
+    fn views() {
+        let map: CharArray2d = EXAMPLE.parse().unwrap();
+        let row: String = map.row(2).unwrap().iter().collect();
+        assert_eq!(row, "..35..633.");
+        let column: String = map.column(0).unwrap().iter().collect();
+        assert_eq!(column, "4...6.....");
+        assert!(map.row(10).is_none());
+
+        let block = map
+            .sub_view(Vector::new([2, 2]), Vector::new([3, 2]))
+            .unwrap();
+        assert_eq!(block.dims(), [3, 2]);
+        assert_eq!(block.iter().collect::<String>(), "35....");
+        assert_eq!(block.get(V2i32::from_xy(1, 0)), Some(&'5'));
+        assert_eq!(block.get(V2i32::from_xy(3, 0)), None);
+        assert_eq!(block.get(V2i32::from_xy(-1, 0)), None);
+        assert_eq!(block.to_array().to_string(), "35.\n...\n");
+
+        let corners = map
+            .strided_view(
+                Vector::new([0, 0]),
+                Vector::new([2, 2]),
+                Vector::new([9, 9]),
+            )
+            .unwrap();
+        assert_eq!(corners.iter().collect::<String>(), "4...");
+        let inner = block
+            .slice(
+                Vector::new([1, 0]),
+                Vector::new([2, 1]),
+                Vector::new([1, 1]),
+            )
+            .unwrap();
+        assert_eq!(inner.iter().collect::<String>(), "5.");
+    }
+
+    #[test]
+    fn map_with_progress() {
+        let map: CharArray2d = EXAMPLE.parse().unwrap();
+        let mut progress = Progress::new(100);
+        let digits = map.map_with_progress(|c| c.is_ascii_digit(), &mut progress);
+        assert_eq!(progress.count(), 100);
+        assert_eq!(digits, map.map(|c| c.is_ascii_digit()));
+    }
+
     #[test]
     fn fail_parse_chararray2d_inconsistent_line_width() {
         let bad = r#"
@@ -1,3 +1,10 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::Index,
+};
+
 use super::modular::ModularAdd;
 
 #[derive(Debug, Clone)]
@@ -14,11 +21,27 @@ impl Bijection {
         }
     }
 
-    #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
         self.f.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.f.is_empty()
+    }
+
+    /// Pairs `(i, f(i))` in order of `i`.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.f.iter().copied().enumerate()
+    }
+
+    pub fn get_by_left(&self, i: usize) -> Option<usize> {
+        self.f.get(i).copied()
+    }
+
+    pub fn get_by_right(&self, j: usize) -> Option<usize> {
+        self.g.get(j).copied()
+    }
+
     pub fn valid(&self) -> bool {
         (0..self.len()).all(|i| self.g[self.f[i]] == i)
             && (0..self.len()).all(|j| self.f[self.g[j]] == j)
@@ -48,3 +71,336 @@ impl Bijection {
         self.swap_adj(a_i, self.len() - 1);
     }
 }
+
+/// Existing pairs that block [`BiMap::try_insert`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BiMapConflict<L, R> {
+    /// The left value is already paired.
+    Left(L, R),
+    /// The right value is already paired.
+    Right(L, R),
+    /// Both values are paired, with different partners.
+    Both((L, R), (L, R)),
+}
+
+impl<L: Debug, R: Debug> Display for BiMapConflict<L, R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BiMapConflict::Left(l, r) => write!(f, "{l:?} is already paired with {r:?}."),
+            BiMapConflict::Right(l, r) => write!(f, "{r:?} is already paired with {l:?}."),
+            BiMapConflict::Both((l0, r0), (l1, r1)) => write!(
+                f,
+                "{l0:?} is already paired with {r0:?} and {r1:?} with {l1:?}."
+            ),
+        }
+    }
+}
+
+impl<L: Debug, R: Debug> std::error::Error for BiMapConflict<L, R> {}
+
+/// Bidirectional map between arbitrary values, a partial [`Bijection`].
+#[derive(Debug, Clone)]
+pub struct BiMap<L, R> {
+    left: HashMap<L, R>,
+    right: HashMap<R, L>,
+}
+
+impl<L, R> Default for BiMap<L, R> {
+    fn default() -> Self {
+        Self {
+            left: HashMap::new(),
+            right: HashMap::new(),
+        }
+    }
+}
+
+impl<L: Eq + Hash + Clone, R: Eq + Hash + Clone> PartialEq for BiMap<L, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.left == other.left
+    }
+}
+
+impl<L: Eq + Hash + Clone, R: Eq + Hash + Clone> Eq for BiMap<L, R> {}
+
+impl<L: Eq + Hash + Clone, R: Eq + Hash + Clone> BiMap<L, R> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty()
+    }
+
+    /// Pairs in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&L, &R)> {
+        self.left.iter()
+    }
+
+    pub fn contains_left(&self, l: &L) -> bool {
+        self.left.contains_key(l)
+    }
+
+    pub fn contains_right(&self, r: &R) -> bool {
+        self.right.contains_key(r)
+    }
+
+    pub fn get_by_left(&self, l: &L) -> Option<&R> {
+        self.left.get(l)
+    }
+
+    pub fn get_by_right(&self, r: &R) -> Option<&L> {
+        self.right.get(r)
+    }
+
+    /// Insert the pair, dropping any pairs that shared `l` or `r` with it.
+    pub fn insert(&mut self, l: L, r: R) {
+        self.insert_replacing(l, r);
+    }
+
+    /// Insert the pair only if neither side is paired yet, or already with each other.
+    pub fn try_insert(&mut self, l: L, r: R) -> Result<(), BiMapConflict<L, R>> {
+        if self.left.get(&l) == Some(&r) {
+            return Ok(());
+        }
+        match (self.left.get(&l), self.right.get(&r)) {
+            (None, None) => {
+                self.left.insert(l.clone(), r.clone());
+                self.right.insert(r, l);
+                Ok(())
+            }
+            (Some(old_r), None) => Err(BiMapConflict::Left(l, old_r.clone())),
+            (None, Some(old_l)) => Err(BiMapConflict::Right(old_l.clone(), r)),
+            (Some(old_r), Some(old_l)) => {
+                Err(BiMapConflict::Both((l, old_r.clone()), (old_l.clone(), r)))
+            }
+        }
+    }
+
+    /// Insert the pair, evicting and returning the pairs that shared `l` or `r` with it, the
+    /// one sharing `l` first. Re-inserting an existing pair returns it once.
+    pub fn insert_replacing(&mut self, l: L, r: R) -> Vec<(L, R)> {
+        let evicted = self
+            .remove_by_left(&l)
+            .into_iter()
+            .chain(self.remove_by_right(&r))
+            .collect();
+        self.left.insert(l.clone(), r.clone());
+        self.right.insert(r, l);
+        evicted
+    }
+
+    pub fn remove_by_left(&mut self, l: &L) -> Option<(L, R)> {
+        let r = self.left.remove(l)?;
+        let l = self.right.remove(&r).expect("BiMap sides out of sync");
+        Some((l, r))
+    }
+
+    pub fn remove_by_right(&mut self, r: &R) -> Option<(L, R)> {
+        let l = self.right.remove(r)?;
+        let r = self.left.remove(&l).expect("BiMap sides out of sync");
+        Some((l, r))
+    }
+}
+
+impl<L: Eq + Hash + Clone, R: Eq + Hash + Clone> FromIterator<(L, R)> for BiMap<L, R> {
+    fn from_iter<I: IntoIterator<Item = (L, R)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (l, r) in iter {
+            map.insert(l, r);
+        }
+        map
+    }
+}
+
+/// Dense ids `0, 1, 2, ...` for values in order of first insertion, so that labeled data can be
+/// stored in `Vec`s and bitsets indexed by id.
+#[derive(Debug, Clone)]
+pub struct Interner<T> {
+    values: Vec<T>,
+    ids: HashMap<T, usize>,
+}
+
+impl<T> Default for Interner<T> {
+    fn default() -> Self {
+        Self {
+            values: vec![],
+            ids: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone> Interner<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Id of `value`, assigning the next one if it is new.
+    pub fn intern(&mut self, value: T) -> usize {
+        if let Some(&id) = self.ids.get(&value) {
+            return id;
+        }
+        let id = self.values.len();
+        self.ids.insert(value.clone(), id);
+        self.values.push(value);
+        id
+    }
+
+    pub fn id(&self, value: &T) -> Option<usize> {
+        self.ids.get(value).copied()
+    }
+
+    pub fn get(&self, id: usize) -> Option<&T> {
+        self.values.get(id)
+    }
+
+    /// Values indexed by id.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.values.iter().enumerate()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Interner<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
+impl<T: Eq> Eq for Interner<T> {}
+
+impl<T> Index<usize> for Interner<T> {
+    type Output = T;
+
+    fn index(&self, id: usize) -> &Self::Output {
+        &self.values[id]
+    }
+}
+
+impl<T: Eq + Hash + Clone> FromIterator<T> for Interner<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut interner = Self::new();
+        for value in iter {
+            interner.intern(value);
+        }
+        interner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BiMap, BiMapConflict, Bijection, Interner};
+
+    #[test]
+    fn bijection_lookup() {
+        let mut b = Bijection::new(4);
+        b.swap(0, 3);
+        b.swap_with_right(1);
+        assert!(b.valid());
+        assert_eq!(b.len(), 4);
+        assert!(!b.is_empty());
+        assert!(Bijection::new(0).is_empty());
+        for (i, j) in b.iter() {
+            assert_eq!(b.get_by_right(j), Some(i));
+            assert_eq!(b.get_by_left(i), Some(j));
+        }
+        assert_eq!(b.get_by_left(4), None);
+        assert_eq!(
+            b.iter().collect::<Vec<_>>(),
+            vec![(0, 3), (1, 2), (2, 1), (3, 0)]
+        );
+    }
+
+    #[test]
+    fn bimap() {
+        let mut map: BiMap<&str, i32> = [("a", 1), ("b", 2)].into_iter().collect();
+        assert_eq!(map.len(), 2);
+        assert!(map.contains_left(&"a"));
+        assert!(map.contains_right(&2));
+        assert!(!map.contains_right(&3));
+        assert_eq!(map.get_by_left(&"b"), Some(&2));
+        assert_eq!(map.get_by_right(&1), Some(&"a"));
+
+        // NOTE(lubo): Both old pairs conflict with the new one.
+        map.insert("a", 2);
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get_by_right(&2), Some(&"a"));
+        assert!(!map.contains_left(&"b"));
+        assert!(!map.contains_right(&1));
+
+        assert_eq!(map.remove_by_right(&2), Some(("a", 2)));
+        assert_eq!(map.remove_by_left(&"a"), None);
+        assert!(map.is_empty());
+        assert_eq!(map, BiMap::new());
+    }
+
+    #[test]
+    fn bimap_conflicts() {
+        let mut map = BiMap::new();
+        assert_eq!(map.try_insert('a', 1), Ok(()));
+        assert_eq!(map.try_insert('b', 2), Ok(()));
+        assert_eq!(map.try_insert('a', 3), Err(BiMapConflict::Left('a', 1)));
+        assert_eq!(map.try_insert('c', 2), Err(BiMapConflict::Right('b', 2)));
+        assert_eq!(
+            map.try_insert('a', 2),
+            Err(BiMapConflict::Both(('a', 1), ('b', 2)))
+        );
+        assert_eq!(
+            map.try_insert('a', 2).unwrap_err().to_string(),
+            "'a' is already paired with 1 and 2 with 'b'."
+        );
+        assert_eq!(map.try_insert('a', 1), Ok(()));
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.insert_replacing('a', 2), vec![('a', 1), ('b', 2)]);
+        assert_eq!(map.insert_replacing('a', 2), vec![('a', 2)]);
+        assert_eq!(map.insert_replacing('z', 26), vec![]);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get_by_right(&2), Some(&'a'));
+    }
+
+    #[test]
+    fn interner() {
+        let mut nodes = Interner::new();
+        assert_eq!(nodes.intern("start"), 0);
+        assert_eq!(nodes.intern("a"), 1);
+        assert_eq!(nodes.intern("start"), 0);
+        assert_eq!(nodes.intern("end"), 2);
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(nodes.id(&"end"), Some(2));
+        assert_eq!(nodes.id(&"b"), None);
+        assert_eq!(nodes.get(1), Some(&"a"));
+        assert_eq!(nodes.get(3), None);
+        assert_eq!(nodes[2], "end");
+        assert_eq!(nodes.values(), ["start", "a", "end"]);
+
+        // NOTE(lubo): Edges over labels become a dense adjacency list.
+        let edges = [("start", "a"), ("a", "end"), ("start", "end")];
+        let mut adjacency = vec![vec![]; nodes.len()];
+        for (from, to) in edges {
+            adjacency[nodes.id(&from).unwrap()].push(nodes.id(&to).unwrap());
+        }
+        assert_eq!(adjacency, vec![vec![1, 2], vec![2], vec![]]);
+
+        let letters: Interner<char> = "abracadabra".chars().collect();
+        assert_eq!(
+            letters.iter().collect::<Vec<_>>(),
+            vec![(0, &'a'), (1, &'b'), (2, &'r'), (3, &'c'), (4, &'d')]
+        );
+        assert!(Interner::<u8>::new().is_empty());
+    }
+}
@@ -0,0 +1,353 @@
+use std::marker::PhantomData;
+
+use super::linear_index::LinearIndex;
+
+/// Growable set of small non-negative integers, one bit each.
+#[derive(Debug, Clone, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Room for `0..bits` without reallocating.
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            words: vec![0; bits.div_ceil(64)],
+        }
+    }
+
+    /// Returns `true` if `i` was not in the set yet.
+    pub fn insert(&mut self, i: usize) -> bool {
+        let (word, bit) = (i / 64, 1 << (i % 64));
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let fresh = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        fresh
+    }
+
+    /// Returns `true` if `i` was in the set.
+    pub fn remove(&mut self, i: usize) -> bool {
+        let (word, bit) = (i / 64, 1 << (i % 64));
+        match self.words.get_mut(word) {
+            Some(w) if *w & bit != 0 => {
+                *w &= !bit;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn contains(&self, i: usize) -> bool {
+        self.words
+            .get(i / 64)
+            .is_some_and(|w| w & (1 << (i % 64)) != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    pub fn union_with(&mut self, other: &Self) {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a |= b;
+        }
+    }
+
+    pub fn intersect_with(&mut self, other: &Self) {
+        self.words.truncate(other.words.len());
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a &= b;
+        }
+    }
+
+    pub fn difference_with(&mut self, other: &Self) {
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a &= !b;
+        }
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.words
+            .iter()
+            .enumerate()
+            .all(|(i, &a)| a & !other.words.get(i).copied().unwrap_or(0) == 0)
+    }
+
+    /// Members in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &w)| BitSet64(w).iter().map(move |bit| i * 64 + bit))
+    }
+}
+
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        // NOTE(lubo): Trailing zero words do not matter.
+        let n = self.words.len().max(other.words.len());
+        (0..n).all(|i| self.words.get(i).unwrap_or(&0) == other.words.get(i).unwrap_or(&0))
+    }
+}
+
+impl Eq for BitSet {}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut result = Self::new();
+        for i in iter {
+            result.insert(i);
+        }
+        result
+    }
+}
+
+macro_rules! fixed_bitset {
+    ($($n:ident, $t:ty);*) => {
+        $(
+        /// Set of integers below the bit width, packed into one word. `Copy`, ordered and hashable
+        /// for use as a compact state.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+        pub struct $n(pub $t);
+
+        impl $n {
+            pub const CAPACITY: usize = <$t>::BITS as usize;
+            pub const EMPTY: Self = Self(0);
+            pub const FULL: Self = Self(<$t>::MAX);
+
+            /// Panics unless `i < CAPACITY`.
+            pub fn insert(&mut self, i: usize) -> bool {
+                let fresh = !self.contains(i);
+                *self = self.with(i);
+                fresh
+            }
+
+            /// Values outside the capacity are never present, removing them does nothing.
+            pub fn remove(&mut self, i: usize) -> bool {
+                let present = self.contains(i);
+                *self = self.without(i);
+                present
+            }
+
+            /// Panics unless `i < CAPACITY`.
+            pub fn with(self, i: usize) -> Self {
+                assert!(i < Self::CAPACITY, "{i} does not fit in a {}", stringify!($n));
+                Self(self.0 | 1 << i)
+            }
+
+            pub fn without(self, i: usize) -> Self {
+                if i < Self::CAPACITY {
+                    Self(self.0 & !(1 << i))
+                } else {
+                    self
+                }
+            }
+
+            pub fn contains(&self, i: usize) -> bool {
+                i < Self::CAPACITY && self.0 & (1 << i) != 0
+            }
+
+            pub fn len(&self) -> usize {
+                self.0.count_ones() as usize
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            pub fn union(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+
+            pub fn intersection(self, other: Self) -> Self {
+                Self(self.0 & other.0)
+            }
+
+            pub fn difference(self, other: Self) -> Self {
+                Self(self.0 & !other.0)
+            }
+
+            pub fn is_subset(self, other: Self) -> bool {
+                self.0 & !other.0 == 0
+            }
+
+            /// Members in increasing order.
+            pub fn iter(self) -> impl Iterator<Item = usize> {
+                let mut rest = self.0;
+                std::iter::from_fn(move || {
+                    (rest != 0).then(|| {
+                        let i = rest.trailing_zeros() as usize;
+                        rest &= rest - 1;
+                        i
+                    })
+                })
+            }
+        }
+
+        impl FromIterator<usize> for $n {
+            fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+                iter.into_iter().fold(Self::EMPTY, Self::with)
+            }
+        }
+        )*
+    };
+}
+
+fixed_bitset!(BitSet64, u64; BitSet128, u128);
+
+/// [`BitSet`] of positions `I`, stored by their linear index in `index`.
+#[derive(Debug, Clone)]
+pub struct IndexedBitSet<L, I> {
+    pub index: L,
+    bits: BitSet,
+    position: PhantomData<I>,
+}
+
+impl<L: LinearIndex<I>, I> IndexedBitSet<L, I> {
+    pub fn new(index: L) -> Self {
+        let bits = index
+            .checked_cardinality()
+            .map_or_else(BitSet::new, BitSet::with_capacity);
+        Self {
+            index,
+            bits,
+            position: PhantomData,
+        }
+    }
+
+    /// `None` if `i` is outside of the index space.
+    pub fn insert(&mut self, i: I) -> Option<bool> {
+        Some(self.bits.insert(self.index.index(i)?))
+    }
+
+    pub fn remove(&mut self, i: I) -> Option<bool> {
+        Some(self.bits.remove(self.index.index(i)?))
+    }
+
+    pub fn contains(&self, i: I) -> bool {
+        self.index.index(i).is_some_and(|i| self.bits.contains(i))
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    pub fn bits(&self) -> &BitSet {
+        &self.bits
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = I> + '_ {
+        self.bits.iter().filter_map(|i| self.index.unindex(i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{linear_index::OffsetIndex, vector::V2i32};
+
+    #[test]
+    fn growable() {
+        let mut a: BitSet = [1, 5, 64, 200].into_iter().collect();
+        assert!(a.contains(64));
+        assert!(!a.contains(63));
+        assert!(!a.contains(100000));
+        assert!(!a.insert(5));
+        assert!(a.insert(6));
+        assert!(a.remove(200));
+        assert!(!a.remove(200));
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![1, 5, 6, 64]);
+        assert_eq!(a.len(), 4);
+
+        let b: BitSet = [5, 64, 70, 1000].into_iter().collect();
+        let mut union = a.clone();
+        union.union_with(&b);
+        assert_eq!(
+            union.iter().collect::<Vec<_>>(),
+            vec![1, 5, 6, 64, 70, 1000]
+        );
+        let mut intersection = a.clone();
+        intersection.intersect_with(&b);
+        assert_eq!(intersection, [5, 64].into_iter().collect());
+        assert!(intersection.is_subset(&a) && intersection.is_subset(&b));
+        let mut difference = b.clone();
+        difference.difference_with(&a);
+        assert_eq!(difference.iter().collect::<Vec<_>>(), vec![70, 1000]);
+        assert_eq!(BitSet::with_capacity(500), BitSet::new());
+        difference.clear();
+        assert!(difference.is_empty());
+    }
+
+    #[test]
+    fn fixed() {
+        let a: BitSet64 = [0, 3, 63].into_iter().collect();
+        assert_eq!(a.0, 1 | 8 | 1 << 63);
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![0, 3, 63]);
+        assert!(a.contains(63) && !a.contains(64));
+        let b = BitSet64::EMPTY.with(3).with(10);
+        assert_eq!(a.union(b).len(), 4);
+        assert_eq!(a.intersection(b), BitSet64::EMPTY.with(3));
+        assert_eq!(a.difference(b).without(0), BitSet64(1 << 63));
+        assert!(a.is_subset(BitSet64::FULL));
+
+        let mut c = BitSet128::default();
+        assert!(c.insert(100));
+        assert!(!c.insert(100));
+        assert_eq!(c.iter().collect::<Vec<_>>(), vec![100]);
+        assert!(c.remove(100));
+        assert!(c.is_empty());
+
+        let mut d = BitSet64::FULL;
+        assert!(!d.remove(BitSet64::CAPACITY));
+        assert_eq!(d.without(BitSet64::CAPACITY), BitSet64::FULL);
+        assert!(!BitSet128::FULL.contains(BitSet128::CAPACITY));
+        assert!(!c.remove(BitSet128::CAPACITY + 1));
+        assert!(!d.insert(BitSet64::CAPACITY - 1));
+        assert_eq!(d, BitSet64::FULL);
+    }
+
+    #[test]
+    #[should_panic(expected = "64 does not fit in a BitSet64")]
+    fn fixed_out_of_range() {
+        let mut set = BitSet64::EMPTY;
+        set.insert(BitSet64::CAPACITY);
+    }
+
+    #[test]
+    fn indexed() {
+        let mut visited = IndexedBitSet::new(OffsetIndex::new(V2i32::from_xy(-2, -2), [5, 5]));
+        assert_eq!(visited.insert(V2i32::from_xy(-2, 1)), Some(true));
+        assert_eq!(visited.insert(V2i32::from_xy(2, 2)), Some(true));
+        assert_eq!(visited.insert(V2i32::from_xy(2, 2)), Some(false));
+        assert_eq!(visited.insert(V2i32::from_xy(3, 2)), None);
+        assert!(visited.contains(V2i32::from_xy(-2, 1)));
+        assert!(!visited.contains(V2i32::from_xy(0, 0)));
+        assert_eq!(
+            visited.iter().collect::<Vec<_>>(),
+            vec![V2i32::from_xy(-2, 1), V2i32::from_xy(2, 2)]
+        );
+        assert_eq!(visited.remove(V2i32::from_xy(-2, 1)), Some(true));
+        assert_eq!(visited.len(), 1);
+    }
+}
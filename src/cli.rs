@@ -1,10 +1,22 @@
-use std::fmt::Display;
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    hint::black_box,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+    time::Instant,
+};
 
+/// Rate limited progress report on stderr, with an ETA when the total is known.
+#[derive(Debug, Clone)]
 pub struct Progress<T> {
     calls: usize,
-    max: T,
+    count: usize,
+    max: Option<T>,
     start_time: std::time::Instant,
     last_print_time: std::time::Instant,
+    interval: std::time::Duration,
 }
 
 pub struct ProgressBar {
@@ -81,36 +93,668 @@ impl Display for Duration {
     }
 }
 
+impl<T> Progress<T> {
+    /// Print at most once per `interval`, every 10 seconds by default.
+    pub fn with_interval(mut self, interval: std::time::Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Number of [`Progress::tick`]s so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    fn should_print(&mut self) -> bool {
+        self.calls += 1;
+        if self.last_print_time.elapsed() >= self.interval {
+            self.last_print_time = std::time::Instant::now();
+            true
+        } else {
+            false
+        }
+    }
+}
+
 impl Progress<usize> {
     pub fn new(max: usize) -> Self {
+        Self::with_max(Some(max))
+    }
+
+    /// Progress of a search of unknown size, reports counts and rate only.
+    pub fn unbounded() -> Self {
+        Self::with_max(None)
+    }
+
+    fn with_max(max: Option<usize>) -> Self {
         Progress {
             calls: 0,
+            count: 0,
             max,
             start_time: std::time::Instant::now(),
             last_print_time: std::time::Instant::now(),
+            interval: std::time::Duration::new(10, 0),
         }
     }
 
     pub fn progress(&mut self, current: usize) {
-        self.calls += 1;
-        if self.last_print_time.elapsed() >= std::time::Duration::new(10, 0) {
-            self.last_print_time = std::time::Instant::now();
+        if self.should_print() {
+            eprintln!("{}", self.status(current));
+            self.calls = 0;
+        }
+    }
+
+    /// One more step done, `progress(count)`.
+    pub fn tick(&mut self) {
+        self.count += 1;
+        self.progress(self.count);
+    }
 
-            let elapsed = self.start_time.elapsed().as_secs_f64();
-            let progress = current as f64 / self.max as f64;
-            let total = elapsed / progress;
-            let time_left = total - elapsed;
+    pub fn status(&self, current: usize) -> String {
+        let elapsed = self.start_time.elapsed().as_secs_f64();
+        match self.max {
+            Some(max) => {
+                let progress = current as f64 / max as f64;
+                let total = elapsed / progress;
+                let time_left = total - elapsed;
 
-            println!(
-                "{} Calls since last print: {}  Elapsed: {}  Progress: {}%  ETA: {}",
-                ProgressBar::new(progress as f32),
+                format!(
+                    "{} Calls since last print: {}  Elapsed: {}  Progress: {}%  ETA: {}",
+                    ProgressBar::new(progress as f32),
+                    self.calls,
+                    Duration::new(elapsed),
+                    100.0 * progress,
+                    Duration::new(time_left)
+                )
+            }
+            None => format!(
+                "Calls since last print: {}  Elapsed: {}  Count: {}  Rate: {:.0}/s",
                 self.calls,
                 Duration::new(elapsed),
-                100.0 * progress,
-                Duration::new(time_left)
-            );
+                current,
+                current as f64 / elapsed
+            ),
+        }
+    }
+}
 
-            self.calls = 0;
+/// Human readable time with a unit fitting its magnitude, e.g. `1.23 ms`.
+fn format_elapsed(elapsed: std::time::Duration) -> String {
+    let nanos = elapsed.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos} ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.2} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", nanos as f64 / 1e9)
+    }
+}
+
+/// Run `f` once, print `name: result (time)` and return the result.
+pub fn run_timed<T: Display>(name: &str, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    println!("{name}: {result} ({})", format_elapsed(elapsed));
+    result
+}
+
+/// Timings of repeated runs collected by [`bench`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchStats {
+    pub name: String,
+    pub runs: usize,
+    pub min: std::time::Duration,
+    pub median: std::time::Duration,
+    pub mean: std::time::Duration,
+    pub max: std::time::Duration,
+}
+
+impl Display for BenchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: min {}  median {}  mean {}  max {}  ({} runs)",
+            self.name,
+            format_elapsed(self.min),
+            format_elapsed(self.median),
+            format_elapsed(self.mean),
+            format_elapsed(self.max),
+            self.runs
+        )
+    }
+}
+
+/// Time `runs` calls of `f` after `warmups` untimed ones. Results go through
+/// [`std::hint::black_box`] so the work is not optimized away.
+pub fn bench<T>(name: &str, warmups: usize, runs: usize, mut f: impl FnMut() -> T) -> BenchStats {
+    assert!(runs > 0, "bench needs at least one timed run");
+    for _ in 0..warmups {
+        black_box(f());
+    }
+    let mut times: Vec<_> = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    let total: std::time::Duration = times.iter().sum();
+    BenchStats {
+        name: name.to_string(),
+        runs,
+        min: times[0],
+        median: times[runs / 2],
+        mean: total / runs as u32,
+        max: times[runs - 1],
+    }
+}
+
+type Solver = Box<dyn Fn(&str) -> String>;
+
+struct Solution {
+    solve: Solver,
+    expected: Option<String>,
+}
+
+/// Solutions keyed by `(day, part)`, run by [`main`].
+pub struct Registry {
+    solutions: BTreeMap<(u32, u32), Solution>,
+    input_dir: PathBuf,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self {
+            solutions: BTreeMap::new(),
+            input_dir: PathBuf::from("input"),
+        }
+    }
+}
+
+/// Answer of one solution run by [`Registry::run`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunResult {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub expected: Option<String>,
+    pub elapsed: std::time::Duration,
+}
+
+impl RunResult {
+    /// `None` when no expected answer was registered.
+    pub fn is_correct(&self) -> Option<bool> {
+        self.expected
+            .as_ref()
+            .map(|expected| *expected == self.answer)
+    }
+}
+
+impl Display for RunResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {} part {}: {} ({})",
+            self.day,
+            self.part,
+            self.answer,
+            format_elapsed(self.elapsed)
+        )?;
+        match (self.is_correct(), &self.expected) {
+            (Some(true), _) => write!(f, " ok"),
+            (Some(false), Some(expected)) => write!(f, " WRONG, expected {expected}"),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CliError {
+    Usage(String),
+    UnknownDay(u32),
+    UnknownSolution(u32, u32),
+    Input(PathBuf, std::io::Error),
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{message}"),
+            CliError::UnknownDay(day) => write!(f, "No solutions registered for day {day}."),
+            CliError::UnknownSolution(day, part) => {
+                write!(f, "No solution registered for day {day} part {part}.")
+            }
+            CliError::Input(path, error) => {
+                write!(f, "Cannot read input {}: {error}", path.display())
+            }
         }
     }
 }
+
+impl std::error::Error for CliError {}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Directory holding `dayNN.txt` inputs, `input` by default.
+    pub fn with_input_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.input_dir = dir.into();
+        self
+    }
+
+    pub fn register<T: Display>(
+        &mut self,
+        day: u32,
+        part: u32,
+        solve: impl Fn(&str) -> T + 'static,
+    ) -> &mut Self {
+        self.insert(day, part, solve, None)
+    }
+
+    /// Register with a known answer that runs are validated against.
+    pub fn register_expected<T: Display>(
+        &mut self,
+        day: u32,
+        part: u32,
+        solve: impl Fn(&str) -> T + 'static,
+        expected: impl Display,
+    ) -> &mut Self {
+        self.insert(day, part, solve, Some(expected.to_string()))
+    }
+
+    fn insert<T: Display>(
+        &mut self,
+        day: u32,
+        part: u32,
+        solve: impl Fn(&str) -> T + 'static,
+        expected: Option<String>,
+    ) -> &mut Self {
+        let solve = Box::new(move |input: &str| solve(input).to_string());
+        self.solutions
+            .insert((day, part), Solution { solve, expected });
+        self
+    }
+
+    /// Registered `(day, part)` pairs in order.
+    pub fn keys(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        self.solutions.keys().copied()
+    }
+
+    pub fn input_path(&self, day: u32) -> PathBuf {
+        self.input_dir.join(format!("day{day:02}.txt"))
+    }
+
+    pub fn run(&self, day: u32, part: u32, input: &str) -> Result<RunResult, CliError> {
+        let solution = self
+            .solutions
+            .get(&(day, part))
+            .ok_or(CliError::UnknownSolution(day, part))?;
+        let start = Instant::now();
+        let answer = (solution.solve)(input);
+        Ok(RunResult {
+            day,
+            part,
+            answer,
+            expected: solution.expected.clone(),
+            elapsed: start.elapsed(),
+        })
+    }
+
+    /// Run the parts of `day` selected by `part` (all when `None`) on its input file.
+    pub fn run_day(&self, day: u32, part: Option<u32>) -> Result<Vec<RunResult>, CliError> {
+        self.run_day_on(day, part, &self.input_path(day))
+    }
+
+    /// [`Registry::run_day`] with the input read from `path`.
+    pub fn run_day_on(
+        &self,
+        day: u32,
+        part: Option<u32>,
+        path: &Path,
+    ) -> Result<Vec<RunResult>, CliError> {
+        let parts: Vec<_> = match part {
+            Some(part) => vec![part],
+            None => self
+                .keys()
+                .filter(|&(d, _)| d == day)
+                .map(|(_, p)| p)
+                .collect(),
+        };
+        if parts.is_empty() {
+            return Err(CliError::UnknownDay(day));
+        }
+        let input =
+            std::fs::read_to_string(path).map_err(|e| CliError::Input(path.to_path_buf(), e))?;
+        parts
+            .into_iter()
+            .map(|part| self.run(day, part, &input))
+            .collect()
+    }
+
+    /// Run what `config` selects, the day is the first free argument and the part either
+    /// `--part` or the second one.
+    pub fn run_config(&self, config: &Config) -> Result<Vec<RunResult>, CliError> {
+        let usage =
+            || CliError::Usage(format!("Usage: <day> [part]\n{}", Config::parser().usage()));
+        let parse = |arg: &String| arg.parse::<u32>().map_err(|_| usage());
+        let (day, part) = match (config.free.as_slice(), config.part) {
+            ([day], part) => (parse(day)?, part),
+            ([day, part], None) => (parse(day)?, Some(parse(part)?)),
+            _ => return Err(usage()),
+        };
+        let path = config.input.clone().unwrap_or_else(|| self.input_path(day));
+        if config.verbose {
+            eprintln!("Reading {}", path.display());
+        }
+        self.run_day_on(day, part, &path)
+    }
+}
+
+/// Declared command line interface, `--name` flags and `--name value` (or `--name=value`)
+/// options in any order among free arguments. Everything after `--` is free.
+#[derive(Debug, Clone, Default)]
+pub struct ArgParser {
+    flags: Vec<(&'static str, &'static str)>,
+    options: Vec<(&'static str, &'static str)>,
+}
+
+/// Result of [`ArgParser::parse`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Args {
+    flags: Vec<&'static str>,
+    options: BTreeMap<&'static str, String>,
+    pub free: Vec<String>,
+}
+
+impl ArgParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn flag(mut self, name: &'static str, help: &'static str) -> Self {
+        self.flags.push((name, help));
+        self
+    }
+
+    pub fn option(mut self, name: &'static str, help: &'static str) -> Self {
+        self.options.push((name, help));
+        self
+    }
+
+    pub fn usage(&self) -> String {
+        let mut usage = String::new();
+        for (name, help) in self.flags.iter() {
+            usage += &format!("  --{name:<16} {help}\n");
+        }
+        for (name, help) in self.options.iter() {
+            usage += &format!("  --{:<16} {help}\n", format!("{name} <value>"));
+        }
+        usage
+    }
+
+    pub fn parse<I: IntoIterator<Item = String>>(&self, args: I) -> Result<Args, CliError> {
+        let unknown =
+            |arg: &str| CliError::Usage(format!("Unknown argument {arg}.\n{}", self.usage()));
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                parsed.free.extend(args.by_ref());
+                break;
+            }
+            let Some(name) = arg.strip_prefix("--") else {
+                parsed.free.push(arg);
+                continue;
+            };
+            let (name, inline) = match name.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (name, None),
+            };
+            if let Some(&(flag, _)) = self.flags.iter().find(|(f, _)| *f == name) {
+                if inline.is_some() {
+                    return Err(CliError::Usage(format!("Flag --{flag} takes no value.")));
+                }
+                parsed.flags.push(flag);
+            } else if let Some(&(option, _)) = self.options.iter().find(|(o, _)| *o == name) {
+                let value = inline
+                    .or_else(|| args.next())
+                    .ok_or_else(|| CliError::Usage(format!("Option --{option} needs a value.")))?;
+                parsed.options.insert(option, value);
+            } else {
+                return Err(unknown(&arg));
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+impl Args {
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.contains(&name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(|value| value.as_str())
+    }
+
+    /// Option parsed as `T`, `Ok(None)` when it was not given.
+    pub fn get_parsed<T: FromStr>(&self, name: &str) -> Result<Option<T>, CliError> {
+        self.get(name)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| CliError::Usage(format!("Invalid value {value} for --{name}.")))
+            })
+            .transpose()
+    }
+}
+
+/// Standard options of puzzle binaries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    pub input: Option<PathBuf>,
+    pub part: Option<u32>,
+    pub verbose: bool,
+    pub free: Vec<String>,
+}
+
+impl Config {
+    pub fn parser() -> ArgParser {
+        ArgParser::new()
+            .flag("verbose", "Print more details.")
+            .option("input", "Input file, input/dayNN.txt by default.")
+            .option("part", "Run only this part.")
+    }
+
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, CliError> {
+        let args = Self::parser().parse(args)?;
+        Ok(Self {
+            input: args.get("input").map(PathBuf::from),
+            part: args.get_parsed("part")?,
+            verbose: args.flag("verbose"),
+            free: args.free,
+        })
+    }
+
+    /// Parse the arguments of the running program.
+    pub fn from_env() -> Result<Self, CliError> {
+        Self::parse(std::env::args().skip(1))
+    }
+}
+
+/// Entry point for puzzle binaries, `<day> [part]` from argv selects the solutions to run on
+/// `input/dayNN.txt`, see [`Config`] for the options. Fails on errors and wrong answers.
+pub fn main(registry: &Registry) -> ExitCode {
+    match Config::from_env().and_then(|config| registry.run_config(&config)) {
+        Ok(results) => {
+            for result in results.iter() {
+                println!("{result}");
+            }
+            if results.iter().any(|r| r.is_correct() == Some(false)) {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use std::path::PathBuf;
+
+    use super::{
+        ArgParser, CliError, Config, Progress, Registry, bench, format_elapsed, run_timed,
+    };
+
+    #[test]
+    fn elapsed_units() {
+        assert_eq!(format_elapsed(Duration::from_nanos(999)), "999 ns");
+        assert_eq!(format_elapsed(Duration::from_nanos(1_500)), "1.50 µs");
+        assert_eq!(format_elapsed(Duration::from_micros(2_345)), "2.35 ms");
+        assert_eq!(format_elapsed(Duration::from_millis(61_000)), "61.00 s");
+    }
+
+    #[test]
+    fn timing() {
+        assert_eq!(run_timed("answer", || 6 * 7), 42);
+
+        let mut calls = 0;
+        let stats = bench("sum", 2, 5, || {
+            calls += 1;
+            (0..1000u64).sum::<u64>()
+        });
+        assert_eq!(calls, 7);
+        assert_eq!(stats.runs, 5);
+        assert!(stats.min <= stats.median && stats.median <= stats.max);
+        assert!(stats.min <= stats.mean && stats.mean <= stats.max);
+        assert!(stats.to_string().starts_with("sum: min "));
+    }
+
+    #[test]
+    fn registry() {
+        let dir = std::env::temp_dir().join(format!("lk_math_registry_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("day01.txt"), "1\n2\n3\n").unwrap();
+
+        let mut registry = Registry::new().with_input_dir(&dir);
+        let numbers = |input: &str| {
+            input
+                .lines()
+                .map(|l| l.parse::<i32>().unwrap())
+                .collect::<Vec<_>>()
+        };
+        registry
+            .register_expected(1, 1, move |input| numbers(input).iter().sum::<i32>(), 6)
+            .register_expected(1, 2, move |input| numbers(input).iter().product::<i32>(), 7)
+            .register(2, 1, |input: &str| input.len());
+        assert_eq!(
+            registry.keys().collect::<Vec<_>>(),
+            vec![(1, 1), (1, 2), (2, 1)]
+        );
+
+        let results = registry.run_day(1, None).unwrap();
+        assert_eq!(results[0].answer, "6");
+        assert_eq!(results[0].is_correct(), Some(true));
+        assert_eq!(results[1].is_correct(), Some(false));
+        assert!(results[1].to_string().ends_with("WRONG, expected 7"));
+
+        let args = |a: &[&str]| Config::parse(a.iter().map(|s| s.to_string())).unwrap();
+        assert_eq!(registry.run_config(&args(&["1", "1"])).unwrap().len(), 1);
+        assert!(matches!(
+            registry.run_config(&args(&["1", "3"])),
+            Err(CliError::UnknownSolution(1, 3))
+        ));
+        assert!(matches!(
+            registry.run_config(&args(&["2"])),
+            Err(CliError::Input(..))
+        ));
+        assert!(matches!(
+            registry.run_config(&args(&["one"])),
+            Err(CliError::Usage(_))
+        ));
+        assert!(matches!(
+            registry.run_config(&args(&["3"])),
+            Err(CliError::UnknownDay(3))
+        ));
+        let input = dir.join("day01.txt").display().to_string();
+        let results = registry
+            .run_config(&args(&["2", "--input", &input]))
+            .unwrap();
+        assert_eq!(results[0].answer, "6");
+        assert_eq!(registry.run(2, 1, "abc").unwrap().is_correct(), None);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn arguments() {
+        let strings = |a: &[&str]| a.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let parser = ArgParser::new()
+            .flag("fast", "Skip checks.")
+            .option("seed", "Random seed.");
+        let args = parser
+            .parse(strings(&[
+                "a", "--seed", "7", "b", "--fast", "--", "--seed",
+            ]))
+            .unwrap();
+        assert!(args.flag("fast"));
+        assert_eq!(args.get_parsed::<u64>("seed").unwrap(), Some(7));
+        assert_eq!(args.free, strings(&["a", "b", "--seed"]));
+        assert_eq!(
+            parser.parse(strings(&["--seed=x"])).unwrap().get("seed"),
+            Some("x")
+        );
+        assert!(
+            parser
+                .parse(strings(&["--seed=x"]))
+                .unwrap()
+                .get_parsed::<u64>("seed")
+                .is_err()
+        );
+        assert!(parser.parse(strings(&["--seed"])).is_err());
+        assert!(parser.parse(strings(&["--fast=yes"])).is_err());
+        assert!(parser.parse(strings(&["--slow"])).is_err());
+        assert!(parser.usage().contains("--seed <value>"));
+
+        assert_eq!(
+            Config::parse(strings(&["5", "--part", "2", "--verbose", "--input=a.txt"])).unwrap(),
+            Config {
+                input: Some(PathBuf::from("a.txt")),
+                part: Some(2),
+                verbose: true,
+                free: strings(&["5"]),
+            }
+        );
+        assert!(Config::parse(strings(&["--part", "two"])).is_err());
+    }
+
+    #[test]
+    fn progress() {
+        let mut progress = Progress::new(200).with_interval(Duration::from_secs(3600));
+        for _ in 0..50 {
+            progress.tick();
+        }
+        assert_eq!(progress.count(), 50);
+        let status = progress.status(50);
+        assert!(status.starts_with("[#####               ]"));
+        assert!(status.contains("Calls since last print: 50"));
+        assert!(status.contains("Progress: 25%"));
+
+        let mut progress = Progress::unbounded().with_interval(Duration::ZERO);
+        progress.tick();
+        assert!(progress.status(7).contains("Count: 7"));
+    }
+}
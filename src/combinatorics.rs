@@ -0,0 +1,357 @@
+/// Lazy iterator over `k` element index combinations of `0..n` in lexicographic order, see
+/// [`combination_indices`].
+#[derive(Debug, Clone)]
+pub struct CombinationIndices {
+    n: usize,
+    indices: Option<Vec<usize>>,
+}
+
+impl Iterator for CombinationIndices {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.indices.clone()?;
+        let k = current.len();
+        let indices = self.indices.as_mut().unwrap();
+        // NOTE(lubo): Bump the rightmost index that still has room, reset the ones after it.
+        match (0..k).rev().find(|&i| indices[i] < self.n - k + i) {
+            Some(i) => {
+                indices[i] += 1;
+                for j in i + 1..k {
+                    indices[j] = indices[j - 1] + 1;
+                }
+            }
+            None => self.indices = None,
+        }
+        Some(current)
+    }
+}
+
+/// All increasing `k` element sequences of indices below `n`, `C(n, k)` of them.
+pub fn combination_indices(n: usize, k: usize) -> CombinationIndices {
+    CombinationIndices {
+        n,
+        indices: (k <= n).then(|| (0..k).collect()),
+    }
+}
+
+/// All `k` element combinations of `items`, keeping their order, lexicographic by position.
+pub fn combinations<T: Clone>(items: &[T], k: usize) -> impl Iterator<Item = Vec<T>> + '_ {
+    combination_indices(items.len(), k)
+        .map(|indices| indices.iter().map(|&i| items[i].clone()).collect())
+}
+
+/// Bitmasks of all `k` element subsets of `0..n` in increasing order (Gosper's hack), `n <= 64`.
+pub fn k_subsets(n: u32, k: u32) -> impl Iterator<Item = u64> {
+    assert!(n <= 64, "at most 64 elements");
+    let limit = if n == 64 { u64::MAX } else { (1 << n) - 1 };
+    let mut next = match k {
+        _ if k > n => None,
+        0 => Some(0),
+        64 => Some(u64::MAX),
+        _ => Some((1u64 << k) - 1),
+    };
+    std::iter::from_fn(move || {
+        let current = next?;
+        next = if current == 0 {
+            None
+        } else {
+            let c = current & current.wrapping_neg();
+            current
+                .checked_add(c)
+                .map(|r| (((r ^ current) >> 2) / c) | r)
+                .filter(|&x| x <= limit)
+        };
+        Some(current)
+    })
+}
+
+/// Every way to pick one item from each list, the last list varying fastest.
+pub fn cartesian_product<T: Clone>(lists: &[Vec<T>]) -> impl Iterator<Item = Vec<T>> + '_ {
+    let mut counters = (!lists.iter().any(|l| l.is_empty())).then(|| vec![0; lists.len()]);
+    std::iter::from_fn(move || {
+        let current = counters.clone()?;
+        let c = counters.as_mut().unwrap();
+        match (0..lists.len()).rev().find(|&i| c[i] + 1 < lists[i].len()) {
+            Some(i) => {
+                c[i] += 1;
+                c[i + 1..].fill(0);
+            }
+            None => counters = None,
+        }
+        Some(
+            current
+                .iter()
+                .zip(lists)
+                .map(|(&i, l)| l[i].clone())
+                .collect(),
+        )
+    })
+}
+
+/// Partitions of `n` into positive parts, each non-increasing, in reverse lexicographic order
+/// starting with `[n]`. `n = 0` has the single empty partition.
+pub fn partitions(n: usize) -> impl Iterator<Item = Vec<usize>> {
+    let mut next = Some(if n == 0 { vec![] } else { vec![n] });
+    std::iter::from_fn(move || {
+        let current = next.take()?;
+        let mut parts = current.clone();
+        let mut rest = 0;
+        while parts.last() == Some(&1) {
+            parts.pop();
+            rest += 1;
+        }
+        if let Some(last) = parts.pop() {
+            let part = last - 1;
+            parts.push(part);
+            rest += 1;
+            while rest >= part {
+                parts.push(part);
+                rest -= part;
+            }
+            if rest > 0 {
+                parts.push(rest);
+            }
+            next = Some(parts);
+        }
+        Some(current)
+    })
+}
+
+/// Ordered ways to write `n` as a sum of positive parts, `2^(n - 1)` of them.
+pub fn compositions(n: usize) -> impl Iterator<Item = Vec<usize>> {
+    assert!(n <= 64, "too many compositions");
+    let count = if n == 0 { 1 } else { 1u128 << (n - 1) };
+    (0..count).map(move |mask| {
+        // NOTE(lubo): Bit `i` of the mask cuts between unit `i` and `i + 1`.
+        let mut parts = vec![];
+        let mut part = 0;
+        for i in 0..n {
+            part += 1;
+            if i + 1 == n || mask & (1 << i) != 0 {
+                parts.push(part);
+                part = 0;
+            }
+        }
+        parts
+    })
+}
+
+/// Iterator over all subsets of a slice, see [`power_set`].
+#[derive(Debug, Clone)]
+pub struct PowerSet<'a, T> {
+    items: &'a [T],
+    mask: u64,
+    end: u64,
+}
+
+impl<T: Clone> Iterator for PowerSet<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.mask >= self.end {
+            return None;
+        }
+        let subset = (0..self.items.len())
+            .filter(|&i| self.mask & (1 << i) != 0)
+            .map(|i| self.items[i].clone())
+            .collect();
+        self.mask += 1;
+        Some(subset)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.end - self.mask).ok();
+        (remaining.unwrap_or(usize::MAX), remaining)
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for PowerSet<'_, T> {}
+
+/// All `2^n` subsets of `items`, subset number `m` holds the items at the set bits of `m`.
+/// At most 63 items.
+pub fn power_set<T: Clone>(items: &[T]) -> PowerSet<'_, T> {
+    assert!(items.len() < 64, "too many items");
+    PowerSet {
+        items,
+        mask: 0,
+        end: 1 << items.len(),
+    }
+}
+
+/// Sums of all subsets of `values`, indexed by the subset's bitmask.
+fn subset_sums(values: &[i64]) -> Vec<i128> {
+    let mut sums = vec![0i128; 1 << values.len()];
+    for mask in 1..sums.len() {
+        let low = mask.trailing_zeros() as usize;
+        sums[mask] = sums[mask & (mask - 1)] + values[low] as i128;
+    }
+    sums
+}
+
+/// Indices of some subset of `values` adding up to `target`, by meeting in the middle in
+/// `O(2^(n/2) n)`. Practical up to about 40 values.
+pub fn subset_sum(values: &[i64], target: i64) -> Option<Vec<usize>> {
+    assert!(values.len() <= 60, "too many values");
+    let (left, right) = values.split_at(values.len() / 2);
+    let left_sums = subset_sums(left);
+    let mut right_sums: Vec<(i128, usize)> = subset_sums(right)
+        .into_iter()
+        .enumerate()
+        .map(|(mask, sum)| (sum, mask))
+        .collect();
+    right_sums.sort_unstable();
+
+    for (left_mask, &sum) in left_sums.iter().enumerate() {
+        let need = target as i128 - sum;
+        let at = right_sums.partition_point(|&(s, _)| s < need);
+        if let Some(&(s, right_mask)) = right_sums.get(at)
+            && s == need
+        {
+            let indices = (0..left.len())
+                .filter(|&i| left_mask & (1 << i) != 0)
+                .chain(
+                    (0..right.len())
+                        .filter(|&i| right_mask & (1 << i) != 0)
+                        .map(|i| i + left.len()),
+                )
+                .collect();
+            return Some(indices);
+        }
+    }
+    None
+}
+
+/// Number of subsets of `values` adding up to `target`, the empty one included.
+pub fn count_subset_sums(values: &[i64], target: i64) -> u64 {
+    assert!(values.len() <= 60, "too many values");
+    let (left, right) = values.split_at(values.len() / 2);
+    let mut right_sums = subset_sums(right);
+    right_sums.sort_unstable();
+    subset_sums(left)
+        .into_iter()
+        .map(|sum| {
+            let need = target as i128 - sum;
+            let start = right_sums.partition_point(|&s| s < need);
+            let end = right_sums.partition_point(|&s| s <= need);
+            (end - start) as u64
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::Binomial;
+
+    #[test]
+    fn combination_counts() {
+        let items = ['a', 'b', 'c', 'd'];
+        let pairs: Vec<String> = combinations(&items, 2)
+            .map(|c| c.into_iter().collect())
+            .collect();
+        assert_eq!(pairs, vec!["ab", "ac", "ad", "bc", "bd", "cd"]);
+        assert_eq!(combinations(&items, 0).collect::<Vec<_>>(), vec![vec![]]);
+        assert_eq!(combinations(&items, 5).count(), 0);
+        for n in 0..10 {
+            for k in 0..=n {
+                assert_eq!(
+                    Some(combination_indices(n, k).count() as u64),
+                    u64::binomial(n as u64, k as u64)
+                );
+                assert_eq!(
+                    Some(k_subsets(n as u32, k as u32).count() as u64),
+                    u64::binomial(n as u64, k as u64)
+                );
+            }
+        }
+        assert_eq!(
+            k_subsets(4, 2).collect::<Vec<_>>(),
+            vec![0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]
+        );
+        assert_eq!(k_subsets(64, 64).collect::<Vec<_>>(), vec![u64::MAX]);
+        assert_eq!(k_subsets(64, 63).count(), 64);
+        assert_eq!(k_subsets(3, 4).count(), 0);
+    }
+
+    #[test]
+    fn products() {
+        let lists = vec![vec![1, 2], vec![10], vec![100, 200, 300]];
+        let sums: Vec<i32> = cartesian_product(&lists).map(|p| p.iter().sum()).collect();
+        assert_eq!(sums, vec![111, 211, 311, 112, 212, 312]);
+        assert_eq!(
+            cartesian_product::<u8>(&[]).collect::<Vec<_>>(),
+            vec![Vec::<u8>::new()]
+        );
+        assert_eq!(cartesian_product(&[vec![1], vec![]]).count(), 0);
+    }
+
+    #[test]
+    fn partitions_and_compositions() {
+        assert_eq!(
+            partitions(5).collect::<Vec<_>>(),
+            vec![
+                vec![5],
+                vec![4, 1],
+                vec![3, 2],
+                vec![3, 1, 1],
+                vec![2, 2, 1],
+                vec![2, 1, 1, 1],
+                vec![1, 1, 1, 1, 1],
+            ]
+        );
+        let counts: Vec<usize> = (0..12).map(|n| partitions(n).count()).collect();
+        assert_eq!(counts, vec![1, 1, 2, 3, 5, 7, 11, 15, 22, 30, 42, 56]);
+        assert!(partitions(9).all(|p| p.iter().sum::<usize>() == 9));
+
+        assert_eq!(
+            compositions(0).collect::<Vec<_>>(),
+            vec![Vec::<usize>::new()]
+        );
+        assert_eq!(
+            compositions(3).collect::<Vec<_>>(),
+            vec![vec![3], vec![1, 2], vec![2, 1], vec![1, 1, 1]]
+        );
+        assert_eq!(compositions(10).count(), 512);
+    }
+
+    #[test]
+    fn power_sets() {
+        let items = ['x', 'y', 'z'];
+        let subsets = power_set(&items);
+        assert_eq!(subsets.len(), 8);
+        let subsets: Vec<String> = subsets.map(|s| s.into_iter().collect()).collect();
+        assert_eq!(subsets, vec!["", "x", "y", "xy", "z", "xz", "yz", "xyz"]);
+        let mut iter = power_set(&items);
+        iter.next();
+        assert_eq!(iter.size_hint(), (7, Some(7)));
+        assert_eq!(power_set::<u8>(&[]).count(), 1);
+    }
+
+    #[test]
+    fn subset_sums() {
+        let values = [3, 34, 4, 12, 5, 2];
+        let found = subset_sum(&values, 9).unwrap();
+        assert_eq!(found.iter().map(|&i| values[i]).sum::<i64>(), 9);
+        assert_eq!(subset_sum(&values, 30), None);
+        assert_eq!(subset_sum(&values, 0), Some(vec![]));
+        assert_eq!(count_subset_sums(&values, 9), 2);
+        assert_eq!(count_subset_sums(&[1, -1, 2, -2], 0), 4);
+
+        let values: Vec<i64> = (0..34)
+            .map(|i| (i * 7919 % 1000) as i64 + 1_000_000_000_000)
+            .collect();
+        let target: i64 = [3, 8, 17, 22, 31, 33].iter().map(|&i| values[i]).sum();
+        let found = subset_sum(&values, target).unwrap();
+        assert_eq!(found.iter().map(|&i| values[i]).sum::<i64>(), target);
+        assert_eq!(found.len(), 6);
+        assert_eq!(subset_sum(&values, target + 1_000_000_000_000 * 34), None);
+
+        let small = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let brute = power_set(&small)
+            .filter(|s| s.iter().sum::<i64>() == 20)
+            .count();
+        assert!(brute > 1);
+        assert_eq!(count_subset_sums(&small, 20), brute as u64);
+    }
+}
@@ -0,0 +1,173 @@
+use std::{
+    cmp::Ordering,
+    ops::{Add, Mul, Sub},
+};
+
+use super::{math::Zero, vector::V2};
+
+fn cross<T>(o: V2<T>, a: V2<T>, b: V2<T>) -> T
+where
+    T: Copy + Sub<Output = T> + Mul<Output = T>,
+{
+    (a - o).wedge(b - o).signed_area()
+}
+
+fn distance_squared<T>(a: V2<T>, b: V2<T>) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let delta = a - b;
+    delta.inner(delta)
+}
+
+/// Convex hull by Andrew's monotone chain, counterclockwise starting from the lowest `x` (then
+/// `y`) point. Collinear boundary points and duplicates are dropped. Exact for integers as long
+/// as the cross products fit in `T`.
+pub fn convex_hull<T>(points: &[V2<T>]) -> Vec<V2<T>>
+where
+    T: Copy + PartialOrd + Zero + Sub<Output = T> + Mul<Output = T>,
+{
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| {
+        (a.x(), a.y())
+            .partial_cmp(&(b.x(), b.y()))
+            .unwrap_or(Ordering::Equal)
+    });
+    sorted.dedup();
+    if sorted.len() <= 2 {
+        return sorted;
+    }
+
+    let mut hull: Vec<V2<T>> = Vec::with_capacity(sorted.len() + 1);
+    let chain = |hull: &mut Vec<V2<T>>, p: V2<T>, floor: usize| {
+        while hull.len() >= floor + 2
+            && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= T::zero()
+        {
+            hull.pop();
+        }
+        hull.push(p);
+    };
+    for &p in &sorted {
+        chain(&mut hull, p, 0);
+    }
+    // NOTE(lubo): The upper chain starts from the last point of the lower one and ends back at
+    // the first point.
+    let floor = hull.len() - 1;
+    for &p in sorted.iter().rev().skip(1) {
+        chain(&mut hull, p, floor);
+    }
+    hull.pop();
+    hull
+}
+
+/// Twice the area of a convex polygon given counterclockwise (e.g. by [`convex_hull`]), exact
+/// for integers.
+pub fn doubled_area<T>(hull: &[V2<T>]) -> T
+where
+    T: Copy + Zero + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    (1..hull.len().saturating_sub(1)).fold(T::zero(), |acc, i| {
+        acc + cross(hull[0], hull[i], hull[i + 1])
+    })
+}
+
+/// Two points of a convex polygon given counterclockwise at the greatest distance from each
+/// other, found by rotating calipers in linear time.
+pub fn farthest_pair<T>(hull: &[V2<T>]) -> Option<(V2<T>, V2<T>)>
+where
+    T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let n = hull.len();
+    match n {
+        0 => return None,
+        1 => return Some((hull[0], hull[0])),
+        2 => return Some((hull[0], hull[1])),
+        _ => {}
+    }
+    let mut best = (hull[0], hull[1]);
+    let mut best_distance = distance_squared(hull[0], hull[1]);
+    let mut j = 1;
+    for i in 0..n {
+        let next = (i + 1) % n;
+        while cross(hull[i], hull[next], hull[(j + 1) % n]) > cross(hull[i], hull[next], hull[j]) {
+            j = (j + 1) % n;
+        }
+        for a in [i, next] {
+            let distance = distance_squared(hull[a], hull[j]);
+            if distance > best_distance {
+                best = (hull[a], hull[j]);
+                best_distance = distance;
+            }
+        }
+    }
+    Some(best)
+}
+
+/// Squared diameter of a convex polygon given counterclockwise, see [`farthest_pair`].
+pub fn diameter_squared<T>(hull: &[V2<T>]) -> Option<T>
+where
+    T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    farthest_pair(hull).map(|(a, b)| distance_squared(a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector::V2i32;
+
+    fn v(x: i32, y: i32) -> V2i32 {
+        V2i32::from_xy(x, y)
+    }
+
+    #[test]
+    fn hull() {
+        let points = [
+            v(0, 0),
+            v(4, 0),
+            v(2, 1),
+            v(4, 4),
+            v(2, 4),
+            v(0, 4),
+            v(1, 2),
+            v(4, 0),
+            v(3, 3),
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(hull, vec![v(0, 0), v(4, 0), v(4, 4), v(0, 4)]);
+        assert_eq!(doubled_area(&hull), 32);
+        assert_eq!(diameter_squared(&hull), Some(32));
+
+        assert_eq!(convex_hull::<i32>(&[]), vec![]);
+        assert_eq!(convex_hull(&[v(1, 1), v(1, 1)]), vec![v(1, 1)]);
+        assert_eq!(
+            convex_hull(&[v(0, 0), v(1, 1), v(2, 2), v(3, 3)]),
+            vec![v(0, 0), v(3, 3)]
+        );
+        assert_eq!(doubled_area(&[v(0, 0), v(3, 3)]), 0);
+    }
+
+    #[test]
+    fn calipers() {
+        let points: Vec<V2i32> = (0..200)
+            .map(|i| v((i * 7919) % 211 - 105, (i * 104729) % 173 - 86))
+            .collect();
+        let hull = convex_hull(&points);
+        let brute = points
+            .iter()
+            .flat_map(|&a| points.iter().map(move |&b| distance_squared(a, b)))
+            .max();
+        assert_eq!(diameter_squared(&hull), brute);
+        assert!(doubled_area(&hull) > 0);
+
+        let triangle = convex_hull(&[
+            V2::from_xy(0.0, 0.0),
+            V2::from_xy(1.0, 0.0),
+            V2::from_xy(0.0, 1.0),
+            V2::from_xy(0.2, 0.2),
+        ]);
+        assert_eq!(triangle.len(), 3);
+        assert_eq!(doubled_area(&triangle), 1.0);
+        assert_eq!(diameter_squared(&triangle), Some(2.0));
+    }
+}
@@ -0,0 +1,289 @@
+use std::{fmt::Display, str::FromStr};
+
+use super::vector::V2i32;
+
+/// Grid direction, listed counterclockwise starting from `+x`. `Up` is `+y`, matching
+/// [`crate::geometric_traits::Movement4Directions`]; use [`Direction4::to_screen_vector`] for
+/// text grids where rows grow downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Right,
+    Up,
+    Left,
+    Down,
+}
+
+/// Grid direction including diagonals, listed counterclockwise starting from `+x`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Right,
+    UpRight,
+    Up,
+    UpLeft,
+    Left,
+    DownLeft,
+    Down,
+    DownRight,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectionParseError(pub String);
+
+impl Display for DirectionParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not a direction.", self.0)
+    }
+}
+
+impl std::error::Error for DirectionParseError {}
+
+impl Direction4 {
+    pub const ALL: [Self; 4] = [Self::Right, Self::Up, Self::Left, Self::Down];
+
+    fn from_index(i: usize) -> Self {
+        Self::ALL[i % 4]
+    }
+
+    pub fn to_vector(self) -> V2i32 {
+        match self {
+            Self::Right => V2i32::from_xy(1, 0),
+            Self::Up => V2i32::from_xy(0, 1),
+            Self::Left => V2i32::from_xy(-1, 0),
+            Self::Down => V2i32::from_xy(0, -1),
+        }
+    }
+
+    /// Like [`Direction4::to_vector`], but `Up` is `-y`.
+    pub fn to_screen_vector(self) -> V2i32 {
+        let v = self.to_vector();
+        V2i32::from_xy(v.x(), -v.y())
+    }
+
+    /// Quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::from_index(self as usize + 1)
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::from_index(self as usize + 3)
+    }
+
+    pub fn turn_around(self) -> Self {
+        Self::from_index(self as usize + 2)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::Right | Self::Left)
+    }
+}
+
+/// Accepts arrows `>^<v`, `RULD` and compass letters `ENWS`, either case.
+impl TryFrom<char> for Direction4 {
+    type Error = DirectionParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c.to_ascii_uppercase() {
+            '>' | 'R' | 'E' => Ok(Self::Right),
+            '^' | 'U' | 'N' => Ok(Self::Up),
+            '<' | 'L' | 'W' => Ok(Self::Left),
+            'V' | 'D' | 'S' => Ok(Self::Down),
+            _ => Err(DirectionParseError(c.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction4 {
+    type Err = DirectionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.trim().chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => c.try_into(),
+            _ => Err(DirectionParseError(s.to_string())),
+        }
+    }
+}
+
+impl Display for Direction4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Self::Right => '>',
+            Self::Up => '^',
+            Self::Left => '<',
+            Self::Down => 'v',
+        };
+        write!(f, "{c}")
+    }
+}
+
+impl Direction8 {
+    pub const ALL: [Self; 8] = [
+        Self::Right,
+        Self::UpRight,
+        Self::Up,
+        Self::UpLeft,
+        Self::Left,
+        Self::DownLeft,
+        Self::Down,
+        Self::DownRight,
+    ];
+
+    fn from_index(i: usize) -> Self {
+        Self::ALL[i % 8]
+    }
+
+    pub fn to_vector(self) -> V2i32 {
+        match self {
+            Self::Right => V2i32::from_xy(1, 0),
+            Self::UpRight => V2i32::from_xy(1, 1),
+            Self::Up => V2i32::from_xy(0, 1),
+            Self::UpLeft => V2i32::from_xy(-1, 1),
+            Self::Left => V2i32::from_xy(-1, 0),
+            Self::DownLeft => V2i32::from_xy(-1, -1),
+            Self::Down => V2i32::from_xy(0, -1),
+            Self::DownRight => V2i32::from_xy(1, -1),
+        }
+    }
+
+    /// Like [`Direction8::to_vector`], but `Up` is `-y`.
+    pub fn to_screen_vector(self) -> V2i32 {
+        let v = self.to_vector();
+        V2i32::from_xy(v.x(), -v.y())
+    }
+
+    /// Eighth turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::from_index(self as usize + 1)
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::from_index(self as usize + 7)
+    }
+
+    pub fn turn_around(self) -> Self {
+        Self::from_index(self as usize + 4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        (self as usize) % 2 == 1
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(value: Direction4) -> Self {
+        Self::from_index(value as usize * 2)
+    }
+}
+
+impl TryFrom<Direction8> for Direction4 {
+    type Error = Direction8;
+
+    fn try_from(value: Direction8) -> Result<Self, Self::Error> {
+        if value.is_diagonal() {
+            Err(value)
+        } else {
+            Ok(Self::from_index(value as usize / 2))
+        }
+    }
+}
+
+/// Accepts everything [`Direction4`] does, plus pairs of them like `NE` or `UR`, either order.
+impl FromStr for Direction8 {
+    type Err = DirectionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || DirectionParseError(s.to_string());
+        let parsed: Vec<Direction4> = s
+            .trim()
+            .chars()
+            .map(Direction4::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(|_| error())?;
+        match parsed[..] {
+            [a] => Ok(a.into()),
+            [a, b] if a.is_horizontal() != b.is_horizontal() => {
+                let v = a.to_vector() + b.to_vector();
+                Ok(*Self::ALL.iter().find(|d| d.to_vector() == v).unwrap())
+            }
+            _ => Err(error()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometric_traits::Movement4Directions;
+
+    #[test]
+    fn turning() {
+        for d in Direction4::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_left().turn_left(), d.turn_around());
+            assert_eq!(
+                d.turn_around().to_vector(),
+                V2i32::from_xy(0, 0) - d.to_vector()
+            );
+            assert_eq!(d.turn_left().to_vector(), d.to_vector().perp());
+            assert_eq!(Direction4::try_from(Direction8::from(d)), Ok(d));
+        }
+        for d in Direction8::ALL {
+            assert_eq!(d.turn_right().turn_left(), d);
+            assert_eq!(
+                d.turn_left().turn_left(),
+                d.turn_around().turn_right().turn_right()
+            );
+            assert_eq!(
+                d.turn_around().to_vector(),
+                V2i32::from_xy(0, 0) - d.to_vector()
+            );
+        }
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(
+            Direction4::try_from(Direction8::UpLeft),
+            Err(Direction8::UpLeft)
+        );
+    }
+
+    #[test]
+    fn parsing() {
+        let path: Vec<Direction4> = "^>vL".chars().map(|c| c.try_into().unwrap()).collect();
+        assert_eq!(
+            path,
+            [
+                Direction4::Up,
+                Direction4::Right,
+                Direction4::Down,
+                Direction4::Left
+            ]
+        );
+        assert_eq!("s".parse(), Ok(Direction4::Down));
+        assert_eq!("W".parse(), Ok(Direction4::Left));
+        assert!("x".parse::<Direction4>().is_err());
+        assert!("UL".parse::<Direction4>().is_err());
+        assert_eq!(
+            path.iter().map(|d| d.to_string()).collect::<String>(),
+            "^>v<"
+        );
+        assert_eq!(Direction4::Up.to_screen_vector(), V2i32::from_xy(0, -1));
+
+        assert_eq!("NE".parse(), Ok(Direction8::UpRight));
+        assert_eq!("ld".parse(), Ok(Direction8::DownLeft));
+        assert_eq!("S".parse(), Ok(Direction8::Down));
+        assert!("NS".parse::<Direction8>().is_err());
+        assert!("".parse::<Direction8>().is_err());
+    }
+
+    #[test]
+    fn stepping() {
+        let start = V2i32::from_xy(2, 3);
+        assert_eq!(start.step(Direction4::Up), Some(V2i32::from_xy(2, 4)));
+        assert_eq!(start.step(Direction4::Left), start.step_left());
+        assert_eq!(
+            start.step_n(Direction4::Right, 5),
+            Some(start + Direction4::Right.to_vector() * 5)
+        );
+        let edge = crate::vector::V2usize::from_xy(0, 0);
+        assert_eq!(edge.step(Direction4::Down), None);
+    }
+}
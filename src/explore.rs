@@ -1,4 +1,5 @@
 use super::{
+    cli::Progress,
     geometric_traits::{IterateNeighbours, IterateNeighboursContext},
     sketch::Bag,
 };
@@ -82,6 +83,7 @@ pub struct Exploration<P: IterateNeighbours<S>, S: IterateNeighboursContext, D>
     pub context: S,
     phantom: std::marker::PhantomData<P>,
     pub extra_data: D,
+    progress: Option<Progress<usize>>,
 }
 
 impl<P: Clone + Copy, S: IterateNeighboursContext, D> Exploration<P, S, D>
@@ -93,9 +95,20 @@ where
             context,
             phantom: std::marker::PhantomData,
             extra_data,
+            progress: None,
         }
     }
 
+    /// Report the number of expanded states while exploring.
+    pub fn with_progress(mut self, progress: Progress<usize>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    pub fn progress(&self) -> Option<&Progress<usize>> {
+        self.progress.as_ref()
+    }
+
     pub fn explore<F, G, B: Bag<P>>(&mut self, start: P, mut goal: G, mut filter_neighbours: F)
     where
         F: FnMut(&P, &P, &mut S, &mut D) -> bool,
@@ -160,6 +173,10 @@ where
                 ExploreSignals::Skip => continue,
             }
 
+            if let Some(progress) = self.progress.as_mut() {
+                progress.tick();
+            }
+
             for n in p.neighbours(&self.context) {
                 if filter_neighbours(&p, &n, &mut data, &mut self.context, &mut self.extra_data) {
                     open.put(n);
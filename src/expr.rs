@@ -1,9 +1,6 @@
-use std::{
-    collections::HashMap,
-    fmt::Debug,
-    ops::{Add, Div, Mul, Sub},
-    str::FromStr,
-};
+use std::{collections::HashMap, fmt::Debug, str::FromStr};
+
+use crate::math::NumOps;
 
 #[derive(Debug, PartialEq)]
 pub enum Expr<T> {
@@ -34,10 +31,7 @@ impl std::error::Error for EvalError {}
 
 impl<T> Expr<T>
 where
-    T: Add<Output = T>,
-    T: Sub<Output = T>,
-    T: Mul<Output = T>,
-    T: Div<Output = T>,
+    T: NumOps,
     T: FromStr,
     T: Copy,
     T: PartialEq,
@@ -1,9 +1,135 @@
 use std::{
     fmt::Display,
-    ops::{Add, Mul, Sub},
+    ops::{Add, Mul, Neg, Sub},
 };
 
-use super::vector::V3;
+use super::{
+    math::One,
+    vector::{V2, V3},
+};
+
+/// Element of the geometric algebra of the plane, `e1 * e1 = e2 * e2 = 1`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Multivector2<T> {
+    pub s: T,
+    pub x: T,
+    pub y: T,
+    pub xy: T,
+}
+
+impl<T: Default> Multivector2<T> {
+    pub fn scalar(s: T) -> Self {
+        Self {
+            s,
+            ..Default::default()
+        }
+    }
+
+    pub fn bivector(xy: T) -> Self {
+        Self {
+            xy,
+            ..Default::default()
+        }
+    }
+}
+
+impl<T: Default + Copy> Multivector2<T> {
+    pub fn from_vector(v: V2<T>) -> Self {
+        Self {
+            x: v.x(),
+            y: v.y(),
+            ..Default::default()
+        }
+    }
+
+    pub fn vector_part(&self) -> V2<T> {
+        V2::from_xy(self.x, self.y)
+    }
+
+    /// Keep only the components of grade `k`.
+    pub fn grade(&self, k: usize) -> Self {
+        let zero = T::default();
+        let keep = |g: usize, x: T| if g == k { x } else { zero };
+        Self {
+            s: keep(0, self.s),
+            x: keep(1, self.x),
+            y: keep(1, self.y),
+            xy: keep(2, self.xy),
+        }
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Multivector2<T> {
+    /// Reverse the order of vectors in every blade, negates the bivector part.
+    pub fn reverse(&self) -> Self {
+        Self {
+            xy: -self.xy,
+            ..*self
+        }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Multivector2<T> {
+    type Output = Self;
+
+    fn add(self, b: Self) -> Self::Output {
+        Self {
+            s: self.s + b.s,
+            x: self.x + b.x,
+            y: self.y + b.y,
+            xy: self.xy + b.xy,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Multivector2<T> {
+    type Output = Self;
+
+    fn sub(self, b: Self) -> Self::Output {
+        Self {
+            s: self.s - b.s,
+            x: self.x - b.x,
+            y: self.y - b.y,
+            xy: self.xy - b.xy,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Multivector2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            s: -self.s,
+            x: -self.x,
+            y: -self.y,
+            xy: -self.xy,
+        }
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Mul for Multivector2<T> {
+    type Output = Self;
+
+    fn mul(self, b: Self) -> Self::Output {
+        Self {
+            s: self.s * b.s + self.x * b.x + self.y * b.y - self.xy * b.xy,
+            x: self.s * b.x + self.x * b.s - self.y * b.xy + self.xy * b.y,
+            y: self.s * b.y + self.y * b.s + self.x * b.xy - self.xy * b.x,
+            xy: self.s * b.xy + self.xy * b.s + self.x * b.y - self.y * b.x,
+        }
+    }
+}
+
+impl<T: Copy + Default + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Mul<V2<T>>
+    for Multivector2<T>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: V2<T>) -> Self::Output {
+        self * Self::from_vector(rhs)
+    }
+}
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Multivector3<T> {
@@ -58,6 +184,41 @@ impl<T: Default + Copy> Multivector3<T> {
     }
 }
 
+impl<T: Default + Copy> Multivector3<T> {
+    pub fn vector_part(&self) -> V3<T> {
+        V3::from_xyz(self.x, self.y, self.z)
+    }
+
+    /// Keep only the components of grade `k`.
+    pub fn grade(&self, k: usize) -> Self {
+        let zero = T::default();
+        let keep = |g: usize, x: T| if g == k { x } else { zero };
+        Self {
+            s: keep(0, self.s),
+            x: keep(1, self.x),
+            y: keep(1, self.y),
+            z: keep(1, self.z),
+            xy: keep(2, self.xy),
+            yz: keep(2, self.yz),
+            xz: keep(2, self.xz),
+            xyz: keep(3, self.xyz),
+        }
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Multivector3<T> {
+    /// Reverse the order of vectors in every blade, negates the bivector and trivector parts.
+    pub fn reverse(&self) -> Self {
+        Self {
+            xy: -self.xy,
+            yz: -self.yz,
+            xz: -self.xz,
+            xyz: -self.xyz,
+            ..*self
+        }
+    }
+}
+
 impl<T: Add<Output = T>> Add for Multivector3<T> {
     type Output = Self;
 
@@ -75,6 +236,40 @@ impl<T: Add<Output = T>> Add for Multivector3<T> {
     }
 }
 
+impl<T: Sub<Output = T>> Sub for Multivector3<T> {
+    type Output = Self;
+
+    fn sub(self, b: Self) -> Self::Output {
+        Self {
+            s: self.s - b.s,
+            x: self.x - b.x,
+            y: self.y - b.y,
+            z: self.z - b.z,
+            xy: self.xy - b.xy,
+            yz: self.yz - b.yz,
+            xz: self.xz - b.xz,
+            xyz: self.xyz - b.xyz,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Multivector3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            s: -self.s,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            xy: -self.xy,
+            yz: -self.yz,
+            xz: -self.xz,
+            xyz: -self.xyz,
+        }
+    }
+}
+
 impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Mul for Multivector3<T> {
     type Output = Self;
 
@@ -126,6 +321,597 @@ impl<T: Copy + Default + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Mu
     }
 }
 
+// NOTE(lubo): Both products are defined blade by blade from the geometric product, for blades of
+// grades r and s the wedge keeps grade r + s and the dot (left contraction) keeps grade s - r.
+macro_rules! graded_products {
+    ($($mv:ident: $n:literal),*) => {
+        $(
+        impl<T> $mv<T>
+        where
+            T: Copy + Default,
+            T: Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+        {
+            /// Outer product `a ^ b`.
+            pub fn wedge(&self, b: &Self) -> Self {
+                let mut result = Self::default();
+                for r in 0..=$n {
+                    for s in 0..=($n - r) {
+                        result = result + (self.grade(r) * b.grade(s)).grade(r + s);
+                    }
+                }
+                result
+            }
+
+            /// Left contraction `a _| b`, for vectors this is the usual dot product.
+            pub fn dot(&self, b: &Self) -> Self {
+                let mut result = Self::default();
+                for r in 0..=$n {
+                    for s in r..=$n {
+                        result = result + (self.grade(r) * b.grade(s)).grade(s - r);
+                    }
+                }
+                result
+            }
+        })*
+    };
+}
+
+graded_products!(Multivector2: 2, Multivector3: 3);
+
+// NOTE(lubo): Duals are taken as `A * I^-1`, undo with `A * I`. In both algebras `I * I = -1`,
+// so `I^-1 = -I`.
+macro_rules! duals {
+    ($($mv:ident),*) => {
+        $(
+        impl<T> $mv<T>
+        where
+            T: Copy + Default + One + Neg<Output = T>,
+            T: Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+        {
+            /// Orthogonal complement, maps grade k to grade n - k.
+            pub fn dual(&self) -> Self {
+                -(*self * Self::pseudoscalar())
+            }
+
+            /// Inverse of [`Self::dual`].
+            pub fn undual(&self) -> Self {
+                *self * Self::pseudoscalar()
+            }
+        })*
+    };
+}
+
+duals!(Multivector2, Multivector3);
+
+// NOTE(lubo): For a blade `B`, `B * ~B` is a scalar, so `B^-1 = ~B / (B * ~B)`.
+macro_rules! float_blade_projections {
+    ($($mv:ident: $t:ty),*) => {
+        $(
+        impl $mv<$t> {
+            /// Inverse of a blade (a wedge of vectors), `None` for a zero blade.
+            pub fn blade_inverse(&self) -> Option<Self> {
+                let reverse = self.reverse();
+                let norm = (*self * reverse).s;
+                (norm != 0.0).then(|| reverse * Self::scalar(1.0 / norm))
+            }
+
+            /// Component of `self` lying in the subspace of `blade`, `(A _| B) B^-1`.
+            ///
+            /// Meant for `self` of grade at most that of `blade`, e.g. a vector onto a line or a
+            /// plane. `None` for a zero blade.
+            pub fn project_onto_blade(&self, blade: &Self) -> Option<Self> {
+                Some(self.dot(blade) * blade.blade_inverse()?)
+            }
+
+            /// Component of `self` orthogonal to the subspace of `blade`, the rest after
+            /// [`Self::project_onto_blade`].
+            pub fn reject_from_blade(&self, blade: &Self) -> Option<Self> {
+                Some(*self - self.project_onto_blade(blade)?)
+            }
+        })*
+    };
+}
+
+float_blade_projections!(
+    Multivector2: f32,
+    Multivector2: f64,
+    Multivector3: f32,
+    Multivector3: f64
+);
+
+impl<T: Default + One> Multivector2<T> {
+    /// Unit oriented area `e12`.
+    pub fn pseudoscalar() -> Self {
+        Self::bivector(T::one())
+    }
+}
+
+impl<T: Default + One> Multivector3<T> {
+    /// Unit oriented volume `e123`.
+    pub fn pseudoscalar() -> Self {
+        Self {
+            xyz: T::one(),
+            ..Default::default()
+        }
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> V2<T> {
+    /// Dual of a vector in the plane, the perpendicular `(y, -x)`.
+    pub fn dual(&self) -> Self {
+        V2::from_xy(self.y(), -self.x())
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> V3<T> {
+    /// Plane orthogonal to the vector. Dualizing twice negates in 3D, undo with
+    /// [`Bivector3::undual`].
+    pub fn dual(&self) -> Bivector3<T> {
+        Bivector3 {
+            xy: -self.z(),
+            yz: -self.x(),
+            xz: self.y(),
+        }
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Bivector3<T> {
+    /// Normal vector of the plane.
+    pub fn dual(&self) -> V3<T> {
+        V3::from_xyz(self.yz, -self.xz, self.xy)
+    }
+
+    /// Inverse of [`V3::dual`].
+    pub fn undual(&self) -> V3<T> {
+        V3::from_xyz(-self.yz, self.xz, -self.xy)
+    }
+}
+
+/// Oriented plane segment `xy * e12`, its value is the signed area.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bivector2<T> {
+    pub xy: T,
+}
+
+/// Oriented plane segment `xy * e12 + yz * e23 + xz * e13`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bivector3<T> {
+    pub xy: T,
+    pub yz: T,
+    pub xz: T,
+}
+
+impl<T: Copy + Sub<Output = T> + Mul<Output = T>> V2<T> {
+    /// Outer product, the oriented parallelogram spanned by `self` and `rhs`.
+    pub fn wedge(&self, rhs: Self) -> Bivector2<T> {
+        Bivector2 {
+            xy: self.x() * rhs.y() - self.y() * rhs.x(),
+        }
+    }
+}
+
+impl<T: Copy + Sub<Output = T> + Mul<Output = T>> V3<T> {
+    /// Outer product, the oriented parallelogram spanned by `self` and `rhs`.
+    pub fn wedge(&self, rhs: Self) -> Bivector3<T> {
+        Bivector3 {
+            xy: self.x() * rhs.y() - self.y() * rhs.x(),
+            yz: self.y() * rhs.z() - self.z() * rhs.y(),
+            xz: self.x() * rhs.z() - self.z() * rhs.x(),
+        }
+    }
+}
+
+impl<T: Copy> Bivector2<T> {
+    /// Positive when the spanning vectors turn counterclockwise.
+    pub fn signed_area(&self) -> T {
+        self.xy
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Mul<Output = T>> Bivector3<T> {
+    pub fn magnitude_squared(&self) -> T {
+        self.xy * self.xy + self.yz * self.yz + self.xz * self.xz
+    }
+}
+
+macro_rules! float_bivectors {
+    ($($t:ty),*) => {
+        $(
+        impl Bivector2<$t> {
+            /// Unsigned area.
+            pub fn magnitude(&self) -> $t {
+                self.xy.abs()
+            }
+
+            /// Unit bivector with the same orientation, zero stays zero.
+            pub fn normalized(&self) -> Self {
+                Self {
+                    xy: if self.xy == 0.0 { 0.0 } else { self.xy.signum() },
+                }
+            }
+        }
+
+        impl Bivector3<$t> {
+            /// Unsigned area.
+            pub fn magnitude(&self) -> $t {
+                self.magnitude_squared().sqrt()
+            }
+
+            /// Unit bivector in the same plane with the same orientation, zero stays zero.
+            pub fn normalized(&self) -> Self {
+                let magnitude = self.magnitude();
+                if magnitude > 0.0 {
+                    *self * (1.0 / magnitude)
+                } else {
+                    *self
+                }
+            }
+        })*
+    };
+}
+
+float_bivectors!(f32, f64);
+
+impl<T: Add<Output = T>> Add for Bivector2<T> {
+    type Output = Self;
+
+    fn add(self, b: Self) -> Self::Output {
+        Self { xy: self.xy + b.xy }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Bivector3<T> {
+    type Output = Self;
+
+    fn add(self, b: Self) -> Self::Output {
+        Self {
+            xy: self.xy + b.xy,
+            yz: self.yz + b.yz,
+            xz: self.xz + b.xz,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Bivector2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { xy: -self.xy }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Bivector3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            xy: -self.xy,
+            yz: -self.yz,
+            xz: -self.xz,
+        }
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Bivector2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self { xy: self.xy * rhs }
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Bivector3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            xy: self.xy * rhs,
+            yz: self.yz * rhs,
+            xz: self.xz * rhs,
+        }
+    }
+}
+
+impl<T: Default> From<Bivector2<T>> for Multivector2<T> {
+    fn from(b: Bivector2<T>) -> Self {
+        Self::bivector(b.xy)
+    }
+}
+
+impl<T: Default> From<Bivector3<T>> for Multivector3<T> {
+    fn from(b: Bivector3<T>) -> Self {
+        Self {
+            xy: b.xy,
+            yz: b.yz,
+            xz: b.xz,
+            ..Default::default()
+        }
+    }
+}
+
+impl<T: Copy> Multivector3<T> {
+    pub fn bivector_part(&self) -> Bivector3<T> {
+        Bivector3 {
+            xy: self.xy,
+            yz: self.yz,
+            xz: self.xz,
+        }
+    }
+}
+
+/// Even element of the 2D algebra `s + xy * e12`, rotates vectors by the sandwich `R v ~R`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotor2<T> {
+    pub s: T,
+    pub xy: T,
+}
+
+/// Even element of the 3D algebra, rotates vectors by the sandwich `R v ~R`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotor3<T> {
+    pub s: T,
+    pub xy: T,
+    pub yz: T,
+    pub xz: T,
+}
+
+// NOTE(lubo): Composition follows matrices, `(b * a).rotate(v) == b.rotate(a.rotate(v))`.
+macro_rules! float_rotors {
+    ($($t:ty),*) => {
+        $(
+        impl Rotor2<$t> {
+            pub fn identity() -> Self {
+                Self { s: 1.0, xy: 0.0 }
+            }
+
+            /// Counterclockwise rotation by `angle` radians, x goes towards y.
+            pub fn from_angle(angle: $t) -> Self {
+                let (sin, cos) = (angle / 2.0).sin_cos();
+                Self { s: cos, xy: -sin }
+            }
+
+            /// Smallest rotation taking the direction of `from` to the direction of `to`.
+            pub fn from_vectors(from: V2<$t>, to: V2<$t>) -> Self {
+                let a = from * (1.0 / from.inner(from).sqrt());
+                let b = to * (1.0 / to.inner(to).sqrt());
+                let halfway = Multivector2::scalar(1.0) + Multivector2::from_vector(b) * a;
+                let rotor = Self {
+                    s: halfway.s,
+                    xy: halfway.xy,
+                };
+                if rotor.magnitude() > <$t>::EPSILON {
+                    rotor.normalized()
+                } else {
+                    Self { s: 0.0, xy: 1.0 }
+                }
+            }
+
+            pub fn to_multivector(&self) -> Multivector2<$t> {
+                Multivector2 {
+                    s: self.s,
+                    xy: self.xy,
+                    ..Default::default()
+                }
+            }
+
+            pub fn magnitude(&self) -> $t {
+                (self.s * self.s + self.xy * self.xy).sqrt()
+            }
+
+            pub fn normalized(&self) -> Self {
+                let magnitude = self.magnitude();
+                Self {
+                    s: self.s / magnitude,
+                    xy: self.xy / magnitude,
+                }
+            }
+
+            /// Inverse of a unit rotor.
+            pub fn reverse(&self) -> Self {
+                Self {
+                    s: self.s,
+                    xy: -self.xy,
+                }
+            }
+
+            pub fn rotate(&self, v: V2<$t>) -> V2<$t> {
+                let r = self.to_multivector();
+                (r * v * r.reverse()).vector_part()
+            }
+        }
+
+        impl Mul for Rotor2<$t> {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                let product = self.to_multivector() * rhs.to_multivector();
+                Self {
+                    s: product.s,
+                    xy: product.xy,
+                }
+            }
+        }
+
+        impl Rotor3<$t> {
+            pub fn identity() -> Self {
+                Self {
+                    s: 1.0,
+                    xy: 0.0,
+                    yz: 0.0,
+                    xz: 0.0,
+                }
+            }
+
+            /// Rotation by `angle` radians in `plane`, oriented so that for `x.wedge(y)` x goes
+            /// towards y.
+            pub fn from_plane_angle(plane: Bivector3<$t>, angle: $t) -> Self {
+                let (sin, cos) = (angle / 2.0).sin_cos();
+                let b = plane.normalized();
+                Self {
+                    s: cos,
+                    xy: -sin * b.xy,
+                    yz: -sin * b.yz,
+                    xz: -sin * b.xz,
+                }
+            }
+
+            /// Smallest rotation taking the direction of `from` to the direction of `to`.
+            ///
+            /// For opposite vectors the rotation plane is chosen arbitrarily.
+            pub fn from_vectors(from: V3<$t>, to: V3<$t>) -> Self {
+                let a = from * (1.0 / from.inner(from).sqrt());
+                let b = to * (1.0 / to.inner(to).sqrt());
+                let halfway = Multivector3::scalar(1.0) + Multivector3::from_vector(b) * a;
+                let rotor = Self::from_even(halfway);
+                if rotor.magnitude() > <$t>::EPSILON {
+                    return rotor.normalized();
+                }
+
+                // NOTE(lubo): Half turn around any axis perpendicular to `a`.
+                let helper = if a.x().abs() < 0.9 {
+                    V3::from_xyz(1.0, 0.0, 0.0)
+                } else {
+                    V3::from_xyz(0.0, 1.0, 0.0)
+                };
+                Self::from_plane_angle(a.wedge(helper), std::f64::consts::PI as $t)
+            }
+
+            fn from_even(m: Multivector3<$t>) -> Self {
+                Self {
+                    s: m.s,
+                    xy: m.xy,
+                    yz: m.yz,
+                    xz: m.xz,
+                }
+            }
+
+            pub fn to_multivector(&self) -> Multivector3<$t> {
+                Multivector3 {
+                    s: self.s,
+                    xy: self.xy,
+                    yz: self.yz,
+                    xz: self.xz,
+                    ..Default::default()
+                }
+            }
+
+            pub fn magnitude(&self) -> $t {
+                (self.s * self.s + self.xy * self.xy + self.yz * self.yz + self.xz * self.xz).sqrt()
+            }
+
+            pub fn normalized(&self) -> Self {
+                let magnitude = self.magnitude();
+                Self {
+                    s: self.s / magnitude,
+                    xy: self.xy / magnitude,
+                    yz: self.yz / magnitude,
+                    xz: self.xz / magnitude,
+                }
+            }
+
+            /// Inverse of a unit rotor.
+            pub fn reverse(&self) -> Self {
+                Self {
+                    s: self.s,
+                    xy: -self.xy,
+                    yz: -self.yz,
+                    xz: -self.xz,
+                }
+            }
+
+            pub fn rotate(&self, v: V3<$t>) -> V3<$t> {
+                let r = self.to_multivector();
+                (r * v * r.reverse()).vector_part()
+            }
+        }
+
+        impl Mul for Rotor3<$t> {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self::from_even(self.to_multivector() * rhs.to_multivector())
+            }
+        })*
+    };
+}
+
+float_rotors!(f32, f64);
+
+// NOTE(lubo): A rotation by `angle` in the unit plane `B` is `exp(-B * angle / 2)`, so `log`
+// returns half of the rotation angle (with a minus sign) as the bivector magnitude.
+macro_rules! float_rotor_exp {
+    ($($t:ty),*) => {
+        $(
+        impl Rotor2<$t> {
+            /// `exp(b) = cos |b| + sin |b| * b / |b|`.
+            pub fn exp(b: Bivector2<$t>) -> Self {
+                let (sin, cos) = b.xy.sin_cos();
+                Self { s: cos, xy: sin }
+            }
+
+            /// Inverse of [`Rotor2::exp`] for unit rotors, the angle is in `[-pi, pi]`.
+            pub fn log(&self) -> Bivector2<$t> {
+                Bivector2 {
+                    xy: self.xy.atan2(self.s),
+                }
+            }
+
+            /// Interpolate along the shorter arc between unit rotors `a` (at `t = 0`) and `b`.
+            pub fn slerp(a: Self, b: Self, t: $t) -> Self {
+                let mut delta = b * a.reverse();
+                if delta.s < 0.0 {
+                    delta = Self {
+                        s: -delta.s,
+                        xy: -delta.xy,
+                    };
+                }
+                Self::exp(delta.log() * t) * a
+            }
+        }
+
+        impl Rotor3<$t> {
+            /// `exp(b) = cos |b| + sin |b| * b / |b|`.
+            pub fn exp(b: Bivector3<$t>) -> Self {
+                let angle = b.magnitude();
+                let (sin, cos) = angle.sin_cos();
+                let b = b.normalized() * sin;
+                Self {
+                    s: cos,
+                    xy: b.xy,
+                    yz: b.yz,
+                    xz: b.xz,
+                }
+            }
+
+            /// Inverse of [`Rotor3::exp`] for unit rotors, the magnitude is in `[0, pi]`.
+            pub fn log(&self) -> Bivector3<$t> {
+                let b = Bivector3 {
+                    xy: self.xy,
+                    yz: self.yz,
+                    xz: self.xz,
+                };
+                b.normalized() * b.magnitude().atan2(self.s)
+            }
+
+            /// Interpolate along the shorter arc between unit rotors `a` (at `t = 0`) and `b`.
+            pub fn slerp(a: Self, b: Self, t: $t) -> Self {
+                let mut delta = b * a.reverse();
+                if delta.s < 0.0 {
+                    delta = Self {
+                        s: -delta.s,
+                        xy: -delta.xy,
+                        yz: -delta.yz,
+                        xz: -delta.xz,
+                    };
+                }
+                Self::exp(delta.log() * t) * a
+            }
+        })*
+    };
+}
+
+float_rotor_exp!(f32, f64);
+
 impl Display for Multivector3<f32> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let a = [
@@ -185,3 +971,276 @@ impl Display for Multivector3<i32> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use super::{Bivector2, Bivector3, Multivector2, Multivector3, Rotor2, Rotor3};
+    use crate::vector::{V2, V3, Vector};
+
+    fn unit<const C: usize>(v: Vector<C, f64>) -> Vector<C, f64> {
+        v * (1.0 / v.inner(v).sqrt())
+    }
+
+    fn assert_close<const C: usize>(a: Vector<C, f64>, b: Vector<C, f64>) {
+        let d = a - b;
+        assert!(d.inner(d) < 1e-20, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn bivectors() {
+        let a = V2::from_xy(3, 1);
+        let b = V2::from_xy(1, 2);
+        assert_eq!(a.wedge(b), Bivector2 { xy: 5 });
+        assert_eq!(b.wedge(a).signed_area(), -5);
+        assert_eq!(a.wedge(b).signed_area(), a.winding(b));
+        assert_eq!(
+            Multivector2::from(a.wedge(b)),
+            Multivector2::from_vector(a).wedge(&Multivector2::from_vector(b))
+        );
+        assert_eq!(
+            Bivector2 { xy: -2.5f32 }.normalized(),
+            Bivector2 { xy: -1.0 }
+        );
+        assert_eq!(Bivector2 { xy: -2.5f32 }.magnitude(), 2.5);
+
+        let a = V3::from_xyz(1, 0, 2);
+        let b = V3::from_xyz(0, 3, 1);
+        assert_eq!(
+            Multivector3::from(a.wedge(b)),
+            Multivector3::from_vector(a).wedge(&Multivector3::from_vector(b))
+        );
+        assert_eq!(a.wedge(a), Bivector3::default());
+        assert_eq!(-a.wedge(b), b.wedge(a));
+        assert_eq!(a.wedge(b).magnitude_squared(), 46);
+
+        let c = V3::from_xyz(0.0f64, 3.0, 4.0).wedge(V3::from_xyz(1.0, 0.0, 0.0));
+        assert_eq!(c.magnitude(), 5.0);
+        assert_eq!(c.normalized().magnitude(), 1.0);
+    }
+
+    #[test]
+    fn duals() {
+        let i2 = Multivector2::<i32>::pseudoscalar();
+        let i3 = Multivector3::<i32>::pseudoscalar();
+        assert_eq!(i2 * i2, Multivector2::scalar(-1));
+        assert_eq!(i3 * i3, Multivector3::scalar(-1));
+        assert_eq!(Multivector2::scalar(1).dual(), -i2);
+
+        let a = Multivector2::from_vector(V2::from_xy(3, 4));
+        assert_eq!(a.dual().undual(), a);
+        assert_eq!(
+            a.dual(),
+            Multivector2::from_vector(V2::from_xy(3, 4).dual())
+        );
+        assert_eq!(V2::from_xy(3, 4).dual().inner(V2::from_xy(3, 4)), 0);
+
+        let v = V3::from_xyz(1, -2, 3);
+        let m = Multivector3::from_vector(v);
+        assert_eq!(m.dual(), Multivector3::from(v.dual()));
+        assert_eq!(m.dual().undual(), m);
+        assert_eq!(v.dual().undual(), v);
+        assert_eq!(v.dual().dual(), V3::from_xyz(-1, 2, -3));
+
+        let b = V3::from_xyz(1, 0, 2).wedge(V3::from_xyz(0, 3, 1));
+        let normal = b.dual();
+        assert_eq!(
+            Multivector3::from(b).dual(),
+            Multivector3::from_vector(normal)
+        );
+        assert_eq!(normal.inner(V3::from_xyz(1, 0, 2)), 0);
+        assert_eq!(normal.inner(V3::from_xyz(0, 3, 1)), 0);
+        assert_eq!(
+            Multivector3::from_vector(normal).undual(),
+            Multivector3::from(b)
+        );
+    }
+
+    #[test]
+    fn blade_projections() {
+        let v = Multivector3::from_vector(V3::from_xyz(3.0f64, 4.0, 5.0));
+        let line = Multivector3::from_vector(V3::from_xyz(2.0, 0.0, 0.0));
+        let plane =
+            Multivector3::from(V3::from_xyz(1.0, 1.0, 0.0).wedge(V3::from_xyz(0.0, 2.0, 0.0)));
+
+        let on_line = v.project_onto_blade(&line).unwrap();
+        assert_eq!(on_line.vector_part(), V3::from_xyz(3.0, 0.0, 0.0));
+        assert_eq!(on_line.grade(1), on_line);
+        assert_eq!(
+            v.reject_from_blade(&line).unwrap().vector_part(),
+            V3::from_xyz(0.0, 4.0, 5.0)
+        );
+
+        let on_plane = v.project_onto_blade(&plane).unwrap();
+        assert_eq!(on_plane.vector_part(), V3::from_xyz(3.0, 4.0, 0.0));
+        assert_eq!(
+            v.reject_from_blade(&plane).unwrap(),
+            Multivector3::z_axis(5.0)
+        );
+
+        let b =
+            Multivector3::from(V3::from_xyz(1.0f64, 2.0, 0.5).wedge(V3::from_xyz(0.0, 1.0, 3.0)));
+        let volume = Multivector3::<f64>::pseudoscalar() * Multivector3::scalar(2.0);
+        assert_eq!(b.project_onto_blade(&volume), Some(b));
+        assert_eq!(v.project_onto_blade(&Multivector3::default()), None);
+
+        let w = Multivector2::from_vector(V2::from_xy(1.0f32, 3.0));
+        let diagonal = Multivector2::from_vector(V2::from_xy(1.0, 1.0));
+        assert_eq!(
+            w.project_onto_blade(&diagonal).unwrap().vector_part(),
+            V2::from_xy(2.0, 2.0)
+        );
+        assert_eq!(
+            w.reject_from_blade(&diagonal).unwrap().vector_part(),
+            V2::from_xy(-1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn rotor2() {
+        let quarter = Rotor2::<f64>::from_angle(FRAC_PI_2);
+        assert_close(quarter.rotate(V2::from_xy(1.0, 0.0)), V2::from_xy(0.0, 1.0));
+        assert_close(
+            quarter.rotate(V2::from_xy(2.0, 3.0)),
+            V2::from_xy(-3.0, 2.0),
+        );
+        assert_close(
+            (quarter * quarter).rotate(V2::from_xy(1.0, 0.0)),
+            V2::from_xy(-1.0, 0.0),
+        );
+        assert_close(
+            (quarter * quarter.reverse()).rotate(V2::from_xy(2.0, 3.0)),
+            V2::from_xy(2.0, 3.0),
+        );
+
+        let from = V2::from_xy(3.0, 1.0);
+        let to = V2::from_xy(-1.0, 2.0);
+        let r = Rotor2::<f64>::from_vectors(from, to);
+        assert!((r.magnitude() - 1.0).abs() < 1e-12);
+        assert_close(unit(r.rotate(from)), unit(to));
+        let opposite = Rotor2::<f64>::from_vectors(from, from * -1.0);
+        assert_close(opposite.rotate(from), from * -1.0);
+        assert_eq!(
+            Rotor2::<f32>::identity().rotate(V2::from_xy(1.0, 2.0)),
+            V2::from_xy(1.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn rotor_exp_log_slerp() {
+        let a = Rotor2::<f64>::from_angle(0.25);
+        let b = Rotor2::<f64>::from_angle(1.75);
+        assert_close(
+            Rotor2::<f64>::exp(a.log()).to_multivector().vector_part(),
+            a.to_multivector().vector_part(),
+        );
+        assert!((Rotor2::<f64>::exp(b.log()).s - b.s).abs() < 1e-12);
+        let half = Rotor2::<f64>::slerp(a, b, 0.5);
+        let expected = Rotor2::<f64>::from_angle(1.0);
+        assert!((half.s - expected.s).abs() < 1e-12 && (half.xy - expected.xy).abs() < 1e-12);
+        // NOTE(lubo): The shorter way from 0.1 to 6.0 radians crosses zero.
+        let wrap = Rotor2::<f64>::slerp(
+            Rotor2::<f64>::from_angle(0.1),
+            Rotor2::<f64>::from_angle(6.0),
+            0.5,
+        );
+        let v = wrap.rotate(V2::from_xy(1.0, 0.0));
+        let angle = (0.1 + 6.0 - 2.0 * PI) / 2.0;
+        assert_close(v, V2::from_xy(angle.cos(), angle.sin()));
+
+        let plane = V3::from_xyz(1.0, 2.0, 0.5).wedge(V3::from_xyz(-1.0, 0.0, 3.0));
+        let a = Rotor3::<f64>::from_plane_angle(plane, 0.4);
+        let b = Rotor3::<f64>::from_plane_angle(plane, 2.0);
+        let c = Rotor3::<f64>::from_plane_angle(plane.normalized() * -1.0, 0.7);
+        let v = V3::from_xyz(0.3, -1.0, 2.0);
+        for r in [a, b, c] {
+            assert_close(Rotor3::<f64>::exp(r.log()).rotate(v), r.rotate(v));
+        }
+        assert_close(
+            Rotor3::<f64>::exp(plane.normalized() * -0.2).rotate(v),
+            a.rotate(v),
+        );
+        assert_close(Rotor3::<f64>::slerp(a, b, 0.0).rotate(v), a.rotate(v));
+        assert_close(Rotor3::<f64>::slerp(a, b, 1.0).rotate(v), b.rotate(v));
+        assert_close(
+            Rotor3::<f64>::slerp(a, b, 0.5).rotate(v),
+            Rotor3::<f64>::from_plane_angle(plane, 1.2).rotate(v),
+        );
+    }
+
+    #[test]
+    fn rotor3() {
+        let xy = V3::from_xyz(1.0, 0.0, 0.0).wedge(V3::from_xyz(0.0, 1.0, 0.0));
+        let quarter = Rotor3::<f64>::from_plane_angle(xy, FRAC_PI_2);
+        assert_close(
+            quarter.rotate(V3::from_xyz(1.0, 0.0, 5.0)),
+            V3::from_xyz(0.0, 1.0, 5.0),
+        );
+
+        let a = Rotor3::<f64>::from_plane_angle(xy * 3.0, 0.3);
+        let yz = Bivector3 {
+            yz: 1.0,
+            ..Default::default()
+        };
+        let b = Rotor3::<f64>::from_plane_angle(yz, 1.1);
+        let v = V3::from_xyz(0.5, -2.0, 1.5);
+        assert_close((b * a).rotate(v), b.rotate(a.rotate(v)));
+        assert_close((b * a).reverse().rotate((b * a).rotate(v)), v);
+
+        for (from, to) in [
+            (V3::from_xyz(1.0, 2.0, 3.0), V3::from_xyz(-2.0, 0.5, 1.0)),
+            (V3::from_xyz(1.0, 2.0, 3.0), V3::from_xyz(-1.0, -2.0, -3.0)),
+            (V3::from_xyz(1.0, 0.0, 0.0), V3::from_xyz(-1.0, 0.0, 0.0)),
+        ] {
+            let r = Rotor3::<f64>::from_vectors(from, to);
+            assert!((r.magnitude() - 1.0).abs() < 1e-12);
+            assert_close(unit(r.rotate(from)), unit(to));
+        }
+
+        let half = Rotor3::<f64>::from_plane_angle(yz, PI);
+        assert_close(
+            half.rotate(V3::from_xyz(1.0, 1.0, 1.0)),
+            V3::from_xyz(1.0, -1.0, -1.0),
+        );
+    }
+
+    #[test]
+    fn multivector2_products() {
+        let a = Multivector2::from_vector(V2::from_xy(1, 2));
+        let b = Multivector2::from_vector(V2::from_xy(3, 4));
+        assert_eq!(a * b, Multivector2::scalar(11) + Multivector2::bivector(-2));
+        assert_eq!(a.dot(&b), Multivector2::scalar(11));
+        assert_eq!(a.wedge(&b), Multivector2::bivector(-2));
+        assert_eq!(a.wedge(&b), -b.wedge(&a));
+        assert_eq!(a.wedge(&a), Multivector2::default());
+
+        let i = Multivector2::bivector(1);
+        assert_eq!(i * i, Multivector2::scalar(-1));
+        assert_eq!(i.reverse(), -i);
+        assert_eq!((a * b).grade(0), a.dot(&b));
+    }
+
+    #[test]
+    fn multivector3_products() {
+        let a = Multivector3::from_vector(V3::from_xyz(1, 0, 2));
+        let b = Multivector3::from_vector(V3::from_xyz(0, 3, 1));
+        let c = Multivector3::from_vector(V3::from_xyz(1, 1, 1));
+
+        assert_eq!(a * b, a.dot(&b) + a.wedge(&b));
+        assert_eq!(a.dot(&b), Multivector3::scalar(2));
+        let ab = a.wedge(&b);
+        assert_eq!((ab.xy, ab.yz, ab.xz), (3, -6, 1));
+
+        // NOTE(lubo): Volume of the parallelepiped spanned by a, b, c.
+        let volume = a.wedge(&b).wedge(&c);
+        assert_eq!(volume, a.wedge(&b.wedge(&c)));
+        assert_eq!(volume.grade(3), volume);
+        assert_eq!(volume.xyz, -4);
+
+        assert_eq!((a * b).reverse(), b * a);
+        let abc = a * b * c;
+        assert_eq!(abc.reverse(), c * b * a);
+        assert_eq!(c.dot(&ab), (c * ab).grade(1));
+    }
+}
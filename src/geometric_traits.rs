@@ -1,3 +1,5 @@
+use super::direction::Direction4;
+
 pub trait CoverObject<T> {
     fn cover(&mut self, object: &T);
 }
@@ -65,6 +67,23 @@ where
         }
         Some(result)
     }
+
+    fn step(&self, direction: Direction4) -> Option<Self> {
+        match direction {
+            Direction4::Right => self.step_right(),
+            Direction4::Up => self.step_up(),
+            Direction4::Left => self.step_left(),
+            Direction4::Down => self.step_down(),
+        }
+    }
+    fn step_n(&self, direction: Direction4, n: usize) -> Option<Self> {
+        match direction {
+            Direction4::Right => self.step_right_n(n),
+            Direction4::Up => self.step_up_n(n),
+            Direction4::Left => self.step_left_n(n),
+            Direction4::Down => self.step_down_n(n),
+        }
+    }
 }
 
 // // NOTE(lubo): With no context, we can move as far as the range of the underlying type allows us.
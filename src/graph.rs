@@ -0,0 +1,202 @@
+use std::hash::Hash;
+
+use crate::{
+    arraynd::ArrayNd,
+    bijection::Interner,
+    geometric_traits::{IterateNeighbours, IterateNeighboursContext},
+    linear_index::LinearIndex,
+    vector::Vector,
+};
+
+/// Id of a node interned in a [`Graph`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NodeId(pub usize);
+
+/// Directed graph with interned nodes and compressed (CSR) adjacency.
+#[derive(Clone, Debug)]
+pub struct Graph<N, E> {
+    nodes: Interner<N>,
+    offsets: Vec<usize>,
+    targets: Vec<NodeId>,
+    edges: Vec<E>,
+}
+
+impl<N: Eq + Hash + Clone, E> Graph<N, E> {
+    pub fn from_edges<I: IntoIterator<Item = (N, N, E)>>(edges: I) -> Self {
+        Self::with_nodes(std::iter::empty(), edges)
+    }
+
+    /// Like [`Graph::from_edges`], but also keeps nodes that have no edges.
+    pub fn with_nodes<M, I>(nodes: M, edges: I) -> Self
+    where
+        M: IntoIterator<Item = N>,
+        I: IntoIterator<Item = (N, N, E)>,
+    {
+        let mut interner: Interner<N> = nodes.into_iter().collect();
+        let list: Vec<(usize, usize, E)> = edges
+            .into_iter()
+            .map(|(a, b, e)| (interner.intern(a), interner.intern(b), e))
+            .collect();
+
+        let mut offsets = vec![0; interner.len() + 1];
+        for &(a, _, _) in &list {
+            offsets[a + 1] += 1;
+        }
+        for i in 1..offsets.len() {
+            offsets[i] += offsets[i - 1];
+        }
+
+        let mut next = offsets.clone();
+        let mut slots: Vec<Option<(NodeId, E)>> = (0..list.len()).map(|_| None).collect();
+        for (a, b, e) in list {
+            slots[next[a]] = Some((NodeId(b), e));
+            next[a] += 1;
+        }
+        let (targets, edges) = slots.into_iter().map(Option::unwrap).unzip();
+
+        Self {
+            nodes: interner,
+            offsets,
+            targets,
+            edges,
+        }
+    }
+
+    pub fn id(&self, node: &N) -> Option<NodeId> {
+        self.nodes.id(node).map(NodeId)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeId, &N)> {
+        self.nodes.iter().map(|(i, n)| (NodeId(i), n))
+    }
+}
+
+impl<N: Eq + Hash + Clone, E: Clone> Graph<N, E> {
+    /// Every edge is added in both directions.
+    pub fn from_undirected_edges<I: IntoIterator<Item = (N, N, E)>>(edges: I) -> Self {
+        Self::from_edges(
+            edges
+                .into_iter()
+                .flat_map(|(a, b, e)| [(a.clone(), b.clone(), e.clone()), (b, a, e)]),
+        )
+    }
+}
+
+impl<N, E> Graph<N, E> {
+    pub fn node_count(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.targets.len()
+    }
+
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id.0]
+    }
+
+    pub fn degree(&self, id: NodeId) -> usize {
+        self.offsets[id.0 + 1] - self.offsets[id.0]
+    }
+
+    /// Outgoing edges of `id` as `(target, weight)`.
+    pub fn neighbours(&self, id: NodeId) -> impl Iterator<Item = (NodeId, &E)> {
+        let range = self.offsets[id.0]..self.offsets[id.0 + 1];
+        self.targets[range.clone()]
+            .iter()
+            .copied()
+            .zip(&self.edges[range])
+    }
+}
+
+impl<const C: usize> Graph<Vector<C, i32>, ()>
+where
+    Vector<C, i32>: IterateNeighbours<()>,
+{
+    /// Cells for which `passable` holds become nodes, connected to their passable
+    /// in-bounds neighbours.
+    pub fn from_array<T, F: Fn(&T) -> bool>(array: &ArrayNd<C, T>, passable: F) -> Self {
+        let cells: Vec<Vector<C, i32>> = (0..array.data.len())
+            .filter(|&i| passable(&array.data[i]))
+            .map(|i| array.unindex(i).unwrap())
+            .collect();
+        let edges: Vec<_> = cells
+            .iter()
+            .flat_map(|&p| {
+                p.neighbours(&())
+                    .into_iter()
+                    .filter(|n| array.get(*n).is_some_and(&passable))
+                    .map(move |n| (p, n, ()))
+            })
+            .collect();
+        Self::with_nodes(cells, edges)
+    }
+}
+
+impl<N, E> IterateNeighboursContext for Graph<N, E> {}
+
+impl<N, E> IterateNeighbours<Graph<N, E>> for NodeId {
+    fn neighbours(&self, context: &Graph<N, E>) -> Vec<Self> {
+        context.neighbours(*self).map(|(n, _)| n).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{Graph, NodeId};
+    use crate::{
+        arraynd::CharArray2d,
+        explore::{Exploration, ExploreSignals},
+        sketch::QueueBag,
+        vector::V2,
+    };
+
+    #[test]
+    fn adjacency() {
+        let g = Graph::from_edges([("a", "b", 1), ("b", "c", 2), ("a", "c", 5), ("c", "a", 3)]);
+        assert_eq!(g.node_count(), 3);
+        assert_eq!(g.edge_count(), 4);
+        let a = g.id(&"a").unwrap();
+        assert_eq!(g.degree(a), 2);
+        let out: Vec<_> = g.neighbours(a).map(|(n, &w)| (*g.node(n), w)).collect();
+        assert_eq!(out, vec![("b", 1), ("c", 5)]);
+        assert_eq!(g.id(&"d"), None);
+
+        let u = Graph::with_nodes(["x"], [] as [(&str, &str, ()); 0]);
+        assert_eq!(u.node_count(), 1);
+        assert_eq!(u.degree(NodeId(0)), 0);
+
+        let u = Graph::from_undirected_edges([(1, 2, ()), (2, 3, ())]);
+        assert_eq!(u.edge_count(), 4);
+        assert_eq!(u.degree(u.id(&2).unwrap()), 2);
+    }
+
+    #[test]
+    fn from_array_bfs() {
+        let map: CharArray2d = "..#\n#..\n...".parse().unwrap();
+        let g = Graph::from_array(&map, |&c| c == '.');
+        assert_eq!(g.node_count(), 7);
+        assert!(g.id(&V2::new([2, 0])).is_none());
+
+        let start = g.id(&V2::new([0, 0])).unwrap();
+        let mut distances = HashMap::from([(start, 0)]);
+        let mut exploration = Exploration::new(g, ());
+        exploration.explore::<_, _, QueueBag<_>>(
+            start,
+            |_, _, _| ExploreSignals::Explore,
+            |p, n, _, _| {
+                if distances.contains_key(n) {
+                    return false;
+                }
+                distances.insert(*n, distances[p] + 1);
+                true
+            },
+        );
+        let g = &exploration.context;
+        let end = g.id(&V2::new([2, 2])).unwrap();
+        assert_eq!(distances.len(), 7);
+        assert_eq!(distances[&end], 4);
+    }
+}
@@ -0,0 +1,136 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// Left action of the group `G` on the set `X`, `act(a.op(b), x) == act(a, act(b, x))` and
+/// the identity leaves every `x` in place.
+///
+/// Closures `Fn(&G, &X) -> X` are actions too.
+pub trait GroupAction<G, X> {
+    fn act(&self, g: &G, x: &X) -> X;
+
+    /// Everything `x` can be moved to by words in `generators`, breadth first starting with `x`.
+    fn orbit(&self, generators: &[G], x: &X) -> Vec<X>
+    where
+        X: Clone + Eq + Hash,
+    {
+        let mut seen = HashSet::from([x.clone()]);
+        let mut open = VecDeque::from([x.clone()]);
+        let mut orbit = vec![];
+        // NOTE(lubo): Products of generators suffice for finite groups, inverses are powers.
+        while let Some(y) = open.pop_front() {
+            for g in generators.iter() {
+                let z = self.act(g, &y);
+                if seen.insert(z.clone()) {
+                    open.push_back(z);
+                }
+            }
+            orbit.push(y);
+        }
+        orbit
+    }
+
+    /// The elements out of `elements` that fix `x`.
+    fn stabilizer(&self, elements: &[G], x: &X) -> Vec<G>
+    where
+        G: Clone,
+        X: Eq,
+    {
+        elements
+            .iter()
+            .filter(|g| self.act(g, x) == *x)
+            .cloned()
+            .collect()
+    }
+}
+
+impl<G, X, F: Fn(&G, &X) -> X> GroupAction<G, X> for F {
+    fn act(&self, g: &G, x: &X) -> X {
+        self(g, x)
+    }
+}
+
+/// Number of orbits of a finite group action by Burnside's lemma, the average number of points
+/// fixed by an element.
+///
+/// `fixed_points(g)` counts the points left in place by `g`, the total has to be divisible by
+/// the number of elements. `None` when a count is `None` or the total overflows.
+pub fn count_orbits<G, F>(elements: &[G], fixed_points: F) -> Option<u128>
+where
+    F: Fn(&G) -> Option<u128>,
+{
+    assert!(!elements.is_empty(), "a group has at least one element");
+    let total = elements
+        .iter()
+        .try_fold(0u128, |total, g| total.checked_add(fixed_points(g)?))?;
+    assert_eq!(
+        total % elements.len() as u128,
+        0,
+        "the elements do not form a group acting on the points"
+    );
+    Some(total / elements.len() as u128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GroupAction, count_orbits};
+    use crate::{
+        arraynd::Array2d,
+        group::{IterateGroup, Zn},
+        symmetry::{GridSymmetry, OnGrid},
+        vector::V2,
+    };
+
+    #[test]
+    fn closure_action() {
+        let shift = |g: &Zn<12>, x: &usize| (x + g.0) % 12;
+        assert_eq!(shift.orbit(&[Zn(4)], &1), vec![1, 5, 9]);
+        assert_eq!(shift.orbit(&[Zn(4), Zn(6)], &1).len(), 6);
+        assert_eq!(shift.stabilizer(&Zn::<12>::all(), &3), vec![Zn(0)]);
+
+        let rotate = |g: &GridSymmetry, p: &V2<i32>| g.apply(*p);
+        let generators = [GridSymmetry::Rotate90, GridSymmetry::FlipX];
+        assert_eq!(rotate.orbit(&generators, &V2::from_xy(1, 2)).len(), 8);
+        assert_eq!(rotate.orbit(&generators, &V2::from_xy(3, 3)).len(), 4);
+        assert_eq!(
+            rotate.stabilizer(&GridSymmetry::ALL, &V2::from_xy(3, 3)),
+            vec![GridSymmetry::Identity, GridSymmetry::Transpose]
+        );
+    }
+
+    #[test]
+    fn grid_action() {
+        let array = Array2d::from_slice([2, 2], &[1, 0, 0, 1]);
+        let orbit = OnGrid.orbit(&[GridSymmetry::Rotate90], &array);
+        assert_eq!(orbit.len(), 2);
+        assert_eq!(orbit[1].data, vec![0, 1, 1, 0]);
+        assert_eq!(OnGrid.stabilizer(&GridSymmetry::ALL, &array).len(), 4);
+
+        let array = Array2d::from_slice([3, 1], &[1, 2, 3]);
+        assert_eq!(OnGrid.orbit(&GridSymmetry::ALL, &array).len(), 4);
+    }
+
+    #[test]
+    fn burnside() {
+        // NOTE(lubo): Necklaces of 6 beads in 2 colors under rotation.
+        let rotations = Zn::<6>::all();
+        let necklaces: Vec<_> = (0..64u32).collect();
+        let rotate = |g: &Zn<6>, x: &u32| (x << g.0 | x >> ((6 - g.0) % 6)) & 63;
+        let count = count_orbits(&rotations, |g| {
+            Some(necklaces.iter().filter(|x| rotate(g, x) == **x).count() as u128)
+        });
+        assert_eq!(count, Some(14));
+        assert_eq!(count_orbits(&rotations, |_| Some(u128::MAX)), None);
+
+        let mut seen = std::collections::HashSet::new();
+        let mut orbits = 0;
+        for x in necklaces.iter() {
+            if seen.insert(*x) {
+                orbits += 1;
+                seen.extend(rotate.orbit(&[Zn(1)], x));
+            }
+        }
+        assert_eq!(Some(orbits as u128), count);
+    }
+}
@@ -0,0 +1,226 @@
+use std::{collections::HashMap, fmt::Debug, fmt::Display, hash::Hash};
+
+use super::group::Group;
+
+/// Operation table of a finite group, `table[a][b]` is the index of `elements[a].op(elements[b])`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CayleyTable<G> {
+    pub elements: Vec<G>,
+    pub table: Vec<Vec<usize>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupAxiomError<G> {
+    Empty,
+    Duplicate(G),
+    NotClosed(G, G),
+    MissingIdentity,
+    WrongIdentity(G),
+    MissingInverse(G),
+    NotAssociative(G, G, G),
+}
+
+impl<G: Debug> Display for GroupAxiomError<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GroupAxiomError::Empty => write!(f, "A group cannot be empty."),
+            GroupAxiomError::Duplicate(a) => write!(f, "Element {a:?} is listed more than once."),
+            GroupAxiomError::NotClosed(a, b) => {
+                write!(
+                    f,
+                    "The product of {a:?} and {b:?} is not among the elements."
+                )
+            }
+            GroupAxiomError::MissingIdentity => {
+                write!(f, "The identity is not among the elements.")
+            }
+            GroupAxiomError::WrongIdentity(a) => {
+                write!(f, "The identity does not act trivially on {a:?}.")
+            }
+            GroupAxiomError::MissingInverse(a) => {
+                write!(
+                    f,
+                    "The inverse of {a:?} is not among the elements or is wrong."
+                )
+            }
+            GroupAxiomError::NotAssociative(a, b, c) => {
+                write!(
+                    f,
+                    "The operation is not associative for {a:?}, {b:?}, {c:?}."
+                )
+            }
+        }
+    }
+}
+
+impl<G: Debug> std::error::Error for GroupAxiomError<G> {}
+
+/// Build the operation table of `elements` and check that they form a group under
+/// [`Group::op`] with [`Group::identity`] and [`Group::inverse`].
+///
+/// The associativity check is `O(n^3)`.
+pub fn cayley_table<G>(elements: Vec<G>) -> Result<CayleyTable<G>, GroupAxiomError<G>>
+where
+    G: Group + Hash,
+{
+    if elements.is_empty() {
+        return Err(GroupAxiomError::Empty);
+    }
+
+    let mut index = HashMap::new();
+    for (i, g) in elements.iter().enumerate() {
+        if index.insert(*g, i).is_some() {
+            return Err(GroupAxiomError::Duplicate(*g));
+        }
+    }
+
+    let mut table = vec![vec![0; elements.len()]; elements.len()];
+    for (i, a) in elements.iter().enumerate() {
+        for (j, b) in elements.iter().enumerate() {
+            table[i][j] = *index
+                .get(&a.op(*b))
+                .ok_or(GroupAxiomError::NotClosed(*a, *b))?;
+        }
+    }
+
+    let e = *index
+        .get(&G::identity())
+        .ok_or(GroupAxiomError::MissingIdentity)?;
+    for (i, a) in elements.iter().enumerate() {
+        if table[e][i] != i || table[i][e] != i {
+            return Err(GroupAxiomError::WrongIdentity(*a));
+        }
+        let inverse = index
+            .get(&a.inverse())
+            .ok_or(GroupAxiomError::MissingInverse(*a))?;
+        if table[i][*inverse] != e || table[*inverse][i] != e {
+            return Err(GroupAxiomError::MissingInverse(*a));
+        }
+    }
+
+    let n = elements.len();
+    for a in 0..n {
+        for b in 0..n {
+            for c in 0..n {
+                if table[table[a][b]][c] != table[a][table[b][c]] {
+                    return Err(GroupAxiomError::NotAssociative(
+                        elements[a],
+                        elements[b],
+                        elements[c],
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(CayleyTable { elements, table })
+}
+
+impl<G> CayleyTable<G> {
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    pub fn product(&self, a: usize, b: usize) -> usize {
+        self.table[a][b]
+    }
+}
+
+impl<G: Debug> Display for CayleyTable<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<_> = self.elements.iter().map(|g| format!("{g:?}")).collect();
+        let width = names
+            .iter()
+            .map(|name| name.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        write!(f, "{:width$} |", "")?;
+        for name in names.iter() {
+            write!(f, " {name:width$}")?;
+        }
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{}+{}",
+            "-".repeat(width + 1),
+            "-".repeat((width + 1) * names.len())
+        )?;
+        for (name, row) in names.iter().zip(self.table.iter()) {
+            write!(f, "{name:width$} |")?;
+            for j in row {
+                write!(f, " {:width$}", names[*j])?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GroupAxiomError, cayley_table};
+    use crate::{
+        group::group::{Group, IterateGroup, ThreeGroup, Vierergruppe},
+        symmetry::GridSymmetry,
+    };
+
+    #[test]
+    fn valid_tables() {
+        let table = cayley_table(ThreeGroup::all()).unwrap();
+        assert_eq!(
+            table.table,
+            vec![vec![0, 1, 2], vec![1, 2, 0], vec![2, 0, 1]]
+        );
+        assert_eq!(
+            table.to_string(),
+            "  | E A B\n--+------\nE | E A B\nA | A B E\nB | B E A\n"
+        );
+
+        assert_eq!(cayley_table(Vierergruppe::all()).unwrap().len(), 4);
+        assert_eq!(
+            cayley_table(GridSymmetry::all().collect()).unwrap().len(),
+            8
+        );
+        assert_eq!(cayley_table(vec![()]).unwrap().product(0, 0), 0);
+    }
+
+    #[test]
+    fn invalid_tables() {
+        assert_eq!(cayley_table::<bool>(vec![]), Err(GroupAxiomError::Empty));
+        assert_eq!(
+            cayley_table(vec![false, true, false]),
+            Err(GroupAxiomError::Duplicate(false))
+        );
+        assert_eq!(
+            cayley_table(vec![ThreeGroup::E, ThreeGroup::A]),
+            Err(GroupAxiomError::NotClosed(ThreeGroup::A, ThreeGroup::A))
+        );
+        assert_eq!(
+            cayley_table(vec![true]),
+            Err(GroupAxiomError::NotClosed(true, true))
+        );
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        struct Subtraction(i8);
+        impl Group for Subtraction {
+            fn identity() -> Self {
+                Self(0)
+            }
+            fn op(self, rhs: Self) -> Self {
+                Self((self.0 - rhs.0).rem_euclid(3))
+            }
+            fn inverse(self) -> Self {
+                self
+            }
+        }
+        assert_eq!(
+            cayley_table(vec![Subtraction(0), Subtraction(1), Subtraction(2)]),
+            Err(GroupAxiomError::WrongIdentity(Subtraction(1)))
+        );
+    }
+}
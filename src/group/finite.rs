@@ -0,0 +1,372 @@
+use std::hash::{Hash, Hasher};
+
+use super::group::{Group, IterateGroup};
+
+/// Cyclic group of order `N`, addition of integers modulo `N`. `N` must be positive.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Zn<const N: usize>(pub usize);
+
+impl<const N: usize> Zn<N> {
+    pub const fn new(value: usize) -> Self {
+        Self(value % N)
+    }
+
+    pub const fn generator() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const N: usize> Group for Zn<N> {
+    fn identity() -> Self {
+        Self(0)
+    }
+
+    fn op(self, rhs: Self) -> Self {
+        Self((self.0 + rhs.0) % N)
+    }
+
+    fn inverse(self) -> Self {
+        Self((N - self.0) % N)
+    }
+}
+
+impl<const N: usize> IterateGroup for Zn<N> {
+    fn all() -> Vec<Self> {
+        (0..N).map(Self).collect()
+    }
+}
+
+/// Dihedral group of order `2 * N`, symmetries of a regular `N`-gon. `N` must be positive.
+///
+/// The element is `r^rotation` followed by a reflection `s` when `reflected`, i.e.
+/// `r^rotation * s`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Dn<const N: usize> {
+    pub rotation: usize,
+    pub reflected: bool,
+}
+
+impl<const N: usize> Dn<N> {
+    pub const fn new(rotation: usize, reflected: bool) -> Self {
+        Self {
+            rotation: rotation % N,
+            reflected,
+        }
+    }
+
+    pub const fn rotation() -> Self {
+        Self::new(1, false)
+    }
+
+    pub const fn reflection() -> Self {
+        Self::new(0, true)
+    }
+}
+
+impl<const N: usize> Group for Dn<N> {
+    fn identity() -> Self {
+        Self::new(0, false)
+    }
+
+    fn op(self, rhs: Self) -> Self {
+        let (rotation, reflected) = dihedral_op(N, self.into(), rhs.into());
+        Self::new(rotation, reflected)
+    }
+
+    fn inverse(self) -> Self {
+        let (rotation, reflected) = dihedral_inverse(N, self.into());
+        Self::new(rotation, reflected)
+    }
+}
+
+impl<const N: usize> IterateGroup for Dn<N> {
+    fn all() -> Vec<Self> {
+        [false, true]
+            .into_iter()
+            .flat_map(|reflected| (0..N).map(move |rotation| Self::new(rotation, reflected)))
+            .collect()
+    }
+}
+
+impl<const N: usize> From<Dn<N>> for (usize, bool) {
+    fn from(value: Dn<N>) -> Self {
+        (value.rotation, value.reflected)
+    }
+}
+
+// NOTE(lubo): `s * r^k = r^-k * s`.
+fn dihedral_op(n: usize, (a, f): (usize, bool), (b, g): (usize, bool)) -> (usize, bool) {
+    let b = if f { n - b } else { b };
+    ((a + b) % n, f ^ g)
+}
+
+fn dihedral_inverse(n: usize, (a, f): (usize, bool)) -> (usize, bool) {
+    if f { (a, f) } else { ((n - a) % n, f) }
+}
+
+/// Order `0` only appears in the identity returned by [`Group::identity`], which does not know
+/// the order and is compatible with every other element.
+///
+/// Different non-zero orders break the precondition documented on [`Cyclic`] and [`Dihedral`].
+fn common_order(a: usize, b: usize) -> usize {
+    match (a, b) {
+        (0, n) | (n, 0) => n,
+        (a, b) => {
+            assert_eq!(a, b, "elements of different groups");
+            a
+        }
+    }
+}
+
+/// Cyclic group with the order chosen at runtime, see [`Zn`] for the const generic version.
+///
+/// [`Group::identity`] does not know the order, it compares equal to the identity of every
+/// order and takes the order of the other operand.
+///
+/// # Panics
+///
+/// Elements of different orders belong to different groups. Only combine elements created
+/// with the same `order` (or the identity), [`Group::op`] panics otherwise.
+#[derive(Debug, Clone, Copy)]
+pub struct Cyclic {
+    value: usize,
+    order: usize,
+}
+
+impl Cyclic {
+    pub fn new(order: usize, value: usize) -> Self {
+        assert!(order > 0, "cyclic group of order zero");
+        Self {
+            value: value % order,
+            order,
+        }
+    }
+
+    pub fn generator(order: usize) -> Self {
+        Self::new(order, 1)
+    }
+
+    pub fn all(order: usize) -> Vec<Self> {
+        (0..order).map(|value| Self::new(order, value)).collect()
+    }
+
+    pub fn value(&self) -> usize {
+        self.value
+    }
+}
+
+impl PartialEq for Cyclic {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && (self.value == 0 || self.order == other.order)
+    }
+}
+
+impl Eq for Cyclic {}
+
+impl Hash for Cyclic {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl Group for Cyclic {
+    fn identity() -> Self {
+        Self { value: 0, order: 0 }
+    }
+
+    fn op(self, rhs: Self) -> Self {
+        let order = common_order(self.order, rhs.order);
+        match order {
+            0 => Self::identity(),
+            _ => Self::new(order, self.value + rhs.value),
+        }
+    }
+
+    fn inverse(self) -> Self {
+        match self.value {
+            0 => self,
+            value => Self::new(self.order, self.order - value),
+        }
+    }
+}
+
+/// Dihedral group with `n` chosen at runtime, see [`Dn`] for the const generic version.
+///
+/// Like [`Cyclic`], the identity returned by [`Group::identity`] works for every `n`.
+///
+/// # Panics
+///
+/// Only combine elements created with the same `n` (or the identity), [`Group::op`] panics
+/// otherwise.
+#[derive(Debug, Clone, Copy)]
+pub struct Dihedral {
+    rotation: usize,
+    reflected: bool,
+    n: usize,
+}
+
+impl Dihedral {
+    /// The element `r^rotation * s` when `reflected`, `r^rotation` otherwise.
+    pub fn new(n: usize, rotation: usize, reflected: bool) -> Self {
+        assert!(n > 0, "dihedral group of a zero-gon");
+        Self {
+            rotation: rotation % n,
+            reflected,
+            n,
+        }
+    }
+
+    pub fn rotation(n: usize) -> Self {
+        Self::new(n, 1, false)
+    }
+
+    pub fn reflection(n: usize) -> Self {
+        Self::new(n, 0, true)
+    }
+
+    /// All `2 * n` elements, rotations first.
+    pub fn all(n: usize) -> Vec<Self> {
+        [false, true]
+            .into_iter()
+            .flat_map(|reflected| (0..n).map(move |rotation| Self::new(n, rotation, reflected)))
+            .collect()
+    }
+
+    pub fn rotation_steps(&self) -> usize {
+        self.rotation
+    }
+
+    pub fn is_reflection(&self) -> bool {
+        self.reflected
+    }
+
+    fn is_identity(&self) -> bool {
+        self.rotation == 0 && !self.reflected
+    }
+}
+
+impl PartialEq for Dihedral {
+    fn eq(&self, other: &Self) -> bool {
+        self.rotation == other.rotation
+            && self.reflected == other.reflected
+            && (self.is_identity() || self.n == other.n)
+    }
+}
+
+impl Eq for Dihedral {}
+
+impl Hash for Dihedral {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rotation.hash(state);
+        self.reflected.hash(state);
+    }
+}
+
+impl Group for Dihedral {
+    fn identity() -> Self {
+        Self {
+            rotation: 0,
+            reflected: false,
+            n: 0,
+        }
+    }
+
+    fn op(self, rhs: Self) -> Self {
+        let n = common_order(self.n, rhs.n);
+        if n == 0 {
+            return Self::identity();
+        }
+        let (rotation, reflected) = dihedral_op(
+            n,
+            (self.rotation, self.reflected),
+            (rhs.rotation, rhs.reflected),
+        );
+        Self::new(n, rotation, reflected)
+    }
+
+    fn inverse(self) -> Self {
+        if self.is_identity() {
+            return self;
+        }
+        let (rotation, reflected) = dihedral_inverse(self.n, (self.rotation, self.reflected));
+        Self::new(self.n, rotation, reflected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cyclic, Dihedral, Dn, Zn};
+    use crate::group::{
+        cayley_table, generate,
+        group::{Group, IterateGroup},
+        order,
+    };
+
+    #[test]
+    fn const_groups() {
+        assert!(cayley_table(Zn::<1>::all()).is_ok());
+        assert!(cayley_table(Zn::<12>::all()).is_ok());
+        assert_eq!(order(Zn::<12>::new(8)), 3);
+        assert_eq!(Zn::<5>::new(7), Zn(2));
+
+        assert!(cayley_table(Dn::<1>::all()).is_ok());
+        assert!(cayley_table(Dn::<2>::all()).is_ok());
+        let all = Dn::<5>::all();
+        assert_eq!(all.len(), 10);
+        assert!(cayley_table(all).is_ok());
+
+        let (r, s) = (Dn::<5>::rotation(), Dn::<5>::reflection());
+        assert_eq!(order(r), 5);
+        assert_eq!(order(s), 2);
+        assert_eq!(s.op(r).op(s), r.inverse());
+        assert_eq!(generate(&[r, s]).len(), 10);
+        assert_eq!(
+            generate(&[r.op(s), s]),
+            Dn::<5>::all().into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn runtime_groups() {
+        let all = Cyclic::all(7);
+        assert!(cayley_table(all.clone()).is_ok());
+        assert_eq!(all[0], Cyclic::identity());
+        assert_eq!(order(Cyclic::new(6, 4)), 3);
+        assert_eq!(Cyclic::new(6, 4).inverse().value(), 2);
+        assert_eq!(generate(&[Cyclic::new(12, 9)]).len(), 4);
+
+        let all = Dihedral::all(6);
+        assert_eq!(all.len(), 12);
+        assert!(cayley_table(all.clone()).is_ok());
+        assert_eq!(all[0], Dihedral::identity());
+
+        let (r, s) = (Dihedral::rotation(6), Dihedral::reflection(6));
+        assert_eq!(s.op(r).op(s), r.inverse());
+        assert_eq!(r.op(s).rotation_steps(), 1);
+        assert!(r.op(s).is_reflection());
+        assert_eq!(order(r.op(r)), 3);
+        assert_eq!(generate(&[r, s]).len(), 12);
+
+        for (a, b) in Dn::<6>::all().into_iter().zip(all.iter()) {
+            for (c, d) in Dn::<6>::all().into_iter().zip(all.iter()) {
+                let (e, f) = (a.op(c), b.op(*d));
+                assert_eq!(
+                    (e.rotation, e.reflected),
+                    (f.rotation_steps(), f.is_reflection())
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "elements of different groups")]
+    fn mixed_orders() {
+        Cyclic::new(3, 1).op(Cyclic::new(4, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "elements of different groups")]
+    fn mixed_dihedral_orders() {
+        Dihedral::rotation(3).op(Dihedral::reflection(4));
+    }
+}
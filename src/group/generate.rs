@@ -0,0 +1,112 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    hash::Hash,
+};
+
+use super::group::Group;
+
+/// `identity, g, g^2, ...` up to (excluding) the first repetition of the identity.
+///
+/// Never ends for elements of infinite order, combine with `take` to cap it.
+pub fn powers<G: Group>(g: G) -> impl Iterator<Item = G> {
+    std::iter::successors(Some(G::identity()), move |x| {
+        let next = x.op(g);
+        (next != G::identity()).then_some(next)
+    })
+}
+
+/// Smallest `n > 0` with `g^n = identity`. Loops forever for elements of infinite order, see
+/// [`order_at_most`].
+pub fn order<G: Group>(g: G) -> usize {
+    powers(g).count()
+}
+
+/// Like [`order`] but gives up with `None` when the order is larger than `cap`.
+pub fn order_at_most<G: Group>(g: G, cap: usize) -> Option<usize> {
+    let n = powers(g).take(cap + 1).count();
+    (n <= cap).then_some(n)
+}
+
+/// Lazily enumerates the subgroup generated by a set of elements, breadth first from the
+/// identity, so the elements come roughly ordered by word length.
+#[derive(Debug, Clone)]
+pub struct Generate<G> {
+    generators: Vec<G>,
+    seen: HashSet<G>,
+    open: VecDeque<G>,
+}
+
+impl<G: Group + Hash> Generate<G> {
+    pub fn new(generators: &[G]) -> Self {
+        Self {
+            generators: generators.to_vec(),
+            seen: HashSet::from([G::identity()]),
+            open: VecDeque::from([G::identity()]),
+        }
+    }
+}
+
+impl<G: Group + Hash> Iterator for Generate<G> {
+    type Item = G;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.open.pop_front()?;
+        // NOTE(lubo): Only products are needed, in a finite group inverses are powers.
+        for s in self.generators.iter() {
+            let y = x.op(*s);
+            if self.seen.insert(y) {
+                self.open.push_back(y);
+            }
+        }
+        Some(x)
+    }
+}
+
+/// All elements of the subgroup generated by `generators`. Use [`Generate`] to cap the
+/// enumeration for large (or infinite) groups.
+pub fn generate<G: Group + Hash>(generators: &[G]) -> HashSet<G> {
+    Generate::new(generators).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Generate, generate, order, order_at_most, powers};
+    use crate::{
+        group::group::{ThreeGroup, Vierergruppe},
+        symmetry::{CubeRotation, GridSymmetry},
+    };
+
+    #[test]
+    fn element_order() {
+        assert_eq!(order(ThreeGroup::E), 1);
+        assert_eq!(order(ThreeGroup::A), 3);
+        assert_eq!(order(GridSymmetry::Rotate90), 4);
+        assert_eq!(order(GridSymmetry::FlipX), 2);
+        assert_eq!(order(3i8), 256);
+        assert_eq!(order(64i8), 4);
+        assert_eq!(order_at_most(3i8, 100), None);
+        assert_eq!(order_at_most(64i8, 4), Some(4));
+        assert_eq!(
+            powers(GridSymmetry::Rotate90).collect::<Vec<_>>(),
+            GridSymmetry::rotations().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn generated_subgroups() {
+        assert_eq!(generate::<Vierergruppe>(&[]).len(), 1);
+        assert_eq!(generate(&[Vierergruppe::A]).len(), 2);
+        assert_eq!(generate(&[Vierergruppe::A, Vierergruppe::B]).len(), 4);
+        assert_eq!(generate(&[GridSymmetry::Rotate90]).len(), 4);
+        assert_eq!(
+            generate(&[GridSymmetry::Rotate90, GridSymmetry::FlipX]).len(),
+            8
+        );
+        assert_eq!(
+            generate(&[CubeRotation::rotate_x(), CubeRotation::rotate_y()]).len(),
+            24
+        );
+        assert_eq!(generate(&[4i8]).len(), 64);
+        assert_eq!(Generate::new(&[1i8]).take(10).count(), 10);
+    }
+}
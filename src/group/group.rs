@@ -9,7 +9,7 @@ where
 
 impl Group for () {
     fn identity() -> Self {}
-    fn op(self, rhs: Self) -> Self {}
+    fn op(self, _rhs: Self) -> Self {}
     fn inverse(self) -> Self {}
 }
 
@@ -33,7 +33,7 @@ impl Group for bool {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-enum ThreeGroup {
+pub enum ThreeGroup {
     E,
     A,
     B,
@@ -70,7 +70,7 @@ impl Group for ThreeGroup {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-enum Vierergruppe {
+pub enum Vierergruppe {
     E,
     A,
     B,
@@ -122,46 +122,53 @@ impl Group for i8 {
     }
 }
 
-#[cfg(test)]
-mod tests {
-
-    use super::{Group, ThreeGroup, Vierergruppe};
+/// Finite group whose elements can all be listed.
+pub trait IterateGroup
+where
+    Self: Sized + Group,
+{
+    fn all() -> Vec<Self>;
+}
 
-    pub trait IterateGroup
-    where
-        Self: Sized + Group,
-    {
-        fn all() -> Vec<Self>;
+impl IterateGroup for () {
+    fn all() -> Vec<Self> {
+        vec![()]
     }
+}
 
-    impl IterateGroup for bool {
-        fn all() -> Vec<Self> {
-            vec![false, true]
-        }
+impl IterateGroup for bool {
+    fn all() -> Vec<Self> {
+        vec![false, true]
     }
+}
 
-    impl IterateGroup for ThreeGroup {
-        fn all() -> Vec<Self> {
-            vec![ThreeGroup::E, ThreeGroup::A, ThreeGroup::B]
-        }
+impl IterateGroup for ThreeGroup {
+    fn all() -> Vec<Self> {
+        vec![ThreeGroup::E, ThreeGroup::A, ThreeGroup::B]
     }
+}
 
-    impl IterateGroup for Vierergruppe {
-        fn all() -> Vec<Self> {
-            vec![
-                Vierergruppe::E,
-                Vierergruppe::A,
-                Vierergruppe::B,
-                Vierergruppe::C,
-            ]
-        }
+impl IterateGroup for Vierergruppe {
+    fn all() -> Vec<Self> {
+        vec![
+            Vierergruppe::E,
+            Vierergruppe::A,
+            Vierergruppe::B,
+            Vierergruppe::C,
+        ]
     }
+}
 
-    impl IterateGroup for i8 {
-        fn all() -> Vec<Self> {
-            (i8::MIN..=i8::MAX).collect()
-        }
+impl IterateGroup for i8 {
+    fn all() -> Vec<Self> {
+        (i8::MIN..=i8::MAX).collect()
     }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{IterateGroup, ThreeGroup, Vierergruppe};
 
     fn group_test<G: IterateGroup + std::fmt::Debug>() {
         let elements = G::all();
@@ -1,98 +1,433 @@
-use std::collections::{HashSet, VecDeque};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+};
 
-#[derive(Default)]
-struct GroupPresentation {
+use super::group::Group;
+
+type Word = Vec<usize>;
+
+/// Finite group given by generators and relations, e.g. the Klein four-group is
+/// `<a, b | aa = e, bb = e, abab = e>`.
+///
+/// Relations are read as a monoid presentation, so every generator has to have finite order
+/// through them.
+#[derive(Debug, Default, Clone)]
+pub struct GroupPresentation {
     symbols: Vec<char>,
     rules: Vec<(String, String)>,
+    max_rules: Option<usize>,
+    max_elements: Option<usize>,
 }
 
-struct Group {
-    symbols: Vec<char>,
-    rules: Vec<(String, String)>,
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PresentationError {
+    UnknownSymbol(char),
+    DuplicateSymbol(char),
+    /// Knuth-Bendix completion did not finish within the rule limit.
+    TooManyRules,
+    /// The group is larger than the element limit, possibly infinite.
+    TooManyElements,
+    /// The generator has no inverse, the relations describe a monoid but not a group.
+    NotInvertible(char),
 }
 
-// NOTE(lubo): Something of this sort! :)
-struct GroupElement {
-    id: usize,
-    name: Vec<String>,
-    aliases: Vec<String>,
-    left_compose: HashMap<usize, usize>,
+impl Display for PresentationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PresentationError::UnknownSymbol(c) => write!(f, "Unknown symbol {c:?}."),
+            PresentationError::DuplicateSymbol(c) => {
+                write!(f, "Symbol {c:?} is declared more than once.")
+            }
+            PresentationError::TooManyRules => {
+                write!(
+                    f,
+                    "The rewriting system did not complete within the rule limit."
+                )
+            }
+            PresentationError::TooManyElements => {
+                write!(f, "The group has more elements than the limit.")
+            }
+            PresentationError::NotInvertible(c) => {
+                write!(f, "Generator {c:?} has no inverse.")
+            }
+        }
+    }
 }
 
+impl std::error::Error for PresentationError {}
+
 impl GroupPresentation {
+    const DEFAULT_MAX_RULES: usize = 1000;
+    const DEFAULT_MAX_ELEMENTS: usize = 100_000;
+
     pub fn new() -> Self {
         Self::default()
     }
+
     pub fn with_symbol(mut self, symbol: char) -> Self {
         self.symbols.push(symbol);
         self
     }
+
+    /// Relation `lhs = rhs`, the empty word is the identity.
     pub fn with_equality(mut self, lhs: String, rhs: String) -> Self {
         self.rules.push((lhs, rhs));
         self
     }
-    pub fn build(self) -> Group {
-        Group {
+
+    pub fn with_max_rules(mut self, max_rules: usize) -> Self {
+        self.max_rules = Some(max_rules);
+        self
+    }
+
+    pub fn with_max_elements(mut self, max_elements: usize) -> Self {
+        self.max_elements = Some(max_elements);
+        self
+    }
+
+    pub fn build(self) -> Result<PresentedGroup, PresentationError> {
+        let mut seen = HashSet::new();
+        if let Some(c) = self.symbols.iter().find(|c| !seen.insert(**c)) {
+            return Err(PresentationError::DuplicateSymbol(*c));
+        }
+
+        let parse = |word: &str| -> Result<Word, PresentationError> {
+            word.chars()
+                .map(|c| {
+                    self.symbols
+                        .iter()
+                        .position(|s| *s == c)
+                        .ok_or(PresentationError::UnknownSymbol(c))
+                })
+                .collect()
+        };
+        let mut rules = vec![];
+        for (lhs, rhs) in self.rules.iter() {
+            if let Some(rule) = orient(parse(lhs)?, parse(rhs)?) {
+                rules.push(rule);
+            }
+        }
+
+        let rules = complete(rules, self.max_rules.unwrap_or(Self::DEFAULT_MAX_RULES))?;
+        let mut group = PresentedGroup {
             symbols: self.symbols,
-            rules: self.rules,
+            rules,
+            elements: vec![],
+            index: HashMap::new(),
+            inverses: vec![],
+        };
+        group.enumerate(self.max_elements.unwrap_or(Self::DEFAULT_MAX_ELEMENTS))?;
+        Ok(group)
+    }
+}
+
+/// Shortlex order, shorter words first, then lexicographic by symbol declaration order.
+fn shortlex(word: &Word) -> (usize, &Word) {
+    (word.len(), word)
+}
+
+fn orient(a: Word, b: Word) -> Option<(Word, Word)> {
+    match shortlex(&a).cmp(&shortlex(&b)) {
+        std::cmp::Ordering::Less => Some((b, a)),
+        std::cmp::Ordering::Equal => None,
+        std::cmp::Ordering::Greater => Some((a, b)),
+    }
+}
+
+fn find(haystack: &[usize], needle: &[usize]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn reduce(rules: &[(Word, Word)], mut word: Word) -> Word {
+    // NOTE(lubo): Every rule makes the word shortlex smaller, so this terminates.
+    'outer: loop {
+        for (lhs, rhs) in rules.iter() {
+            if let Some(i) = find(&word, lhs) {
+                word.splice(i..i + lhs.len(), rhs.iter().copied());
+                continue 'outer;
+            }
         }
+        return word;
     }
 }
 
-impl Group {
-    fn simplify(&self, mut g: String) -> String {
-        // println!("simplifying {:?}", g);
-        for _ in 0..10 {
-            let mut simplified = true;
-            for rule in self.rules.iter() {
-                let applied = g.replace(&rule.0, &rule.1);
-                if g != applied {
-                    g = applied;
-                    // println!("applied {:?}", rule);
-                    // println!("{:?}", g);
-                    simplified = false;
+/// Knuth-Bendix completion with the shortlex order, resolves all critical pairs so that
+/// [`reduce`] gives unique normal forms.
+fn complete(
+    mut rules: Vec<(Word, Word)>,
+    max_rules: usize,
+) -> Result<Vec<(Word, Word)>, PresentationError> {
+    let mut i = 0;
+    while i < rules.len() {
+        for j in 0..=i {
+            for (a, b) in [(i, j), (j, i)] {
+                let (l1, r1) = rules[a].clone();
+                let (l2, r2) = rules[b].clone();
+
+                let mut pairs = vec![];
+                // NOTE(lubo): Suffix of `l1` overlapping a prefix of `l2`.
+                for k in 1..l1.len().min(l2.len()) {
+                    if l1[l1.len() - k..] == l2[..k] {
+                        let left = [&r1[..], &l2[k..]].concat();
+                        let right = [&l1[..l1.len() - k], &r2[..]].concat();
+                        pairs.push((left, right));
+                    }
+                }
+                // NOTE(lubo): `l2` inside of `l1`.
+                if a != b
+                    && let Some(p) = find(&l1, &l2)
+                {
+                    let right = [&l1[..p], &r2[..], &l1[p + l2.len()..]].concat();
+                    pairs.push((r1.clone(), right));
+                }
+
+                for (left, right) in pairs {
+                    let left = reduce(&rules, left);
+                    let right = reduce(&rules, right);
+                    if let Some(rule) = orient(left, right) {
+                        rules.push(rule);
+                        if rules.len() > max_rules {
+                            return Err(PresentationError::TooManyRules);
+                        }
+                    }
                 }
-            }
-            if simplified {
-                return g;
             }
         }
-        panic!("Simplify loop limit reached")
+        i += 1;
     }
 
-    pub fn find_all_elements(&self) -> HashSet<String> {
-        let mut closed = HashSet::new();
-        let mut open = VecDeque::from([String::new()]);
+    // NOTE(lubo): Drop rules made redundant by later ones, keeps reduction fast.
+    let mut reduced: Vec<(Word, Word)> = vec![];
+    for k in 0..rules.len() {
+        let (lhs, rhs) = &rules[k];
+        let others = rules
+            .iter()
+            .enumerate()
+            .filter(|(m, _)| *m != k)
+            .filter(|(m, (l, _))| find(lhs, l).is_some() && (l != lhs || *m < k));
+        if others.count() == 0 {
+            reduced.push((lhs.clone(), rhs.clone()));
+        }
+    }
+    for k in 0..reduced.len() {
+        reduced[k].1 = reduce(&reduced, reduced[k].1.clone());
+    }
+    Ok(reduced)
+}
 
-        let mut limit = 50;
+/// Group of a [`GroupPresentation`], elements are identified by their shortlex smallest word.
+#[derive(Debug, Clone)]
+pub struct PresentedGroup {
+    symbols: Vec<char>,
+    rules: Vec<(Word, Word)>,
+    /// Normal forms in shortlex order, the identity first.
+    elements: Vec<Word>,
+    index: HashMap<Word, usize>,
+    /// Normal form of the inverse of each symbol.
+    inverses: Vec<Word>,
+}
+
+impl PresentedGroup {
+    fn enumerate(&mut self, max_elements: usize) -> Result<(), PresentationError> {
+        let mut closed = HashSet::from([vec![]]);
+        let mut open = VecDeque::from([vec![]]);
         while let Some(word) = open.pop_front() {
-            // if word.len() > 1 {
-            //     open.push_back(self.simplify(word[1..].into()));
-            //     open.push_back(self.simplify(word[..word.len()].into()));
-            // }
-
-            for c in self.symbols.iter() {
-                let child = format!("{}{}", word, c);
-                let child = self.simplify(child);
-                open.push_back(child);
+            for s in 0..self.symbols.len() {
+                let child = reduce(&self.rules, [&word[..], &[s]].concat());
+                if closed.insert(child.clone()) {
+                    if closed.len() > max_elements {
+                        return Err(PresentationError::TooManyElements);
+                    }
+                    open.push_back(child);
+                }
             }
-            closed.insert(word);
+        }
 
-            println!("closed: {:?}", closed);
+        self.elements = closed.into_iter().collect();
+        self.elements.sort_by(|a, b| shortlex(a).cmp(&shortlex(b)));
+        self.index = self
+            .elements
+            .iter()
+            .enumerate()
+            .map(|(i, w)| (w.clone(), i))
+            .collect();
 
-            limit -= 1;
-            if limit <= 0 {
-                panic!("graph search loop limit reached")
+        for s in 0..self.symbols.len() {
+            let mut power = vec![];
+            let mut inverse = None;
+            for _ in 0..self.elements.len() {
+                let next = reduce(&self.rules, [&power[..], &[s]].concat());
+                if next.is_empty() {
+                    inverse = Some(power);
+                    break;
+                }
+                power = next;
             }
+            self.inverses
+                .push(inverse.ok_or(PresentationError::NotInvertible(self.symbols[s]))?);
         }
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    pub fn symbols(&self) -> &[char] {
+        &self.symbols
+    }
+
+    /// All elements in shortlex order of their normal forms, the identity first.
+    pub fn elements(&self) -> impl Iterator<Item = PresentedElement<'_>> {
+        (0..self.len()).map(|index| PresentedElement {
+            group: Some(self),
+            index,
+        })
+    }
 
-        closed
+    /// Element spelled by `word`, `None` if it uses an unknown symbol.
+    pub fn element(&self, word: &str) -> Option<PresentedElement<'_>> {
+        let word = word
+            .chars()
+            .map(|c| self.symbols.iter().position(|s| *s == c))
+            .collect::<Option<Word>>()?;
+        Some(self.element_of(word))
+    }
+
+    pub fn generator(&self, symbol: char) -> Option<PresentedElement<'_>> {
+        self.element(&symbol.to_string())
+    }
+
+    /// Normal form of `word`, `None` if it uses an unknown symbol.
+    pub fn simplify(&self, word: &str) -> Option<String> {
+        self.element(word).map(|g| g.word())
+    }
+
+    fn element_of(&self, word: Word) -> PresentedElement<'_> {
+        let index = self.index[&reduce(&self.rules, word)];
+        PresentedElement {
+            group: Some(self),
+            index,
+        }
+    }
+
+    fn spell(&self, word: &[usize]) -> String {
+        word.iter().map(|s| self.symbols[*s]).collect()
+    }
+}
+
+/// Element of a [`PresentedGroup`].
+///
+/// Like the runtime [`super::Cyclic`], [`Group::identity`] does not know its group, it equals
+/// the identity of every group and takes the group of the other operand.
+#[derive(Clone, Copy)]
+pub struct PresentedElement<'a> {
+    group: Option<&'a PresentedGroup>,
+    index: usize,
+}
+
+impl PresentedElement<'_> {
+    /// Normal form, the empty string for the identity.
+    pub fn word(&self) -> String {
+        match self.group {
+            Some(group) => group.spell(&group.elements[self.index]),
+            None => String::new(),
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl PartialEq for PresentedElement<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+            && match (self.group, other.group) {
+                (Some(a), Some(b)) => std::ptr::eq(a, b),
+                _ => self.index == 0,
+            }
+    }
+}
+
+impl Eq for PresentedElement<'_> {}
+
+impl Hash for PresentedElement<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+
+impl Debug for PresentedElement<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl Display for PresentedElement<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.word().as_str() {
+            "" => write!(f, "e"),
+            word => write!(f, "{word}"),
+        }
+    }
+}
+
+impl<'a> Group for PresentedElement<'a> {
+    fn identity() -> Self {
+        Self {
+            group: None,
+            index: 0,
+        }
+    }
+
+    fn op(self, rhs: Self) -> Self {
+        let group = match (self.group, rhs.group) {
+            (Some(a), Some(b)) => {
+                assert!(std::ptr::eq(a, b), "elements of different groups");
+                a
+            }
+            (Some(group), None) | (None, Some(group)) => group,
+            (None, None) => return self,
+        };
+        let word = [
+            &group.elements[self.index][..],
+            &group.elements[rhs.index][..],
+        ]
+        .concat();
+        group.element_of(word)
+    }
+
+    fn inverse(self) -> Self {
+        let Some(group) = self.group else {
+            return self;
+        };
+        let word = group.elements[self.index]
+            .iter()
+            .rev()
+            .flat_map(|s| group.inverses[*s].iter().copied())
+            .collect();
+        group.element_of(word)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::GroupPresentation;
+    use super::{GroupPresentation, PresentationError};
+    use crate::group::{
+        cayley_table, generate,
+        group::{Group, Vierergruppe},
+        order,
+    };
 
     #[test]
     fn vierergruppe() {
@@ -102,8 +437,87 @@ mod tests {
             .with_equality("aa".into(), "".into())
             .with_equality("bb".into(), "".into())
             .with_equality("abab".into(), "".into())
+            .build()
+            .unwrap();
+
+        let words: Vec<_> = group.elements().map(|g| g.word()).collect();
+        assert_eq!(words, ["", "a", "b", "ab"]);
+        assert_eq!(group.simplify("ba"), Some("ab".into()));
+        assert_eq!(group.simplify("abba"), Some("".into()));
+        assert_eq!(group.simplify("ax"), None);
+
+        let table = cayley_table(group.elements().collect()).unwrap();
+        let expected = cayley_table(vec![
+            Vierergruppe::E,
+            Vierergruppe::A,
+            Vierergruppe::B,
+            Vierergruppe::C,
+        ])
+        .unwrap();
+        assert_eq!(table.table, expected.table);
+    }
+
+    #[test]
+    fn symmetric_group() {
+        // NOTE(lubo): S3 as the dihedral group of the triangle, `r^3 = s^2 = (sr)^2 = e`.
+        let group = GroupPresentation::new()
+            .with_symbol('r')
+            .with_symbol('s')
+            .with_equality("rrr".into(), "".into())
+            .with_equality("ss".into(), "".into())
+            .with_equality("srsr".into(), "".into())
+            .build()
+            .unwrap();
+        assert_eq!(group.len(), 6);
+        assert!(cayley_table(group.elements().collect()).is_ok());
+
+        let r = group.generator('r').unwrap();
+        let s = group.generator('s').unwrap();
+        assert_eq!(r.inverse().word(), "rr");
+        assert_eq!(r.op(s).inverse(), r.op(s));
+        assert_ne!(r.op(s), s.op(r));
+        assert_eq!(r.op(r).op(r), Group::identity());
+        assert_eq!(order(r), 3);
+        assert_eq!(generate(&[r, s]).len(), 6);
+        assert_eq!(group.element("rsr").unwrap(), s);
+        assert_eq!(format!("{:?}", s.op(s)), "e");
+    }
+
+    #[test]
+    fn quaternion_group() {
+        let group = GroupPresentation::new()
+            .with_symbol('i')
+            .with_symbol('j')
+            .with_equality("iiii".into(), "".into())
+            .with_equality("ii".into(), "jj".into())
+            .with_equality("iji".into(), "j".into())
+            .build()
+            .unwrap();
+        assert_eq!(group.len(), 8);
+        assert!(cayley_table(group.elements().collect()).is_ok());
+        let i = group.generator('i').unwrap();
+        let j = group.generator('j').unwrap();
+        assert_eq!(order(i.op(j)), 4);
+    }
+
+    #[test]
+    fn invalid_presentations() {
+        let unknown = GroupPresentation::new()
+            .with_symbol('a')
+            .with_equality("ab".into(), "".into())
             .build();
+        assert_eq!(unknown.err(), Some(PresentationError::UnknownSymbol('b')));
 
-        dbg!(group.find_all_elements());
+        let infinite = GroupPresentation::new()
+            .with_symbol('a')
+            .with_max_elements(100)
+            .build();
+        assert_eq!(infinite.err(), Some(PresentationError::TooManyElements));
+
+        let monoid = GroupPresentation::new()
+            .with_symbol('a')
+            .with_equality("aaa".into(), "aa".into())
+            .build();
+        assert_eq!(monoid.err(), Some(PresentationError::NotInvertible('a')));
     }
 }
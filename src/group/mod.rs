@@ -1,2 +1,23 @@
-// mod group_presentation;
+pub mod action;
+pub mod cayley;
+pub mod finite;
+pub mod generate;
+#[allow(clippy::module_inception)]
+pub mod group;
+pub mod group_presentation;
 pub mod map;
+pub mod morphism;
+pub mod product;
+pub mod structure;
+
+pub use action::{GroupAction, count_orbits};
+pub use cayley::{CayleyTable, GroupAxiomError, cayley_table};
+pub use finite::{Cyclic, Dihedral, Dn, Zn};
+pub use generate::{Generate, generate, order, order_at_most, powers};
+pub use group::{Group, IterateGroup};
+pub use group_presentation::{
+    GroupPresentation, PresentationError, PresentedElement, PresentedGroup,
+};
+pub use morphism::{find_isomorphism, generators, is_homomorphism, is_isomorphism};
+pub use product::{Action, Semidirect};
+pub use structure::{center, conjugacy_classes, is_abelian};
@@ -0,0 +1,188 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use super::{
+    generate::{generate, order},
+    group::{Group, IterateGroup},
+};
+
+/// Check `f(a.op(b)) == f(a).op(f(b))` for all elements of `G`. `O(|G|^2)`.
+pub fn is_homomorphism<G, H, F>(f: F) -> bool
+where
+    G: IterateGroup,
+    H: Group,
+    F: Fn(G) -> H,
+{
+    let elements = G::all();
+    let images: Vec<_> = elements.iter().map(|g| f(*g)).collect();
+    elements.iter().zip(images.iter()).all(|(a, fa)| {
+        elements
+            .iter()
+            .zip(images.iter())
+            .all(|(b, fb)| f(a.op(*b)) == fa.op(*fb))
+    })
+}
+
+/// [`is_homomorphism`] that is also a bijection.
+pub fn is_isomorphism<G, H, F>(f: F) -> bool
+where
+    G: IterateGroup,
+    H: IterateGroup + Hash,
+    F: Fn(G) -> H,
+{
+    let images: HashSet<_> = G::all().into_iter().map(&f).collect();
+    images.len() == H::all().len() && is_homomorphism(f)
+}
+
+/// Small set of elements generating all of `G`, picked greedily in the order of
+/// [`IterateGroup::all`].
+pub fn generators<G: IterateGroup + Hash>() -> Vec<G> {
+    let elements = G::all();
+    let mut generators = vec![];
+    let mut generated = HashSet::from([G::identity()]);
+    for g in elements.iter() {
+        if !generated.contains(g) {
+            generators.push(*g);
+            generated = generate(&generators);
+        }
+    }
+    generators
+}
+
+/// Brute-force search for an isomorphism from `G` to `H`, meant for small groups.
+///
+/// Images of a generating set of `G` are tried among the elements of `H` with matching order,
+/// each assignment is extended to the whole group and checked for consistency.
+pub fn find_isomorphism<G, H>() -> Option<HashMap<G, H>>
+where
+    G: IterateGroup + Hash,
+    H: IterateGroup + Hash,
+{
+    let size = G::all().len();
+    if size != H::all().len() {
+        return None;
+    }
+
+    let generators = generators::<G>();
+    let candidates: Vec<Vec<H>> = generators
+        .iter()
+        .map(|g| {
+            let n = order(*g);
+            H::all().into_iter().filter(|h| order(*h) == n).collect()
+        })
+        .collect();
+
+    // NOTE(lubo): Odometer over all combinations of candidate images.
+    let mut choice = vec![0; generators.len()];
+    if candidates.iter().any(|c| c.is_empty()) {
+        return None;
+    }
+    loop {
+        let images: Vec<H> = choice
+            .iter()
+            .zip(candidates.iter())
+            .map(|(i, c)| c[*i])
+            .collect();
+        if let Some(map) = extend(&generators, &images, size) {
+            return Some(map);
+        }
+
+        let mut k = 0;
+        loop {
+            if k == choice.len() {
+                return None;
+            }
+            choice[k] += 1;
+            if choice[k] < candidates[k].len() {
+                break;
+            }
+            choice[k] = 0;
+            k += 1;
+        }
+    }
+}
+
+/// The homomorphism sending `generators[i]` to `images[i]` if there is one and it is injective.
+fn extend<G, H>(generators: &[G], images: &[H], size: usize) -> Option<HashMap<G, H>>
+where
+    G: Group + Hash,
+    H: Group + Hash,
+{
+    let mut map = HashMap::from([(G::identity(), H::identity())]);
+    let mut open = VecDeque::from([G::identity()]);
+    while let Some(x) = open.pop_front() {
+        let fx = map[&x];
+        for (g, h) in generators.iter().zip(images.iter()) {
+            let y = x.op(*g);
+            let fy = fx.op(*h);
+            match map.get(&y) {
+                Some(image) if *image != fy => return None,
+                Some(_) => {}
+                None => {
+                    map.insert(y, fy);
+                    open.push_back(y);
+                }
+            }
+        }
+    }
+
+    let image: HashSet<_> = map.values().collect();
+    (map.len() == size && image.len() == size).then_some(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_isomorphism, generators, is_homomorphism, is_isomorphism};
+    use crate::{
+        group::{
+            Dn, Semidirect, Zn,
+            group::{Group, ThreeGroup, Vierergruppe},
+            product::Action,
+        },
+        symmetry::GridSymmetry,
+    };
+
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+    struct Invert;
+
+    impl Action<Zn<4>, bool> for Invert {
+        fn act(h: bool, n: Zn<4>) -> Zn<4> {
+            if h { n.inverse() } else { n }
+        }
+    }
+
+    #[test]
+    fn homomorphisms() {
+        assert!(is_homomorphism(|g: Zn<6>| Zn::<3>::new(g.0)));
+        assert!(!is_homomorphism(|g: Zn<4>| Zn::<3>::new(g.0)));
+        assert!(is_homomorphism(|g: GridSymmetry| g.is_reflection()));
+        assert!(is_homomorphism(|_: Vierergruppe| ThreeGroup::E));
+        assert!(!is_isomorphism(|_: Vierergruppe| ThreeGroup::E));
+        assert!(is_isomorphism(|g: Zn<3>| [
+            ThreeGroup::E,
+            ThreeGroup::A,
+            ThreeGroup::B
+        ][g.0]));
+    }
+
+    #[test]
+    fn isomorphisms() {
+        assert_eq!(generators::<Zn<6>>(), vec![Zn(1)]);
+        assert_eq!(generators::<Vierergruppe>().len(), 2);
+
+        let map = find_isomorphism::<ThreeGroup, Zn<3>>().unwrap();
+        assert!(is_isomorphism(|g: ThreeGroup| map[&g]));
+        assert!(find_isomorphism::<Vierergruppe, Zn<4>>().is_none());
+        assert!(find_isomorphism::<Vierergruppe, (bool, bool)>().is_some());
+        assert!(find_isomorphism::<(ThreeGroup, bool), Zn<6>>().is_some());
+        assert!(find_isomorphism::<Zn<6>, Dn<3>>().is_none());
+        assert!(find_isomorphism::<Zn<4>, Zn<5>>().is_none());
+
+        let map = find_isomorphism::<GridSymmetry, Dn<4>>().unwrap();
+        assert!(is_isomorphism(|g: GridSymmetry| map[&g]));
+        assert!(find_isomorphism::<Semidirect<Zn<4>, bool, Invert>, GridSymmetry>().is_some());
+        assert!(find_isomorphism::<(Zn<4>, bool), GridSymmetry>().is_none());
+    }
+}
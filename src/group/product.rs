@@ -0,0 +1,154 @@
+use std::marker::PhantomData;
+
+use super::group::{Group, IterateGroup};
+
+/// Direct product, both components are multiplied independently.
+impl<A: Group, B: Group> Group for (A, B) {
+    fn identity() -> Self {
+        (A::identity(), B::identity())
+    }
+
+    fn op(self, rhs: Self) -> Self {
+        (self.0.op(rhs.0), self.1.op(rhs.1))
+    }
+
+    fn inverse(self) -> Self {
+        (self.0.inverse(), self.1.inverse())
+    }
+}
+
+impl<A: IterateGroup, B: IterateGroup> IterateGroup for (A, B) {
+    fn all() -> Vec<Self> {
+        let b = B::all();
+        A::all()
+            .into_iter()
+            .flat_map(|a| b.iter().map(move |b| (a, *b)))
+            .collect()
+    }
+}
+
+/// Homomorphism from `H` into the automorphisms of `N`, selects the multiplication of a
+/// [`Semidirect`] product.
+///
+/// Implement it on a unit marker type, `act(h.op(g), n) == act(h, act(g, n))` and every
+/// `act(h, _)` must be an automorphism of `N`.
+pub trait Action<N: Group, H: Group> {
+    fn act(h: H, n: N) -> N;
+}
+
+/// Semidirect product `N ⋊ H`, `(n1, h1) * (n2, h2) = (n1 * act(h1, n2), h1 * h2)`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Semidirect<N, H, A> {
+    pub normal: N,
+    pub acting: H,
+    action: PhantomData<A>,
+}
+
+impl<N, H, A> Semidirect<N, H, A> {
+    pub const fn new(normal: N, acting: H) -> Self {
+        Self {
+            normal,
+            acting,
+            action: PhantomData,
+        }
+    }
+}
+
+impl<N, H, A> Group for Semidirect<N, H, A>
+where
+    N: Group,
+    H: Group,
+    A: Action<N, H> + Eq + Copy,
+{
+    fn identity() -> Self {
+        Self::new(N::identity(), H::identity())
+    }
+
+    fn op(self, rhs: Self) -> Self {
+        Self::new(
+            self.normal.op(A::act(self.acting, rhs.normal)),
+            self.acting.op(rhs.acting),
+        )
+    }
+
+    fn inverse(self) -> Self {
+        let acting = self.acting.inverse();
+        Self::new(A::act(acting, self.normal.inverse()), acting)
+    }
+}
+
+impl<N, H, A> IterateGroup for Semidirect<N, H, A>
+where
+    N: IterateGroup,
+    H: IterateGroup,
+    A: Action<N, H> + Eq + Copy,
+{
+    fn all() -> Vec<Self> {
+        <(N, H)>::all()
+            .into_iter()
+            .map(|(n, h)| Self::new(n, h))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Action, Semidirect};
+    use crate::group::{
+        cayley_table, generate,
+        group::{Group, IterateGroup, ThreeGroup, Vierergruppe},
+        order,
+    };
+
+    /// `bool` acting on the cyclic group of order 3 by inversion.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+    struct Invert;
+
+    impl Action<ThreeGroup, bool> for Invert {
+        fn act(h: bool, n: ThreeGroup) -> ThreeGroup {
+            if h { n.inverse() } else { n }
+        }
+    }
+
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+    struct Trivial;
+
+    impl Action<ThreeGroup, bool> for Trivial {
+        fn act(_h: bool, n: ThreeGroup) -> ThreeGroup {
+            n
+        }
+    }
+
+    #[test]
+    fn direct_product() {
+        let all = <(ThreeGroup, bool)>::all();
+        assert_eq!(all.len(), 6);
+        assert!(cayley_table(all).is_ok());
+        assert_eq!(order((ThreeGroup::A, true)), 6);
+        assert_eq!(generate(&[(Vierergruppe::A, ThreeGroup::A)]).len(), 6);
+        assert!(cayley_table(<((), (bool, Vierergruppe))>::all()).is_ok());
+    }
+
+    #[test]
+    fn semidirect_product() {
+        type S3 = Semidirect<ThreeGroup, bool, Invert>;
+        let all = S3::all();
+        assert_eq!(all.len(), 6);
+        assert!(cayley_table(all.clone()).is_ok());
+
+        let r = S3::new(ThreeGroup::A, false);
+        let s = S3::new(ThreeGroup::E, true);
+        assert_ne!(r.op(s), s.op(r));
+        assert_eq!(s.op(r).op(s), r.inverse());
+        assert_eq!(order(r), 3);
+        assert_eq!(order(r.op(s)), 2);
+        assert_eq!(generate(&[r, s]).len(), 6);
+
+        type Z6 = Semidirect<ThreeGroup, bool, Trivial>;
+        assert!(
+            Z6::all()
+                .iter()
+                .all(|a| Z6::all().iter().all(|b| a.op(*b) == b.op(*a)))
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use std::{collections::HashSet, hash::Hash};
+
+use super::group::IterateGroup;
+
+/// Every pair of elements commutes. `O(|G|^2)`.
+pub fn is_abelian<G: IterateGroup>() -> bool {
+    let elements = G::all();
+    elements
+        .iter()
+        .all(|a| elements.iter().all(|b| a.op(*b) == b.op(*a)))
+}
+
+/// Elements commuting with everything, in the order of [`IterateGroup::all`].
+pub fn center<G: IterateGroup>() -> Vec<G> {
+    let elements = G::all();
+    elements
+        .iter()
+        .filter(|a| elements.iter().all(|b| a.op(*b) == b.op(**a)))
+        .copied()
+        .collect()
+}
+
+/// Partition into classes of `g ~ h.op(g).op(h.inverse())`.
+///
+/// Classes are ordered by their first element in [`IterateGroup::all`], so the identity class
+/// comes first if the identity is listed first. Elements keep that order within a class.
+pub fn conjugacy_classes<G: IterateGroup + Hash>() -> Vec<Vec<G>> {
+    let elements = G::all();
+    let mut assigned = HashSet::new();
+    let mut classes = vec![];
+    for g in elements.iter() {
+        if assigned.contains(g) {
+            continue;
+        }
+        let class: HashSet<_> = elements.iter().map(|h| h.op(*g).op(h.inverse())).collect();
+        assigned.extend(class.iter().copied());
+        classes.push(
+            elements
+                .iter()
+                .filter(|x| class.contains(x))
+                .copied()
+                .collect(),
+        );
+    }
+    classes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{center, conjugacy_classes, is_abelian};
+    use crate::{
+        group::{Dn, Zn, group::Vierergruppe},
+        permutations::Perm,
+        symmetry::GridSymmetry,
+    };
+
+    #[test]
+    fn abelian() {
+        assert!(is_abelian::<Zn<6>>());
+        assert!(is_abelian::<Vierergruppe>());
+        assert!(is_abelian::<(Zn<2>, Zn<3>)>());
+        assert!(is_abelian::<Dn<2>>());
+        assert!(!is_abelian::<Dn<3>>());
+        assert!(!is_abelian::<GridSymmetry>());
+        assert_eq!(center::<Zn<5>>().len(), 5);
+    }
+
+    fn sizes<G>(classes: &[Vec<G>]) -> Vec<usize> {
+        let mut sizes: Vec<_> = classes.iter().map(|c| c.len()).collect();
+        sizes.sort();
+        sizes
+    }
+
+    #[test]
+    fn center_and_classes() {
+        assert_eq!(
+            center::<GridSymmetry>(),
+            vec![GridSymmetry::Identity, GridSymmetry::Rotate180]
+        );
+        assert_eq!(center::<Dn<5>>(), vec![Dn::new(0, false)]);
+        assert_eq!(center::<Perm<3>>().len(), 1);
+
+        assert_eq!(sizes(&conjugacy_classes::<Perm<3>>()), vec![1, 2, 3]);
+        assert_eq!(sizes(&conjugacy_classes::<Perm<4>>()), vec![1, 3, 6, 6, 8]);
+        assert_eq!(sizes(&conjugacy_classes::<Zn<4>>()), vec![1, 1, 1, 1]);
+
+        let classes = conjugacy_classes::<GridSymmetry>();
+        assert_eq!(sizes(&classes), vec![1, 1, 2, 2, 2]);
+        assert_eq!(classes[0], vec![GridSymmetry::Identity]);
+        assert!(classes.contains(&vec![GridSymmetry::Rotate90, GridSymmetry::Rotate270]));
+        assert!(classes.contains(&vec![GridSymmetry::FlipX, GridSymmetry::FlipY]));
+    }
+}
@@ -0,0 +1,203 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Binary min-heap of keys by priority that knows where each key is, so priorities can be
+/// lowered in place (`decrease_key`) instead of pushing duplicates and skipping stale entries.
+#[derive(Debug, Clone)]
+pub struct MinHeap<K, P> {
+    heap: Vec<(K, P)>,
+    positions: HashMap<K, usize>,
+}
+
+impl<K, P> Default for MinHeap<K, P> {
+    fn default() -> Self {
+        Self {
+            heap: vec![],
+            positions: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, P: PartialOrd> MinHeap<K, P> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.positions.contains_key(key)
+    }
+
+    pub fn priority(&self, key: &K) -> Option<&P> {
+        self.positions.get(key).map(|&i| &self.heap[i].1)
+    }
+
+    pub fn peek_min(&self) -> Option<(&K, &P)> {
+        self.heap.first().map(|(k, p)| (k, p))
+    }
+
+    /// Insert `key`, or change its priority if it is already queued.
+    pub fn push(&mut self, key: K, priority: P) {
+        match self.positions.get(&key) {
+            Some(&i) => {
+                let raise = priority > self.heap[i].1;
+                self.heap[i].1 = priority;
+                if raise {
+                    self.sift_down(i);
+                } else {
+                    self.sift_up(i);
+                }
+            }
+            None => {
+                self.positions.insert(key.clone(), self.heap.len());
+                self.heap.push((key, priority));
+                self.sift_up(self.heap.len() - 1);
+            }
+        }
+    }
+
+    /// Lower the priority of `key`, or insert it. Returns `false` and changes nothing if it is
+    /// already queued with a priority not greater than `priority`.
+    pub fn decrease_key(&mut self, key: K, priority: P) -> bool {
+        if let Some(&i) = self.positions.get(&key) {
+            if priority.partial_cmp(&self.heap[i].1) != Some(std::cmp::Ordering::Less) {
+                return false;
+            }
+            self.heap[i].1 = priority;
+            self.sift_up(i);
+        } else {
+            self.push(key, priority);
+        }
+        true
+    }
+
+    pub fn pop_min(&mut self) -> Option<(K, P)> {
+        self.remove_at(0)
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<P> {
+        let i = *self.positions.get(key)?;
+        self.remove_at(i).map(|(_, p)| p)
+    }
+
+    fn remove_at(&mut self, i: usize) -> Option<(K, P)> {
+        if i >= self.heap.len() {
+            return None;
+        }
+        let last = self.heap.len() - 1;
+        self.swap(i, last);
+        let (key, priority) = self.heap.pop()?;
+        self.positions.remove(&key);
+        if i < self.heap.len() {
+            self.sift_down(i);
+            self.sift_up(i);
+        }
+        Some((key, priority))
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        *self.positions.get_mut(&self.heap[a].0).unwrap() = a;
+        *self.positions.get_mut(&self.heap[b].0).unwrap() = b;
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if self.heap[i].1 < self.heap[parent].1 {
+                self.swap(i, parent);
+                i = parent;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let mut smallest = i;
+            for child in [2 * i + 1, 2 * i + 2] {
+                if child < self.heap.len() && self.heap[child].1 < self.heap[smallest].1 {
+                    smallest = child;
+                }
+            }
+            if smallest == i {
+                break;
+            }
+            self.swap(i, smallest);
+            i = smallest;
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, P: PartialOrd> FromIterator<(K, P)> for MinHeap<K, P> {
+    fn from_iter<I: IntoIterator<Item = (K, P)>>(iter: I) -> Self {
+        let mut result = Self::new();
+        for (k, p) in iter {
+            result.push(k, p);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+
+    #[test]
+    fn ordering() {
+        let mut heap: MinHeap<char, i32> = [('a', 5), ('b', 3), ('c', 8), ('d', 1)]
+            .into_iter()
+            .collect();
+        assert_eq!(heap.peek_min(), Some((&'d', &1)));
+        assert!(heap.decrease_key('c', 0));
+        assert!(!heap.decrease_key('a', 7));
+        assert_eq!(heap.priority(&'a'), Some(&5));
+        heap.push('d', 10);
+        assert_eq!(heap.remove(&'b'), Some(3));
+        assert!(!heap.contains(&'b'));
+
+        let order: Vec<(char, i32)> = std::iter::from_fn(|| heap.pop_min()).collect();
+        assert_eq!(order, vec![('c', 0), ('a', 5), ('d', 10)]);
+        assert!(heap.is_empty());
+        assert_eq!(heap.pop_min(), None);
+    }
+
+    #[test]
+    fn matches_sorting() {
+        let mut rng = Rng::new(3);
+        let mut heap = MinHeap::new();
+        let mut expected = std::collections::HashMap::new();
+        for _ in 0..2000 {
+            let key = rng.index(300);
+            let priority = rng.range(0..10000);
+            match rng.index(3) {
+                0 => {
+                    heap.push(key, priority);
+                    expected.insert(key, priority);
+                }
+                1 => {
+                    if heap.decrease_key(key, priority) {
+                        expected.insert(key, priority);
+                    }
+                }
+                _ => {
+                    assert_eq!(heap.remove(&key), expected.remove(&key));
+                }
+            }
+        }
+        let mut expected: Vec<i64> = expected.into_values().collect();
+        expected.sort();
+        let popped: Vec<i64> = std::iter::from_fn(|| heap.pop_min())
+            .map(|(_, p)| p)
+            .collect();
+        assert_eq!(popped, expected);
+    }
+}
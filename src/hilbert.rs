@@ -0,0 +1,298 @@
+use crate::{aabb::Aabb, linear_index::LinearIndex, vector::Vector};
+
+/// Hilbert curve through the `2^bits` sided cube in `N` dimensions, consecutive indices are
+/// always adjacent lattice points.
+///
+/// Uses John Skilling's transposition, "Programming the Hilbert curve" (2004).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HilbertCurve<const N: usize> {
+    bits: u32,
+}
+
+impl<const N: usize> HilbertCurve<N> {
+    /// `None` when the indices would not fit in `usize`.
+    pub fn new(bits: u32) -> Option<Self> {
+        (bits <= u32::BITS && N as u64 * bits as u64 <= usize::BITS as u64).then_some(Self { bits })
+    }
+
+    /// Smallest curve covering a box of size `dims`.
+    pub fn covering(dims: Vector<N, usize>) -> Option<Self> {
+        let side = dims.values.iter().copied().max().unwrap_or(1).max(1);
+        Self::new(side.next_power_of_two().trailing_zeros())
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    pub fn side(&self) -> u64 {
+        1 << self.bits
+    }
+
+    fn axes_to_index(&self, mut x: [u32; N]) -> u128 {
+        if self.bits == 0 || N == 0 {
+            return 0;
+        }
+        let m = 1u32 << (self.bits - 1);
+
+        // NOTE(lubo): Inverse undo.
+        let mut q = m;
+        while q > 1 {
+            let p = q - 1;
+            for i in 0..N {
+                if x[i] & q != 0 {
+                    x[0] ^= p;
+                } else {
+                    let t = (x[0] ^ x[i]) & p;
+                    x[0] ^= t;
+                    x[i] ^= t;
+                }
+            }
+            q >>= 1;
+        }
+
+        // NOTE(lubo): Gray encode.
+        for i in 1..N {
+            x[i] ^= x[i - 1];
+        }
+        let mut t = 0;
+        let mut q = m;
+        while q > 1 {
+            if x[N - 1] & q != 0 {
+                t ^= q - 1;
+            }
+            q >>= 1;
+        }
+        for v in x.iter_mut() {
+            *v ^= t;
+        }
+
+        // NOTE(lubo): The transposed index has its bits spread across the axes.
+        let mut index = 0;
+        for j in (0..self.bits).rev() {
+            for v in x.iter() {
+                index = (index << 1) | ((v >> j) & 1) as u128;
+            }
+        }
+        index
+    }
+
+    fn index_to_axes(&self, index: u128) -> [u32; N] {
+        let mut x = [0u32; N];
+        if self.bits == 0 || N == 0 {
+            return x;
+        }
+        let mut bit = N * self.bits as usize;
+        for j in (0..self.bits).rev() {
+            for v in x.iter_mut() {
+                bit -= 1;
+                *v |= (((index >> bit) & 1) as u32) << j;
+            }
+        }
+
+        // NOTE(lubo): Gray decode.
+        let t = x[N - 1] >> 1;
+        for i in (1..N).rev() {
+            x[i] ^= x[i - 1];
+        }
+        x[0] ^= t;
+
+        // NOTE(lubo): Undo excess work.
+        let mut q = 2u64;
+        while q != self.side() {
+            let p = (q - 1) as u32;
+            for i in (0..N).rev() {
+                if x[i] & q as u32 != 0 {
+                    x[0] ^= p;
+                } else {
+                    let t = (x[0] ^ x[i]) & p;
+                    x[0] ^= t;
+                    x[i] ^= t;
+                }
+            }
+            q <<= 1;
+        }
+        x
+    }
+}
+
+impl<const N: usize> LinearIndex<Vector<N, i32>> for HilbertCurve<N> {
+    fn index_unchecked(&self, i: Vector<N, i32>) -> Option<usize> {
+        let mut x = [0; N];
+        for (a, b) in x.iter_mut().zip(i.values) {
+            *a = b.try_into().ok()?;
+        }
+        Some(self.axes_to_index(x) as usize)
+    }
+
+    fn unindex(&self, i: usize) -> Option<Vector<N, i32>> {
+        if (i as u128) >> (N as u32 * self.bits) != 0 {
+            return None;
+        }
+        let x = self.index_to_axes(i as u128);
+        let mut result = [0; N];
+        for (a, b) in result.iter_mut().zip(x) {
+            *a = b.try_into().ok()?;
+        }
+        Some(Vector::new(result))
+    }
+
+    fn is_in_bounds(&self, i: &Vector<N, i32>) -> bool {
+        i.values.iter().all(|&a| a >= 0 && (a as u64) < self.side())
+    }
+
+    fn checked_cardinality(&self) -> Option<usize> {
+        1usize.checked_shl(N as u32 * self.bits)
+    }
+}
+
+/// Lattice points of an [`Aabb`] in the order of the smallest [`HilbertCurve`] covering it, see
+/// [`Aabb::iter_hilbert`].
+///
+/// Walks the curve lazily and skips the parts of it outside the box, so thin boxes are cheap.
+#[derive(Debug, Clone)]
+pub struct HilbertPoints<const N: usize> {
+    curve: HilbertCurve<N>,
+    min: Vector<N, i32>,
+    dims: [u64; N],
+    // NOTE(lubo): First index and bits per axis of the sub-cubes left to visit, the next one last.
+    stack: Vec<(u128, u32)>,
+}
+
+impl<const N: usize> HilbertPoints<N> {
+    /// `None` when the indices of the covering curve would not fit in `u128`.
+    pub fn new(aabb: &Aabb<N, i32>) -> Option<Self> {
+        let dims = std::array::from_fn(|x| {
+            (aabb.max.values[x] as i64 - aabb.min.values[x] as i64 + 1).max(0) as u64
+        });
+        let side = dims.iter().copied().max().unwrap_or(1).max(1);
+        let bits = side.next_power_of_two().trailing_zeros();
+        if N as u64 * bits as u64 > u128::BITS as u64 {
+            return None;
+        }
+        let empty = dims.contains(&0);
+        Some(Self {
+            curve: HilbertCurve { bits },
+            min: aabb.min,
+            dims,
+            stack: if empty { vec![] } else { vec![(0, bits)] },
+        })
+    }
+}
+
+impl<const N: usize> Iterator for HilbertPoints<N> {
+    type Item = Vector<N, i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((start, bits)) = self.stack.pop() {
+            // NOTE(lubo): The sub-cube is aligned, its corner has the low `bits` bits cleared.
+            let mask = u32::MAX.checked_shl(bits).unwrap_or(0);
+            let corner = self.curve.index_to_axes(start).map(|a| a & mask);
+            if corner.iter().zip(&self.dims).any(|(&a, &d)| a as u64 >= d) {
+                continue;
+            }
+            if bits == 0 {
+                let mut p = self.min;
+                for (v, a) in p.values.iter_mut().zip(corner) {
+                    *v = (*v as i64 + a as i64) as i32;
+                }
+                return Some(p);
+            }
+            let child = 1u128 << (N as u32 * (bits - 1));
+            for k in (0..1u128 << N).rev() {
+                self.stack.push((start + k * child, bits - 1));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HilbertCurve;
+    use crate::{
+        aabb::Aabb,
+        geometric_traits::ManhattanDistance,
+        linear_index::LinearIndex,
+        vector::{V2, V3, Vector},
+    };
+
+    fn check_curve<const N: usize>(bits: u32) {
+        let curve = HilbertCurve::<N>::new(bits).unwrap();
+        let count = 1usize << (N as u32 * bits);
+        let mut seen = std::collections::HashSet::new();
+        let mut previous: Option<Vector<N, i32>> = None;
+        for d in 0..count {
+            let p = curve.unindex(d).unwrap();
+            assert!(curve.is_in_bounds(&p));
+            assert_eq!(curve.index(p), Some(d));
+            assert!(seen.insert(p));
+            if let Some(q) = previous {
+                assert_eq!(p.manhattan_distance(&q), 1);
+            }
+            previous = Some(p);
+        }
+        assert_eq!(curve.unindex(count), None);
+    }
+
+    #[test]
+    fn curves() {
+        check_curve::<2>(0);
+        check_curve::<2>(1);
+        check_curve::<2>(4);
+        check_curve::<3>(3);
+        check_curve::<4>(2);
+
+        let curve = HilbertCurve::<2>::new(1).unwrap();
+        let order: Vec<_> = (0..4).map(|d| curve.unindex(d).unwrap()).collect();
+        assert_eq!(
+            order,
+            vec![
+                V2::from_xy(0, 0),
+                V2::from_xy(0, 1),
+                V2::from_xy(1, 1),
+                V2::from_xy(1, 0)
+            ]
+        );
+        assert_eq!(curve.index(V2::from_xy(2, 0)), None);
+        assert_eq!(HilbertCurve::<2>::new(33), None);
+        assert_eq!(
+            HilbertCurve::covering(Vector::new([5, 3])),
+            HilbertCurve::new(3)
+        );
+    }
+
+    #[test]
+    fn aabb_in_hilbert_order() {
+        let aabb = Aabb::new(V3::from_xyz(-2, 5, 0), V3::from_xyz(1, 7, 4));
+        let points: Vec<_> = aabb.iter_hilbert().unwrap().collect();
+        let mut expected: Vec<_> = aabb.iter_points().unwrap().collect();
+        assert_eq!(points.len(), expected.len());
+        let mut sorted = points.clone();
+        sorted.sort_by_key(|p| p.values);
+        expected.sort_by_key(|p| p.values);
+        assert_eq!(sorted, expected);
+
+        let thin = Aabb::new(V3::from_xyz(0, 0, 0), V3::from_xyz(3_000_000, 0, 0));
+        let mut points = thin.iter_hilbert().unwrap();
+        assert_eq!(points.next(), Some(V3::from_xyz(0, 0, 0)));
+        assert_eq!(points.next(), Some(V3::from_xyz(1, 0, 0)));
+        let thin = Aabb::new(V3::from_xyz(-7, 2, 9), V3::from_xyz(1000, 3, 9));
+        assert_eq!(thin.iter_hilbert().unwrap().count(), 2016);
+
+        let inverted = Aabb::new(V2::from_xy(1, 1), V2::from_xy(0, 0));
+        assert_eq!(inverted.iter_hilbert().unwrap().count(), 0);
+        let huge = Aabb::new(Vector::all(i32::MIN), Vector::<5, i32>::all(i32::MAX));
+        assert!(huge.iter_hilbert().is_none());
+
+        // NOTE(lubo): A power of two sided box is traversed without jumps.
+        let square = Aabb::new(V2::from_xy(-4, -4), V2::from_xy(3, 3));
+        let points: Vec<_> = square.iter_hilbert().unwrap().collect();
+        assert_eq!(points.len(), 64);
+        assert!(
+            points
+                .windows(2)
+                .all(|w| w[0].manhattan_distance(&w[1]) == 1)
+        );
+    }
+}
@@ -167,7 +167,29 @@ macro_rules! impl_universal_interval {
 }
 
 impl_universal_interval!(MIN, MAX; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
-impl_universal_interval!(NEG_INFINITY, INFINITY; f32, f64, crate::ord_float::OrdF32, crate::ord_float::OrdF64);
+impl_universal_interval!(NEG_INFINITY, INFINITY; f32, f64, crate::ord_float::NotNanF32, crate::ord_float::NotNanF64);
+
+// NOTE(lubo): The total order puts NaNs past the infinities, they are extremes just as well.
+macro_rules! impl_universal_interval_total_order {
+    ($($t:ty),*) => {
+        $(
+            impl UniversalInterval for $t {
+                const INFINUM: Self = <$t>::NEG_INFINITY;
+                const SUPREMUM: Self = <$t>::INFINITY;
+
+                fn is_infinum(&self) -> bool {
+                    *self <= Self::INFINUM
+                }
+
+                fn is_supremum(&self) -> bool {
+                    *self >= Self::SUPREMUM
+                }
+            }
+        )*
+    };
+}
+
+impl_universal_interval_total_order!(crate::ord_float::OrdF32, crate::ord_float::OrdF64);
 
 pub trait IntervalExt
 where
@@ -130,7 +130,9 @@ impl<T: Copy + Add<Output = T> + Sub<Output = T> + Sum> IntervalSet<T> {
             .map(|x| *x.exclusive_max() - *x.inclusive_min())
             .sum()
     }
+}
 
+impl<T: Copy + Ord> IntervalSet<T> {
     pub fn bounds(&self) -> Option<std::ops::Range<T>> {
         let count = self.intervals.len();
         if count > 0 {
@@ -147,6 +149,7 @@ impl<T: Copy + Add<Output = T> + Sub<Output = T> + Sum> IntervalSet<T> {
     /// For example, the most extreme values for `i32` are `i32::MIN` and `i32::MAX`.
     /// For `f32`, the most extreme values would be `f32::NEG_INFINITY` and `f32::INFINITY`.
     /// (Although `f32` cannot be used since it does not implement `Ord`. See [`crate::ord_float::OrdF32`].)
+    /// For the ordered floats NaNs sort past the infinities and count as extremes as well.
     /// These bounds are defined in the [`UniversalInterval`] trait which is required for
     /// this function.
     ///
@@ -199,38 +202,50 @@ impl<T: Copy + Add<Output = T> + Sub<Output = T> + Sum> IntervalSet<T> {
             Self { intervals: vec![] }
         }
     }
-}
 
-impl<T: Copy + Ord> IntervalSet<T> {
-    pub fn containing_interval(&self, value: &T) -> Option<std::ops::Range<T>> {
-        let index0 = match self
+    /// Index of the interval containing `value`, if any.
+    pub fn containing_interval_index(&self, value: &T) -> Option<usize> {
+        let index = self
             .intervals
-            .binary_search_by(|probe| probe.exclusive_max().cmp(value))
-        {
-            Ok(value) => value,
-            Err(value) => value,
-        };
-        if let Some(a) = self.intervals.get(index0) {
-            if a.contains(value) {
-                Some(a.clone())
-            } else {
-                None
-            }
-        } else {
-            None
+            .partition_point(|probe| probe.exclusive_max() <= value);
+        match self.intervals.get(index) {
+            Some(a) if a.contains(value) => Some(index),
+            _ => None,
         }
     }
 
+    pub fn containing_interval(&self, value: &T) -> Option<std::ops::Range<T>> {
+        self.containing_interval_index(value)
+            .map(|index| self.intervals[index].clone())
+    }
+
     pub fn contains(&self, value: &T) -> bool {
-        self.containing_interval(value).is_some()
+        self.containing_interval_index(value).is_some()
+    }
+
+    /// Iterate all intervals that overlap the given interval, without modifying the set.
+    ///
+    /// Uses the same notion of overlap as [`Interval::overlaps`].
+    pub fn intervals_overlapping(
+        &self,
+        interval: std::ops::Range<T>,
+    ) -> impl Iterator<Item = &std::ops::Range<T>> {
+        let index0 = self
+            .intervals
+            .partition_point(|probe| probe.exclusive_max() <= interval.inclusive_min());
+        let index1 = self
+            .intervals
+            .partition_point(|probe| probe.inclusive_min() < interval.exclusive_max());
+        self.intervals[index0..index1.max(index0)].iter()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
+        interval::{Interval, UniversalInterval},
         interval_set::IntervalSet,
-        ord_float::{OrdF32, OrdF64},
+        ord_float::{NotNanF64, OrdF32, OrdF64},
     };
 
     #[test]
@@ -274,6 +289,42 @@ mod tests {
         assert!(!set.contains(&i32::MAX));
     }
 
+    #[test]
+    fn queries() {
+        let mut set = IntervalSet::new();
+        set.union(0..2);
+        set.union(4..6);
+        set.union(8..10);
+
+        assert_eq!(set.containing_interval_index(&-1), None);
+        assert_eq!(set.containing_interval_index(&0), Some(0));
+        assert_eq!(set.containing_interval_index(&2), None);
+        assert_eq!(set.containing_interval_index(&5), Some(1));
+        assert_eq!(set.containing_interval_index(&9), Some(2));
+        assert_eq!(set.containing_interval_index(&10), None);
+        assert_eq!(set.containing_interval(&4), Some(4..6));
+
+        let overlapping = |a, b| set.intervals_overlapping(a..b).cloned().collect::<Vec<_>>();
+        assert_eq!(overlapping(1, 9), vec![0..2, 4..6, 8..10]);
+        assert_eq!(overlapping(2, 4), vec![]);
+        assert_eq!(overlapping(2, 5), vec![4..6]);
+        assert_eq!(overlapping(5, 20), vec![4..6, 8..10]);
+        assert_eq!(overlapping(-5, 0), vec![]);
+        assert_eq!(overlapping(6, 2), vec![]);
+
+        for a in -1..11 {
+            for b in a..12 {
+                let expected = set
+                    .intervals
+                    .iter()
+                    .filter(|x| x.overlaps(&(a..b)))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                assert_eq!(overlapping(a, b), expected);
+            }
+        }
+    }
+
     #[test]
     fn f32() {
         let a = OrdF32(0.0)..OrdF32(2.0);
@@ -335,4 +386,51 @@ mod tests {
         assert!(!set.contains(&OrdF64(f64::MAX)));
         assert!(!set.contains(&OrdF64(f64::INFINITY)));
     }
+
+    #[test]
+    fn float_edge_cases() {
+        // NOTE(lubo): Negative zero sorts below zero, `[-0.0, 0.0)` holds exactly one value.
+        let mut set = IntervalSet::new();
+        set.union(OrdF64(-0.0)..OrdF64(0.0));
+        assert_eq!(set.intervals.len(), 1);
+        assert_eq!(*set.measure(), 0.0);
+        assert!(set.contains(&OrdF64(-0.0)));
+        assert!(!set.contains(&OrdF64(0.0)));
+        assert_eq!(
+            set.negation().intervals,
+            vec![
+                OrdF64::NEG_INFINITY..OrdF64(-0.0),
+                OrdF64(0.0)..OrdF64::INFINITY
+            ]
+        );
+        assert_eq!(set.negation().negation(), set);
+
+        let mut set = IntervalSet::new();
+        set.union(OrdF64(0.0)..OrdF64(0.0));
+        assert!(set.intervals.is_empty());
+        assert_eq!(set.negation().intervals, vec![OrdF64::universal_interval()]);
+
+        let mut set = IntervalSet::new();
+        set.union(OrdF32::NEG_INFINITY..OrdF32(1.0));
+        set.union(OrdF32(2.0)..OrdF32::INFINITY);
+        assert_eq!(set.negation().intervals, vec![OrdF32(1.0)..OrdF32(2.0)]);
+        assert_eq!(set.negation().negation(), set);
+
+        // NOTE(lubo): NaN bounds are past the infinities, negation must not invert them.
+        let mut set = IntervalSet::new();
+        set.union(OrdF32(-f32::NAN)..OrdF32(-1.0));
+        set.union(OrdF32(1.0)..OrdF32(f32::NAN));
+        assert_eq!(set.negation().intervals, vec![OrdF32(-1.0)..OrdF32(1.0)]);
+
+        let mut set = IntervalSet::new();
+        let bound = |x| NotNanF64::new(x).unwrap();
+        set.union(bound(-1.0)..bound(1.0));
+        assert_eq!(
+            set.negation().intervals,
+            vec![
+                NotNanF64::INFINUM..bound(-1.0),
+                bound(1.0)..NotNanF64::SUPREMUM
+            ]
+        );
+    }
 }
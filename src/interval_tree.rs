@@ -0,0 +1,242 @@
+use std::ops::Range;
+
+use super::interval::{ExclusiveMax, InclusiveMin};
+
+/// Set of possibly-overlapping intervals, each carrying a value.
+///
+/// Unlike [`crate::interval_set::IntervalSet`], intervals are never coalesced, so every
+/// inserted interval keeps its identity and its value.
+///
+/// Entries are kept in a flat vector sorted by start, which is viewed as an implicit
+/// balanced binary search tree (the middle of every range is the node). Each node stores
+/// the maximum end of its subtree, so stabbing and overlap queries run in `O(log n + k)`.
+///
+/// Building from an iterator is `O(n log n)`. [`IntervalTree::insert`] is `O(n)`, prefer
+/// collecting when inserting many intervals at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalTree<T, V> {
+    entries: Vec<(Range<T>, V)>,
+    max_end: Vec<T>,
+}
+
+impl<T: Copy + Ord, V> IntervalTree<T, V> {
+    pub fn new() -> Self {
+        Self {
+            entries: vec![],
+            max_end: vec![],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn insert(&mut self, interval: Range<T>, value: V) {
+        let index = self
+            .entries
+            .partition_point(|(x, _)| x.inclusive_min() <= interval.inclusive_min());
+        self.entries.insert(index, (interval, value));
+        self.reindex();
+    }
+
+    /// Iterate all entries ordered by the start of their interval.
+    pub fn iter(&self) -> impl Iterator<Item = (&Range<T>, &V)> {
+        self.entries.iter().map(|(x, v)| (x, v))
+    }
+
+    /// Iterate all entries whose interval contains `point`, ordered by start.
+    pub fn stabbing(&self, point: &T) -> impl Iterator<Item = (&Range<T>, &V)> {
+        let mut found = vec![];
+        self.collect(
+            0,
+            self.entries.len(),
+            point,
+            &|start| start <= point,
+            &mut found,
+        );
+        found.into_iter().map(|i| {
+            let (x, v) = &self.entries[i];
+            (x, v)
+        })
+    }
+
+    /// Iterate all entries whose interval overlaps `interval`, ordered by start.
+    ///
+    /// Uses the same notion of overlap as [`crate::interval::Interval::overlaps`].
+    pub fn overlapping(&self, interval: Range<T>) -> impl Iterator<Item = (&Range<T>, &V)> {
+        let (start, end) = (interval.inclusive_min(), interval.exclusive_max());
+        let mut found = vec![];
+        self.collect(0, self.entries.len(), start, &|x| x < end, &mut found);
+        found.into_iter().map(|i| {
+            let (x, v) = &self.entries[i];
+            (x, v)
+        })
+    }
+
+    fn reindex(&mut self) {
+        self.max_end = self
+            .entries
+            .iter()
+            .map(|(x, _)| *x.exclusive_max())
+            .collect();
+        self.reindex_range(0, self.entries.len());
+    }
+
+    fn reindex_range(&mut self, lo: usize, hi: usize) -> Option<T> {
+        if lo >= hi {
+            return None;
+        }
+
+        let mid = lo + (hi - lo) / 2;
+        let mut max_end = self.max_end[mid];
+        if let Some(left) = self.reindex_range(lo, mid) {
+            max_end = max_end.max(left);
+        }
+        if let Some(right) = self.reindex_range(mid + 1, hi) {
+            max_end = max_end.max(right);
+        }
+        self.max_end[mid] = max_end;
+        Some(max_end)
+    }
+
+    // NOTE(lubo): Collects (in order) indices of entries with `end > after` and `before(start)`.
+    // `before` must be monotone in start, so once it fails the whole right subtree can be skipped.
+    fn collect<F>(&self, lo: usize, hi: usize, after: &T, before: &F, found: &mut Vec<usize>)
+    where
+        F: Fn(&T) -> bool,
+    {
+        if lo >= hi {
+            return;
+        }
+
+        let mid = lo + (hi - lo) / 2;
+        if self.max_end[mid] <= *after {
+            return;
+        }
+
+        self.collect(lo, mid, after, before, found);
+
+        let (interval, _) = &self.entries[mid];
+        if before(interval.inclusive_min()) {
+            if interval.exclusive_max() > after {
+                found.push(mid);
+            }
+            self.collect(mid + 1, hi, after, before, found);
+        }
+    }
+}
+
+impl<T: Copy + Ord, V> Default for IntervalTree<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Ord, V> FromIterator<(Range<T>, V)> for IntervalTree<T, V> {
+    fn from_iter<I: IntoIterator<Item = (Range<T>, V)>>(iter: I) -> Self {
+        let mut entries: Vec<_> = iter.into_iter().collect();
+        entries.sort_by_key(|(x, _)| *x.inclusive_min());
+        let mut result = Self {
+            entries,
+            max_end: vec![],
+        };
+        result.reindex();
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntervalTree;
+    use crate::interval::Interval;
+
+    fn example() -> IntervalTree<i32, char> {
+        [
+            (0..10, 'a'),
+            (2..3, 'b'),
+            (4..8, 'c'),
+            (5..6, 'd'),
+            (5..20, 'e'),
+            (12..15, 'f'),
+            (16..17, 'g'),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn stabbing() {
+        let tree = example();
+        let values = |p| tree.stabbing(&p).map(|(_, v)| *v).collect::<String>();
+        assert_eq!(values(-1), "");
+        assert_eq!(values(0), "a");
+        assert_eq!(values(2), "ab");
+        assert_eq!(values(5), "acde");
+        assert_eq!(values(10), "e");
+        assert_eq!(values(16), "eg");
+        assert_eq!(values(20), "");
+    }
+
+    #[test]
+    fn overlapping() {
+        let tree = example();
+        let values = |a, b| tree.overlapping(a..b).map(|(_, v)| *v).collect::<String>();
+        assert_eq!(values(3, 4), "a");
+        assert_eq!(values(3, 5), "ac");
+        assert_eq!(values(9, 13), "aef");
+        assert_eq!(values(20, 30), "");
+    }
+
+    #[test]
+    fn keeps_duplicates() {
+        let mut tree = IntervalTree::new();
+        tree.insert(0..2, 1);
+        tree.insert(0..2, 2);
+        tree.insert(1..3, 3);
+        assert_eq!(tree.len(), 3);
+        assert_eq!(tree.stabbing(&1).count(), 3);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut tree = IntervalTree::new();
+        let mut intervals = vec![];
+        for i in 0..40 {
+            let start = (i * 7) % 23;
+            let end = start + (i * 5) % 11 + 1;
+            tree.insert(start..end, i);
+            intervals.push((start..end, i));
+        }
+        intervals.sort_by_key(|(x, _)| x.start);
+
+        for p in -1..36 {
+            let mut expected: Vec<_> = intervals
+                .iter()
+                .filter(|(x, _)| x.contains(&p))
+                .map(|(_, v)| *v)
+                .collect();
+            let mut actual: Vec<_> = tree.stabbing(&p).map(|(_, v)| *v).collect();
+            expected.sort();
+            actual.sort();
+            assert_eq!(actual, expected);
+        }
+
+        for a in -1..36 {
+            for b in (a + 1)..37 {
+                let mut expected: Vec<_> = intervals
+                    .iter()
+                    .filter(|(x, _)| x.overlaps(&(a..b)))
+                    .map(|(_, v)| *v)
+                    .collect();
+                let mut actual: Vec<_> = tree.overlapping(a..b).map(|(_, v)| *v).collect();
+                expected.sort();
+                actual.sort();
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use std::{
+    cmp::Ordering,
+    ops::{Add, Mul, Sub},
+};
+
+use super::{aabb::Aabb, vector::Vector};
+
+/// Static k-d tree over `C` dimensional points carrying values `V`, for nearest neighbour and
+/// range queries. Distances are squared euclidean, computed in `T`.
+///
+/// Stored implicitly: the median of every subslice is its root, split along axis `depth % C`.
+#[derive(Debug, Clone)]
+pub struct KdTree<const C: usize, T, V> {
+    points: Vec<(Vector<C, T>, V)>,
+}
+
+impl<const C: usize, T, V> KdTree<C, T, V>
+where
+    T: Copy + PartialOrd,
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    pub fn new(mut points: Vec<(Vector<C, T>, V)>) -> Self {
+        Self::build(&mut points, 0);
+        Self { points }
+    }
+
+    fn build(points: &mut [(Vector<C, T>, V)], depth: usize) {
+        if points.len() <= 1 || C == 0 {
+            return;
+        }
+        let axis = depth % C;
+        let mid = points.len() / 2;
+        points.select_nth_unstable_by(mid, |a, b| {
+            a.0.values[axis]
+                .partial_cmp(&b.0.values[axis])
+                .unwrap_or(Ordering::Equal)
+        });
+        let (left, right) = points.split_at_mut(mid);
+        Self::build(left, depth + 1);
+        Self::build(&mut right[1..], depth + 1);
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Vector<C, T>, &V)> {
+        self.points.iter().map(|(p, v)| (p, v))
+    }
+
+    fn distance_squared(a: &Vector<C, T>, b: &Vector<C, T>) -> T {
+        let delta = *a - *b;
+        delta.inner(delta)
+    }
+
+    pub fn nearest(&self, query: &Vector<C, T>) -> Option<(&Vector<C, T>, &V)> {
+        self.k_nearest(query, 1).into_iter().next()
+    }
+
+    /// Up to `k` points closest to `query`, closest first.
+    pub fn k_nearest(&self, query: &Vector<C, T>, k: usize) -> Vec<(&Vector<C, T>, &V)> {
+        let mut best: Vec<(T, usize)> = Vec::with_capacity(k + 1);
+        if k > 0 {
+            self.k_nearest_in(0, self.points.len(), 0, query, k, &mut best);
+        }
+        best.into_iter()
+            .map(|(_, i)| (&self.points[i].0, &self.points[i].1))
+            .collect()
+    }
+
+    fn k_nearest_in(
+        &self,
+        start: usize,
+        end: usize,
+        depth: usize,
+        query: &Vector<C, T>,
+        k: usize,
+        best: &mut Vec<(T, usize)>,
+    ) {
+        if start >= end {
+            return;
+        }
+        let mid = start + (end - start) / 2;
+        let point = &self.points[mid].0;
+
+        let distance = Self::distance_squared(point, query);
+        if best.len() < k || distance < best[best.len() - 1].0 {
+            let at = best.partition_point(|(d, _)| *d <= distance);
+            best.insert(at, (distance, mid));
+            best.truncate(k);
+        }
+
+        let axis = depth % C;
+        let delta = query.values[axis] - point.values[axis];
+        let (near, far) = if query.values[axis] < point.values[axis] {
+            ((start, mid), (mid + 1, end))
+        } else {
+            ((mid + 1, end), (start, mid))
+        };
+        self.k_nearest_in(near.0, near.1, depth + 1, query, k, best);
+        if best.len() < k || delta * delta < best[best.len() - 1].0 {
+            self.k_nearest_in(far.0, far.1, depth + 1, query, k, best);
+        }
+    }
+
+    /// All points inside the closed box `aabb`.
+    pub fn within_aabb(&self, aabb: &Aabb<C, T>) -> Vec<(&Vector<C, T>, &V)> {
+        let mut result = vec![];
+        self.within_in(
+            0,
+            self.points.len(),
+            0,
+            aabb,
+            &|p| aabb.contains_point(p),
+            &mut result,
+        );
+        result
+    }
+
+    /// All points at most `radius` away from `center`.
+    pub fn within_radius(&self, center: &Vector<C, T>, radius: T) -> Vec<(&Vector<C, T>, &V)> {
+        let bounds = Aabb::new(*center - Vector::all(radius), *center + Vector::all(radius));
+        let radius_squared = radius * radius;
+        let mut result = vec![];
+        self.within_in(
+            0,
+            self.points.len(),
+            0,
+            &bounds,
+            &|p| Self::distance_squared(p, center) <= radius_squared,
+            &mut result,
+        );
+        result
+    }
+
+    fn within_in<'a, F: Fn(&Vector<C, T>) -> bool>(
+        &'a self,
+        start: usize,
+        end: usize,
+        depth: usize,
+        bounds: &Aabb<C, T>,
+        accept: &F,
+        result: &mut Vec<(&'a Vector<C, T>, &'a V)>,
+    ) {
+        if start >= end {
+            return;
+        }
+        let mid = start + (end - start) / 2;
+        let (point, value) = &self.points[mid];
+        if accept(point) {
+            result.push((point, value));
+        }
+        let axis = depth % C;
+        if bounds.min.values[axis] <= point.values[axis] {
+            self.within_in(start, mid, depth + 1, bounds, accept, result);
+        }
+        if point.values[axis] <= bounds.max.values[axis] {
+            self.within_in(mid + 1, end, depth + 1, bounds, accept, result);
+        }
+    }
+}
+
+impl<const C: usize, T, V> FromIterator<(Vector<C, T>, V)> for KdTree<C, T, V>
+where
+    T: Copy + PartialOrd,
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    fn from_iter<I: IntoIterator<Item = (Vector<C, T>, V)>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector::V2i32;
+
+    fn grid() -> KdTree<2, i32, usize> {
+        (0..400)
+            .map(|i| {
+                (
+                    V2i32::from_xy((i * 37) % 101 - 50, (i * 53) % 97 - 48),
+                    i as usize,
+                )
+            })
+            .collect()
+    }
+
+    fn brute_force(tree: &KdTree<2, i32, usize>, query: V2i32) -> Vec<i32> {
+        let mut distances: Vec<i32> = tree
+            .iter()
+            .map(|(p, _)| {
+                let d = *p - query;
+                d.inner(d)
+            })
+            .collect();
+        distances.sort();
+        distances
+    }
+
+    #[test]
+    fn nearest_neighbours() {
+        let tree = grid();
+        assert_eq!(tree.len(), 400);
+        for query in [
+            V2i32::from_xy(0, 0),
+            V2i32::from_xy(-60, 17),
+            V2i32::from_xy(13, 49),
+        ] {
+            let expected = brute_force(&tree, query);
+            let nearest = tree.nearest(&query).unwrap().0;
+            assert_eq!((*nearest - query).inner(*nearest - query), expected[0]);
+            let k: Vec<i32> = tree
+                .k_nearest(&query, 10)
+                .into_iter()
+                .map(|(p, _)| (*p - query).inner(*p - query))
+                .collect();
+            assert_eq!(k, expected[..10]);
+        }
+        assert_eq!(tree.k_nearest(&V2i32::from_xy(0, 0), 1000).len(), 400);
+        assert!(
+            KdTree::<2, i32, ()>::new(vec![])
+                .nearest(&V2i32::from_xy(0, 0))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn range_queries() {
+        let tree = grid();
+        let aabb = Aabb::new(V2i32::from_xy(-10, -20), V2i32::from_xy(15, 5));
+        let mut found: Vec<usize> = tree
+            .within_aabb(&aabb)
+            .into_iter()
+            .map(|(_, &v)| v)
+            .collect();
+        found.sort();
+        let expected: Vec<usize> = tree
+            .iter()
+            .filter(|(p, _)| aabb.contains_point(p))
+            .map(|(_, &v)| v)
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        assert_eq!(found, expected);
+        assert!(!found.is_empty());
+
+        let center = V2i32::from_xy(3, -4);
+        let mut found: Vec<usize> = tree
+            .within_radius(&center, 12)
+            .into_iter()
+            .map(|(_, &v)| v)
+            .collect();
+        found.sort();
+        let mut expected: Vec<usize> = tree
+            .iter()
+            .filter(|(p, _)| (**p - center).inner(**p - center) <= 144)
+            .map(|(_, &v)| v)
+            .collect();
+        expected.sort();
+        assert_eq!(found, expected);
+
+        let floats: KdTree<3, f64, ()> = [[0.0, 0.0, 0.0], [1.0, 1.0, 1.0], [0.5, 0.2, 0.1]]
+            .into_iter()
+            .map(|p| (Vector::new(p), ()))
+            .collect();
+        assert_eq!(
+            floats.nearest(&Vector::new([0.9, 0.8, 1.2])).unwrap().0,
+            &Vector::new([1.0, 1.0, 1.0])
+        );
+        assert_eq!(
+            floats
+                .within_radius(&Vector::new([0.0, 0.0, 0.0]), 0.6)
+                .len(),
+            2
+        );
+    }
+}
@@ -3,24 +3,48 @@ pub mod prelude;
 pub mod aabb;
 pub mod arraynd;
 pub mod bijection;
+pub mod bitset;
 pub mod cli;
+pub mod combinatorics;
+pub mod convex_hull;
+pub mod direction;
 pub mod explore;
 pub mod expr;
 pub mod geometric_algebra;
 pub mod geometric_traits;
+pub mod graph;
 pub mod group;
+pub mod heap;
+pub mod hilbert;
 pub mod interval;
 pub mod interval_set;
+pub mod interval_tree;
+pub mod kd_tree;
 pub mod line;
 pub mod line_iterator;
+pub mod linear_algebra;
 pub mod linear_index;
 pub mod math;
+pub mod matrix;
+pub mod memo;
 pub mod modular;
+pub mod ntt;
+pub mod number_theory;
 pub mod ord_float;
 pub mod permutations;
+pub mod polygon;
+pub mod polyline;
+pub mod quaternion;
+pub mod random;
+pub mod range_query;
+pub mod recurrence;
+pub mod registration;
+pub mod rle;
 pub mod sketch;
+pub mod symmetry;
 pub mod transformations;
 pub mod vector;
+pub mod voting;
 
 pub fn add(left: usize, right: usize) -> usize {
     left + right
@@ -1,6 +1,11 @@
 use std::ops::{Add, Mul, Sub};
 
-use super::{line_iterator::LineIterator, vector::Vector};
+use super::{
+    line_iterator::{LineIterator, VoxelIterator},
+    math::{Gcd, Zero},
+    transformations::Transform,
+    vector::Vector,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Line<T> {
@@ -13,6 +18,12 @@ impl<T> Line<T> {
     }
 }
 
+impl<T: Copy> Line<T> {
+    pub fn transformed<X: Transform<T>>(&self, transform: &X) -> Self {
+        Self::new(transform.apply(self.start), transform.apply(self.end))
+    }
+}
+
 impl<T: Copy + Add<Output = T> + Sub<Output = T>> Line<T> {
     pub fn delta(&self) -> T {
         self.end - self.start
@@ -43,4 +54,447 @@ impl<const C: usize> LineVectori32<C> {
     }
 }
 
+impl<const C: usize> LineVector<C, f32> {
+    pub fn voxels(&self) -> VoxelIterator<C> {
+        VoxelIterator::new(self.start, self.end)
+    }
+}
+
+fn parallel<const C: usize, T>(a: Vector<C, T>, b: Vector<C, T>) -> bool
+where
+    T: Copy + PartialEq + Mul<Output = T>,
+{
+    (0..C).all(|i| (i + 1..C).all(|j| a.values[i] * b.values[j] == a.values[j] * b.values[i]))
+}
+
+// NOTE(lubo): These predicates only use `+ - *` and comparisons, so they are exact for integers
+// (as long as products of coordinate differences don't overflow).
+impl<const C: usize, T> LineVector<C, T>
+where
+    T: Copy + PartialOrd + Zero,
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    fn is_degenerate(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Both segments lie on a common line.
+    pub fn collinear_with(&self, other: &Self) -> bool {
+        if self.is_degenerate() {
+            if other.is_degenerate() {
+                true
+            } else {
+                parallel(self.start - other.start, other.delta())
+            }
+        } else {
+            let delta = self.delta();
+            parallel(other.start - self.start, delta) && parallel(other.end - self.start, delta)
+        }
+    }
+
+    /// `point` lies on the segment (end points included).
+    pub fn contains_point(&self, point: &Vector<C, T>) -> bool {
+        if self.is_degenerate() {
+            return *point == self.start;
+        }
+        let delta = self.delta();
+        let v = *point - self.start;
+        let dot = v.inner(delta);
+        parallel(v, delta) && dot >= T::zero() && dot <= delta.inner(delta)
+    }
+
+    /// Common part of two collinear segments, oriented like `self`.
+    ///
+    /// Returns `None` if the segments are not collinear or do not touch. Segments touching in a
+    /// single point overlap in a degenerate segment.
+    pub fn overlap(&self, other: &Self) -> Option<Self> {
+        if !self.collinear_with(other) {
+            return None;
+        }
+        if self.is_degenerate() {
+            return other.contains_point(&self.start).then_some(*self);
+        }
+
+        // NOTE(lubo): Parametrize all end points along `self`, `self.start` is 0 and `self.end` is |delta|^2.
+        let delta = self.delta();
+        let param = |p: Vector<C, T>| (p - self.start).inner(delta);
+        let candidates = [
+            (T::zero(), self.start),
+            (delta.inner(delta), self.end),
+            (param(other.start), other.start),
+            (param(other.end), other.end),
+        ];
+        let (other_lo, other_hi) = if candidates[2].0 <= candidates[3].0 {
+            (candidates[2], candidates[3])
+        } else {
+            (candidates[3], candidates[2])
+        };
+
+        let lo = if other_lo.0 > candidates[0].0 {
+            other_lo
+        } else {
+            candidates[0]
+        };
+        let hi = if other_hi.0 < candidates[1].0 {
+            other_hi
+        } else {
+            candidates[1]
+        };
+
+        if lo.0 <= hi.0 {
+            Some(Self::new(lo.1, hi.1))
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! float_line {
+    ($($t:ty),*) => {
+        $(
+        impl<const C: usize> LineVector<C, $t> {
+            /// `start + t * (end - start)`, `t` is not clamped.
+            pub fn point_at(&self, t: $t) -> Vector<C, $t> {
+                self.start + self.delta() * t
+            }
+
+            pub fn midpoint(&self) -> Vector<C, $t> {
+                self.point_at(0.5)
+            }
+
+            /// `n` evenly spaced points from `start` to `end` (both included when `n >= 2`).
+            pub fn sample_n(&self, n: usize) -> impl Iterator<Item = Vector<C, $t>> + '_ {
+                let denominator = n.saturating_sub(1).max(1) as $t;
+                (0..n).map(move |i| self.point_at(i as $t / denominator))
+            }
+
+            /// Parameter `t` of the orthogonal projection of `point` onto the infinite line,
+            /// `start` is at `t = 0` and `end` at `t = 1`. Degenerate lines project to `t = 0`.
+            pub fn project_point(&self, point: &Vector<C, $t>) -> $t {
+                let delta = self.delta();
+                let length_squared = delta.inner(delta);
+                if length_squared > 0.0 {
+                    (*point - self.start).inner(delta) / length_squared
+                } else {
+                    0.0
+                }
+            }
+
+            /// Point of the segment closest to `point`.
+            pub fn closest_point(&self, point: &Vector<C, $t>) -> Vector<C, $t> {
+                self.point_at(self.project_point(point).clamp(0.0, 1.0))
+            }
+
+            /// Squared distance from `point` to the segment.
+            pub fn distance_squared_to_point(&self, point: &Vector<C, $t>) -> $t {
+                let v = self.closest_point(point) - *point;
+                v.inner(v)
+            }
+
+            /// Distance from `point` to the segment.
+            pub fn distance_to_point(&self, point: &Vector<C, $t>) -> $t {
+                self.distance_squared_to_point(point).sqrt()
+            }
+        })*
+    };
+}
+
+float_line!(f32, f64);
+
+macro_rules! integer_line_distance {
+    ($($t:ty => $wide:ty),*) => {
+        $(
+        impl<const C: usize> LineVector<C, $t> {
+            fn wide_delta(a: Vector<C, $t>, b: Vector<C, $t>) -> [$wide; C] {
+                std::array::from_fn(|i| a.values[i] as $wide - b.values[i] as $wide)
+            }
+
+            fn checked_inner(a: &[$wide; C], b: &[$wide; C]) -> Option<$wide> {
+                (0..C).try_fold(0 as $wide, |acc, i| acc.checked_add(a[i].checked_mul(b[i])?))
+            }
+
+            /// Exact parameter `t = numerator / denominator` of the orthogonal projection of
+            /// `point` onto the infinite line. The denominator is positive.
+            /// Degenerate lines project to `t = 0`.
+            ///
+            /// `None` when an intermediate value does not fit the wider type, which needs
+            /// coordinates far apart near the limits of the component type.
+            ///
+            /// See [`crate::math::compare_ratios`] for comparing the results.
+            pub fn project_point_ratio(&self, point: &Vector<C, $t>) -> Option<($wide, $wide)> {
+                let delta = Self::wide_delta(self.end, self.start);
+                let length_squared = Self::checked_inner(&delta, &delta)?;
+                if length_squared > 0 {
+                    let v = Self::wide_delta(*point, self.start);
+                    Some((Self::checked_inner(&v, &delta)?, length_squared))
+                } else {
+                    Some((0, 1))
+                }
+            }
+
+            /// Exact squared distance `numerator / denominator` from `point` to the segment.
+            /// The denominator is positive.
+            ///
+            /// `None` on overflow, like [`Self::project_point_ratio`].
+            ///
+            /// See [`crate::math::compare_ratios`] for comparing the results.
+            pub fn distance_squared_to_point_ratio(
+                &self,
+                point: &Vector<C, $t>,
+            ) -> Option<($wide, $wide)> {
+                let v = Self::wide_delta(*point, self.start);
+                let (dot, length_squared) = self.project_point_ratio(point)?;
+                if dot <= 0 {
+                    Some((Self::checked_inner(&v, &v)?, 1))
+                } else if dot >= length_squared {
+                    let w = Self::wide_delta(*point, self.end);
+                    Some((Self::checked_inner(&w, &w)?, 1))
+                } else {
+                    // NOTE(lubo): |v|^2 - (v.d)^2 / |d|^2
+                    let numerator = Self::checked_inner(&v, &v)?
+                        .checked_mul(length_squared)?
+                        .checked_sub(dot.checked_mul(dot)?)?;
+                    Some((numerator, length_squared))
+                }
+            }
+        })*
+    };
+}
+
+integer_line_distance!(i32 => i128, i64 => i128);
+
+macro_rules! integer_line_lattice_points {
+    ($($t:ty),*) => {
+        $(
+        impl<const C: usize> LineVector<C, $t> {
+            /// Integer points lying exactly on the segment, from `start` to `end` (both included).
+            ///
+            /// Unlike [`LineVectori32::iter`], which rasterizes the segment into touching cells,
+            /// this only yields points the ideal segment passes through. There are
+            /// `gcd(|delta|) + 1` of them.
+            pub fn lattice_points(&self) -> impl Iterator<Item = Vector<C, $t>> {
+                let delta = self.delta();
+                let g = delta
+                    .values
+                    .iter()
+                    .fold(0, |g, d| <$t>::gcd(g, d.abs()));
+                let step = if g > 0 {
+                    delta.elementwise_unary(|d| d / g)
+                } else {
+                    delta
+                };
+                let start = self.start;
+                (0..=g).map(move |k| start + step * k)
+            }
+        })*
+    };
+}
+
+integer_line_lattice_points!(i32, i64);
+
 pub type LineV2i32 = LineVectori32<2>;
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::Line;
+    use crate::{
+        math::compare_ratios,
+        matrix::Matrix,
+        symmetry::GridSymmetry,
+        transformations::{Affine2, Translation},
+        vector::{V2, V3},
+    };
+
+    #[test]
+    fn transformed() {
+        let line = Line::new(V2::from_xy(1, 2), V2::from_xy(3, 2));
+        let moved = line.transformed(&Translation::new(V2::from_xy(-1, 1)));
+        assert_eq!(
+            (moved.start, moved.end),
+            (V2::from_xy(0, 3), V2::from_xy(2, 3))
+        );
+        let flipped = line.transformed(&GridSymmetry::FlipY);
+        assert_eq!(
+            (flipped.start, flipped.end),
+            (V2::from_xy(1, -2), V2::from_xy(3, -2))
+        );
+
+        let scaled = Line::new(V2::from_xy(1.0f64, 0.0), V2::from_xy(0.0, 1.0))
+            .transformed(&Affine2::from_linear(Matrix::new([[2.0, 0.0], [0.0, 3.0]])));
+        assert_eq!(
+            (scaled.start, scaled.end),
+            (V2::from_xy(2.0, 0.0), V2::from_xy(0.0, 3.0))
+        );
+    }
+
+    #[test]
+    fn float_distance() {
+        let line = Line::new(V2::from_xy(0.0f32, 0.0), V2::from_xy(4.0, 0.0));
+        assert_eq!(line.project_point(&V2::from_xy(1.0, 3.0)), 0.25);
+        assert_eq!(line.project_point(&V2::from_xy(-4.0, 3.0)), -1.0);
+        assert_eq!(line.distance_to_point(&V2::from_xy(1.0, 3.0)), 3.0);
+        assert_eq!(line.distance_to_point(&V2::from_xy(7.0, 4.0)), 5.0);
+        assert_eq!(line.distance_squared_to_point(&V2::from_xy(-1.0, 0.0)), 1.0);
+        assert_eq!(
+            line.closest_point(&V2::from_xy(2.0, -1.0)),
+            V2::from_xy(2.0, 0.0)
+        );
+
+        let point = Line::new(V2::from_xy(1.0f64, 1.0), V2::from_xy(1.0, 1.0));
+        assert_eq!(point.distance_to_point(&V2::from_xy(4.0, 5.0)), 5.0);
+    }
+
+    #[test]
+    fn interpolation() {
+        let line = Line::new(V2::from_xy(0.0f32, 2.0), V2::from_xy(4.0, 0.0));
+        assert_eq!(line.point_at(0.25), V2::from_xy(1.0, 1.5));
+        assert_eq!(line.point_at(-1.0), V2::from_xy(-4.0, 4.0));
+        assert_eq!(line.midpoint(), V2::from_xy(2.0, 1.0));
+        assert_eq!(
+            line.sample_n(3).collect::<Vec<_>>(),
+            vec![line.start, line.midpoint(), line.end]
+        );
+        assert_eq!(line.sample_n(5).count(), 5);
+        assert_eq!(line.sample_n(1).collect::<Vec<_>>(), vec![line.start]);
+        assert_eq!(line.sample_n(0).count(), 0);
+    }
+
+    #[test]
+    fn lattice_points() {
+        let line = Line::new(V2::from_xy(0i64, 0), V2::from_xy(6, -4));
+        assert_eq!(
+            line.lattice_points().collect::<Vec<_>>(),
+            vec![V2::from_xy(0, 0), V2::from_xy(3, -2), V2::from_xy(6, -4)]
+        );
+
+        let line = Line::new(V3::from_xyz(1i32, 1, 1), V3::from_xyz(1, 1, 5));
+        assert_eq!(line.lattice_points().count(), 5);
+
+        let line = Line::new(V2::from_xy(2i32, 3), V2::from_xy(5, 7));
+        assert_eq!(line.lattice_points().count(), 2);
+
+        let point = Line::new(V2::from_xy(2i32, 3), V2::from_xy(2, 3));
+        assert_eq!(
+            point.lattice_points().collect::<Vec<_>>(),
+            vec![V2::from_xy(2, 3)]
+        );
+    }
+
+    #[test]
+    fn collinear_and_contains() {
+        let a = Line::new(V2::from_xy(0, 0), V2::from_xy(4, 2));
+        assert!(a.collinear_with(&Line::new(V2::from_xy(-2, -1), V2::from_xy(8, 4))));
+        assert!(a.collinear_with(&Line::new(V2::from_xy(6, 3), V2::from_xy(6, 3))));
+        assert!(!a.collinear_with(&Line::new(V2::from_xy(0, 0), V2::from_xy(4, 3))));
+        assert!(!a.collinear_with(&Line::new(V2::from_xy(0, 1), V2::from_xy(4, 3))));
+
+        assert!(a.contains_point(&V2::from_xy(2, 1)));
+        assert!(a.contains_point(&V2::from_xy(4, 2)));
+        assert!(!a.contains_point(&V2::from_xy(6, 3)));
+        assert!(!a.contains_point(&V2::from_xy(1, 1)));
+
+        let b = Line::new(V3::from_xyz(0, 0, 0), V3::from_xyz(3, 6, 9));
+        assert!(b.contains_point(&V3::from_xyz(1, 2, 3)));
+        assert!(!b.contains_point(&V3::from_xyz(1, 2, 4)));
+    }
+
+    #[test]
+    fn overlap() {
+        let a = Line::new(V2::from_xy(0, 0), V2::from_xy(4, 2));
+        let b = Line::new(V2::from_xy(6, 3), V2::from_xy(2, 1));
+        let overlap = a.overlap(&b).unwrap();
+        assert_eq!(
+            (overlap.start, overlap.end),
+            (V2::from_xy(2, 1), V2::from_xy(4, 2))
+        );
+
+        let inside = Line::new(V2::from_xy(2, 1), V2::from_xy(2, 1));
+        let overlap = a.overlap(&inside).unwrap();
+        assert_eq!(
+            (overlap.start, overlap.end),
+            (V2::from_xy(2, 1), V2::from_xy(2, 1))
+        );
+
+        let touching = Line::new(V2::from_xy(4, 2), V2::from_xy(8, 4));
+        let overlap = a.overlap(&touching).unwrap();
+        assert_eq!(
+            (overlap.start, overlap.end),
+            (V2::from_xy(4, 2), V2::from_xy(4, 2))
+        );
+
+        let disjoint = Line::new(V2::from_xy(6, 3), V2::from_xy(8, 4));
+        assert!(a.overlap(&disjoint).is_none());
+
+        let parallel = Line::new(V2::from_xy(0, 1), V2::from_xy(4, 3));
+        assert!(a.overlap(&parallel).is_none());
+
+        let covering = Line::new(V2::from_xy(8, 4), V2::from_xy(-2, -1));
+        let overlap = a.overlap(&covering).unwrap();
+        assert_eq!((overlap.start, overlap.end), (a.start, a.end));
+    }
+
+    #[test]
+    fn integer_distance() {
+        let line = Line::new(V2::from_xy(0i32, 0), V2::from_xy(2, 2));
+        assert_eq!(line.project_point_ratio(&V2::from_xy(2, 0)), Some((4, 8)));
+        assert_eq!(
+            line.distance_squared_to_point_ratio(&V2::from_xy(2, 0)),
+            Some((16, 8))
+        );
+        assert_eq!(
+            line.distance_squared_to_point_ratio(&V2::from_xy(-1, 0)),
+            Some((1, 1))
+        );
+        assert_eq!(
+            line.distance_squared_to_point_ratio(&V2::from_xy(3, 4)),
+            Some((5, 1))
+        );
+        assert_eq!(
+            line.distance_squared_to_point_ratio(&V2::from_xy(1, 1)),
+            Some((0, 8))
+        );
+
+        let a = line
+            .distance_squared_to_point_ratio(&V2::from_xy(2, 0))
+            .unwrap();
+        let b = line
+            .distance_squared_to_point_ratio(&V2::from_xy(0, 1))
+            .unwrap();
+        assert_eq!(compare_ratios(a, b), Ordering::Greater);
+        assert_eq!(compare_ratios(a, (2, 1)), Ordering::Equal);
+    }
+
+    #[test]
+    fn integer_distance_large() {
+        let line = Line::new(
+            V2::from_xy(-500_000_000i32, -500_000_000),
+            V2::from_xy(500_000_000, 500_000_000),
+        );
+        let near = line.distance_squared_to_point_ratio(&V2::from_xy(1, 0));
+        let far = line.distance_squared_to_point_ratio(&V2::from_xy(2, 0));
+        assert_eq!(compare_ratios(near.unwrap(), (1, 2)), Ordering::Equal);
+        assert_eq!(compare_ratios(near.unwrap(), far.unwrap()), Ordering::Less);
+        let line = Line::new(
+            V2::from_xy(i32::MIN, i32::MIN),
+            V2::from_xy(i32::MAX, i32::MAX),
+        );
+        assert_eq!(
+            line.distance_squared_to_point_ratio(&V2::from_xy(1, 0)),
+            None
+        );
+
+        let line = Line::new(
+            V2::from_xy(-1_000_000_000i64, 0),
+            V2::from_xy(1_000_000_000, 3),
+        );
+        let d = line.distance_squared_to_point_ratio(&V2::from_xy(0, 1_000_000_000));
+        assert!(d.is_some());
+        let line = Line::new(V2::from_xy(i64::MIN, 0), V2::from_xy(i64::MAX, 1));
+        assert_eq!(
+            line.distance_squared_to_point_ratio(&V2::from_xy(0, i64::MAX)),
+            None
+        );
+    }
+}
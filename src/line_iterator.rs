@@ -1,77 +1,165 @@
 use super::vector::Vector;
 
+/// Cells of an integer line from `start` to `end` (N-dimensional Bresenham).
+///
+/// The axis with the largest delta (the driving axis) advances by exactly one every step,
+/// the other axes follow the ideal line rounded to the nearest cell. Consecutive cells are
+/// therefore neighbours including diagonals (8-connected in 2D, 26-connected in 3D).
+///
+/// `B` selects whether `end` itself is included, `start` always is (unless `B` is false and
+/// `start == end`).
 pub struct LineIterator<const B: bool, const C: usize> {
-    step_options: Vec<Vector<C, i32>>,
-    at: Vector<C, i32>,
-    end: Vector<C, i32>,
+    start: Vector<C, i32>,
+    delta: Vector<C, i64>,
+    steps: i64,
+    front: i64,
+    back: i64,
 }
 
 impl<const B: bool, const C: usize> LineIterator<B, C> {
     pub fn new(start: Vector<C, i32>, end: Vector<C, i32>) -> Self {
+        // NOTE(lubo): Endpoints can be more than `i32::MAX` apart.
+        let delta = Vector::new(std::array::from_fn(|i| {
+            end.values[i] as i64 - start.values[i] as i64
+        }));
+        let steps = delta.values.iter().map(|d| d.abs()).max().unwrap_or(0);
         LineIterator {
-            at: start,
-            end,
-            step_options: vec![],
+            start,
+            delta,
+            steps,
+            front: 0,
+            back: if B { steps + 1 } else { steps },
         }
     }
+
+    // NOTE(lubo): Closed form of Bresenham, offset = round(k * delta / steps) with ties rounded
+    // away from `start`. The product needs `i128`, the resulting cell lies between the endpoints.
+    fn point(&self, k: i64) -> Vector<C, i32> {
+        let mut result = self.start;
+        if self.steps > 0 {
+            for i in 0..C {
+                let d = self.delta.values[i];
+                let steps = self.steps as i128;
+                let offset = (2 * k as i128 * d.abs() as i128 + steps) / (2 * steps);
+                result.values[i] = (result.values[i] as i64 + d.signum() * offset as i64) as i32;
+            }
+        }
+        result
+    }
 }
+
 impl<const B: bool, const C: usize> Iterator for LineIterator<B, C> {
     type Item = Vector<C, i32>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.step_options.is_empty() {
-            if self.at == self.end {
-                self.step_options.push(Vector::all(0));
-                if B {
-                    return Some(self.at);
+        if self.front < self.back {
+            let result = self.point(self.front);
+            self.front += 1;
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front).max(0) as usize;
+        (len, Some(len))
+    }
+}
+
+// NOTE(lubo): Walking from the back yields exactly the same cells in reverse order, which is
+// not necessarily the same as the line from `end` to `start` (ties round differently).
+impl<const B: bool, const C: usize> DoubleEndedIterator for LineIterator<B, C> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.point(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl<const B: bool, const C: usize> ExactSizeIterator for LineIterator<B, C> {}
+
+/// Cells visited by a float line segment from `start` to `end` (Amanatides-Woo voxel traversal).
+///
+/// Cell `p` covers `[p, p + 1)` on every axis. Every cell the segment passes through is
+/// visited, in order, moving along a single axis every step (4-connected in 2D, 6-connected
+/// in 3D). Both the cell containing `start` and the cell containing `end` are included.
+pub struct VoxelIterator<const C: usize> {
+    at: Vector<C, i32>,
+    step: Vector<C, i32>,
+    t_max: Vector<C, f32>,
+    t_delta: Vector<C, f32>,
+    remaining: Vector<C, i32>,
+    done: bool,
+}
+
+impl<const C: usize> VoxelIterator<C> {
+    pub fn new(start: Vector<C, f32>, end: Vector<C, f32>) -> Self {
+        let at = start.elementwise_unary(|x| x.floor());
+        let last = end.elementwise_unary(|x| x.floor());
+        let mut result = Self {
+            at: Vector::all(0),
+            step: Vector::all(0),
+            t_max: Vector::all(f32::INFINITY),
+            t_delta: Vector::all(f32::INFINITY),
+            remaining: Vector::all(0),
+            done: false,
+        };
+
+        for i in 0..C {
+            let d = end.values[i] - start.values[i];
+            result.at.values[i] = at.values[i] as i32;
+            result.remaining.values[i] = (last.values[i] as i32 - at.values[i] as i32).abs();
+            if result.remaining.values[i] > 0 {
+                result.step.values[i] = if d > 0.0 { 1 } else { -1 };
+                result.t_delta.values[i] = 1.0 / d.abs();
+                let boundary = if d > 0.0 {
+                    at.values[i] + 1.0
                 } else {
-                    return None;
-                }
+                    at.values[i]
+                };
+                result.t_max.values[i] = (boundary - start.values[i]) / d;
             }
+        }
 
-            let mut dir = self.end - self.at;
-            for i in 0..C {
-                if dir.values[i] > 0 {
-                    dir.values[i] = 1;
-                }
-                if dir.values[i] < 0 {
-                    dir.values[i] = -1;
-                }
-            }
+        result
+    }
+}
 
-            for i in 0..C {
-                if dir.values[i] != 0 {
-                    #[allow(clippy::unnecessary_to_owned)]
-                    for mut opt in self.step_options.to_vec() {
-                        debug_assert_eq!(opt.values[i], 0);
-                        opt.values[i] = dir.values[i];
-                        self.step_options.push(opt);
-                    }
-
-                    let mut values = [0; C];
-                    values[i] = dir.values[i];
-                    self.step_options.push(Vector::new(values));
-                }
-            }
+impl<const C: usize> Iterator for VoxelIterator<C> {
+    type Item = Vector<C, i32>;
 
-            Some(self.at)
-        } else if self.at != self.end {
-            let delta = self.end - self.at;
-            let best_step = *self
-                .step_options
-                .iter()
-                .max_by_key(|step| delta.inner(**step))
-                .unwrap();
-            self.at += best_step;
-
-            if !B && self.at == self.end {
-                return None;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.at;
+
+        // NOTE(lubo): Only axes with remaining steps are considered, so the traversal always
+        // ends exactly in the cell of `end`, regardless of floating point error.
+        let mut best: Option<usize> = None;
+        for i in 0..C {
+            if self.remaining.values[i] > 0
+                && best.is_none_or(|b| self.t_max.values[i] < self.t_max.values[b])
+            {
+                best = Some(i);
             }
+        }
 
-            Some(self.at)
-        } else {
-            None
+        match best {
+            Some(i) => {
+                self.at.values[i] += self.step.values[i];
+                self.t_max.values[i] += self.t_delta.values[i];
+                self.remaining.values[i] -= 1;
+            }
+            None => self.done = true,
         }
+
+        Some(result)
     }
 }
 
@@ -138,3 +226,171 @@ impl<const B: bool, const C: usize> Iterator for LineIterator<B, C> {
 //         }
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::{LineIterator, VoxelIterator};
+    use crate::vector::{V2, V3, Vector};
+
+    fn assert_connected<const C: usize>(cells: &[Vector<C, i32>], max_axes: usize) {
+        for w in cells.windows(2) {
+            let d = w[1] - w[0];
+            assert!(d.values.iter().all(|x| x.abs() <= 1), "{:?}", w);
+            let moved = d.values.iter().filter(|x| **x != 0).count();
+            assert!(moved >= 1 && moved <= max_axes, "{:?}", w);
+        }
+    }
+
+    #[test]
+    fn line_axis_aligned_and_diagonal() {
+        let cells: Vec<_> =
+            LineIterator::<true, 2>::new(V2::from_xy(0, 0), V2::from_xy(3, 0)).collect();
+        assert_eq!(
+            cells,
+            vec![
+                V2::from_xy(0, 0),
+                V2::from_xy(1, 0),
+                V2::from_xy(2, 0),
+                V2::from_xy(3, 0)
+            ]
+        );
+        let cells: Vec<_> =
+            LineIterator::<false, 2>::new(V2::from_xy(0, 0), V2::from_xy(-2, 2)).collect();
+        assert_eq!(cells, vec![V2::from_xy(0, 0), V2::from_xy(-1, 1)]);
+    }
+
+    #[test]
+    fn line_endpoints() {
+        let p = V2::from_xy(3, 4);
+        assert_eq!(
+            LineIterator::<true, 2>::new(p, p).collect::<Vec<_>>(),
+            vec![p]
+        );
+        assert_eq!(LineIterator::<false, 2>::new(p, p).count(), 0);
+
+        let (a, b) = (
+            V2::from_xy(i32::MIN, i32::MAX),
+            V2::from_xy(i32::MAX, i32::MIN),
+        );
+        let mut line = LineIterator::<true, 2>::new(a, b);
+        assert_eq!(line.len(), 1 << 32);
+        assert_eq!(line.next(), Some(a));
+        assert_eq!(line.next(), Some(V2::from_xy(i32::MIN + 1, i32::MAX - 1)));
+        assert_eq!(line.next_back(), Some(b));
+        assert_eq!(
+            line.next_back(),
+            Some(V2::from_xy(i32::MAX - 1, i32::MIN + 1))
+        );
+        let mut shallow =
+            LineIterator::<false, 2>::new(V2::from_xy(i32::MIN, 0), V2::from_xy(i32::MAX, 1));
+        assert_eq!(shallow.next_back(), Some(V2::from_xy(i32::MAX - 1, 1)));
+    }
+
+    #[test]
+    fn line_2d_shallow() {
+        let cells: Vec<_> =
+            LineIterator::<true, 2>::new(V2::from_xy(0, 0), V2::from_xy(6, 2)).collect();
+        assert_eq!(
+            cells,
+            vec![
+                V2::from_xy(0, 0),
+                V2::from_xy(1, 0),
+                V2::from_xy(2, 1),
+                V2::from_xy(3, 1),
+                V2::from_xy(4, 1),
+                V2::from_xy(5, 2),
+                V2::from_xy(6, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn line_3d() {
+        let start = V3::from_xyz(1, -2, 3);
+        let end = V3::from_xyz(-6, 5, 0);
+        let cells: Vec<_> = LineIterator::<true, 3>::new(start, end).collect();
+        assert_eq!(cells.len(), 8);
+        assert_eq!(cells[0], start);
+        assert_eq!(*cells.last().unwrap(), end);
+        assert_connected(&cells, 3);
+
+        // NOTE(lubo): Every cell lies close to the ideal line.
+        let delta = end - start;
+        for (k, c) in cells.iter().enumerate() {
+            let t = k as f32 / 7.0;
+            for i in 0..3 {
+                let ideal = start.values[i] as f32 + t * delta.values[i] as f32;
+                assert!((c.values[i] as f32 - ideal).abs() <= 0.5);
+            }
+        }
+    }
+
+    #[test]
+    fn line_len_and_rev() {
+        let start = V3::from_xyz(0, 0, 0);
+        let end = V3::from_xyz(5, -3, 2);
+        let mut it = LineIterator::<true, 3>::new(start, end);
+        assert_eq!(it.len(), 6);
+        assert_eq!(LineIterator::<false, 3>::new(start, end).len(), 5);
+
+        let forward: Vec<_> = LineIterator::<true, 3>::new(start, end).collect();
+        let mut backward: Vec<_> = LineIterator::<true, 3>::new(start, end).rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        assert_eq!(it.next(), Some(start));
+        assert_eq!(it.next_back(), Some(end));
+        assert_eq!(it.len(), 4);
+        let middle: Vec<_> = it.by_ref().collect();
+        assert_eq!(middle, forward[1..5]);
+        assert_eq!(it.len(), 0);
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn voxels_2d() {
+        let cells: Vec<_> =
+            VoxelIterator::new(V2::from_xy(0.5, 0.5), V2::from_xy(2.5, 1.2)).collect();
+        assert_eq!(
+            cells,
+            vec![
+                V2::from_xy(0, 0),
+                V2::from_xy(1, 0),
+                V2::from_xy(1, 1),
+                V2::from_xy(2, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn voxels_negative_and_axis_parallel() {
+        let cells: Vec<_> =
+            VoxelIterator::new(V2::from_xy(0.5, -0.5), V2::from_xy(-2.5, -0.5)).collect();
+        assert_eq!(
+            cells,
+            vec![
+                V2::from_xy(0, -1),
+                V2::from_xy(-1, -1),
+                V2::from_xy(-2, -1),
+                V2::from_xy(-3, -1)
+            ]
+        );
+
+        let p = V2::from_xy(0.25, 0.75);
+        assert_eq!(
+            VoxelIterator::new(p, p).collect::<Vec<_>>(),
+            vec![V2::from_xy(0, 0)]
+        );
+    }
+
+    #[test]
+    fn voxels_3d() {
+        let start = V3::from_xyz(0.1, 0.2, 0.3);
+        let end = V3::from_xyz(4.7, -2.2, 3.9);
+        let cells: Vec<_> = VoxelIterator::new(start, end).collect();
+        assert_eq!(cells.len(), 1 + 4 + 3 + 3);
+        assert_eq!(cells[0], V3::from_xyz(0, 0, 0));
+        assert_eq!(*cells.last().unwrap(), V3::from_xyz(4, -3, 3));
+        assert_connected(&cells, 1);
+    }
+}
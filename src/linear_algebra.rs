@@ -0,0 +1,244 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use super::{
+    math::{One, Zero},
+    matrix::Matrix,
+    modular::{Mod, ModDyn},
+    vector::Vector,
+};
+
+/// Scalars Gaussian elimination can divide by.
+///
+/// Exact types (e.g. [`Mod`] with a prime modulus) accept any invertible pivot, floats pick the
+/// largest one for stability and treat values that are tiny compared to the rest of their column
+/// as zero.
+pub trait Field:
+    Copy
+    + PartialEq
+    + Zero
+    + One
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// Pivots at most this times the largest weight in their column are unusable.
+    const TOLERANCE: f64 = 0.0;
+
+    /// How good a pivot the value makes, larger is better and `0.0` means unusable.
+    fn pivot_weight(&self) -> f64;
+}
+
+macro_rules! float_field {
+    ($($t:ty: $tolerance:expr),*) => {
+        $(
+        impl Field for $t {
+            const TOLERANCE: f64 = $tolerance;
+
+            fn pivot_weight(&self) -> f64 {
+                self.abs() as f64
+            }
+        }
+        )*
+    };
+}
+
+float_field!(f32: 1e-5, f64: 1e-10);
+
+impl<const N: u64> Field for Mod<N> {
+    fn pivot_weight(&self) -> f64 {
+        if self.recip().is_some() { 1.0 } else { 0.0 }
+    }
+}
+
+impl Field for ModDyn {
+    fn pivot_weight(&self) -> f64 {
+        if self.recip().is_some() { 1.0 } else { 0.0 }
+    }
+}
+
+/// Reduced row echelon form of `rows` (Gauss–Jordan), each with the same number of columns.
+///
+/// Returns the pivot columns and the determinant of the leading square block when `rows` has
+/// full rank in it, zero otherwise.
+fn reduce<T: Field>(rows: &mut [Vec<T>]) -> (Vec<usize>, T) {
+    let columns = rows.first().map_or(0, |row| row.len());
+    let scales: Vec<f64> = (0..columns)
+        .map(|c| {
+            rows.iter()
+                .map(|row| row[c].pivot_weight())
+                .fold(0.0, f64::max)
+        })
+        .collect();
+    let mut pivots = vec![];
+    let mut determinant = T::one();
+    let mut negate = false;
+    let mut zero = None;
+    for c in 0..columns {
+        let rank = pivots.len();
+        if rank == rows.len() {
+            break;
+        }
+        let (best, weight) = (rank..rows.len())
+            .map(|r| (r, rows[r][c].pivot_weight()))
+            .fold((rank, 0.0), |a, b| if b.1 > a.1 { b } else { a });
+        if weight <= T::TOLERANCE * scales[c] {
+            // NOTE(lubo): Unlike `T::zero()` this keeps the modulus of `ModDyn`.
+            zero.get_or_insert(rows[rank][c] * T::zero());
+            continue;
+        }
+        if best != rank {
+            rows.swap(best, rank);
+            negate = !negate;
+        }
+
+        let pivot = rows[rank][c];
+        determinant = determinant * pivot;
+        for x in rows[rank].iter_mut() {
+            *x = *x / pivot;
+        }
+        let pivot_row = rows[rank].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            let factor = row[c];
+            if r != rank && factor != T::zero() {
+                for (x, &p) in row.iter_mut().zip(&pivot_row) {
+                    *x = *x - factor * p;
+                }
+            }
+        }
+        pivots.push(c);
+    }
+    let determinant = match zero {
+        Some(zero) => zero,
+        None if negate => T::zero() - determinant,
+        None => determinant,
+    };
+    (pivots, determinant)
+}
+
+fn to_rows<const R: usize, const C: usize, T: Copy>(a: &Matrix<R, C, T>) -> Vec<Vec<T>> {
+    a.values.iter().map(|row| row.to_vec()).collect()
+}
+
+/// Unique solution `x` of `a * x == b`, `None` if `a` is singular.
+pub fn solve<const N: usize, T: Field>(
+    a: &Matrix<N, N, T>,
+    b: &Vector<N, T>,
+) -> Option<Vector<N, T>> {
+    let mut rows = to_rows(a);
+    for (row, &y) in rows.iter_mut().zip(&b.values) {
+        row.push(y);
+    }
+    let (pivots, _) = reduce(&mut rows);
+    (pivots.len() == N && pivots.iter().all(|&c| c < N))
+        .then(|| Vector::new(std::array::from_fn(|r| rows[r][N])))
+}
+
+/// Determinant of any square matrix, see [`Matrix::determinant`] for the closed forms.
+pub fn determinant<const N: usize, T: Field>(a: &Matrix<N, N, T>) -> T {
+    reduce(&mut to_rows(a)).1
+}
+
+pub fn rank<const R: usize, const C: usize, T: Field>(a: &Matrix<R, C, T>) -> usize {
+    reduce(&mut to_rows(a)).0.len()
+}
+
+/// `None` for singular matrices.
+pub fn inverse<const N: usize, T: Field>(a: &Matrix<N, N, T>) -> Option<Matrix<N, N, T>> {
+    let mut rows = to_rows(a);
+    for (r, row) in rows.iter_mut().enumerate() {
+        row.extend((0..N).map(|c| if r == c { T::one() } else { T::zero() }));
+    }
+    let (pivots, _) = reduce(&mut rows);
+    (pivots.len() == N && pivots.iter().all(|&c| c < N)).then(|| {
+        Matrix::new(std::array::from_fn(|r| {
+            std::array::from_fn(|c| rows[r][N + c])
+        }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn float_system() {
+        let a = Matrix::new([[2.0f64, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]]);
+        let b = Vector::new([8.0, -11.0, -3.0]);
+        let x = solve(&a, &b).unwrap();
+        for (x, e) in x.values.iter().zip([2.0, 3.0, -1.0]) {
+            assert!((x - e).abs() < 1e-12);
+        }
+        assert!((determinant(&a) - a.determinant()).abs() < 1e-12);
+        assert_eq!(rank(&a), 3);
+
+        let singular = Matrix::new([[1.0f64, 2.0], [2.0, 4.0]]);
+        assert_eq!(solve(&singular, &Vector::new([1.0, 2.0])), None);
+        assert_eq!(determinant(&singular), 0.0);
+        assert_eq!(rank(&singular), 1);
+        assert_eq!(inverse(&singular), None);
+        assert_eq!(rank(&Matrix::new([[0.0f32; 3]; 2])), 0);
+        assert_eq!(rank(&Matrix::new([[1.0f32, 2.0, 3.0], [2.0, 4.0, 7.0]])), 2);
+    }
+
+    #[test]
+    fn modular_system() {
+        type M = Mod<1_000_000_007>;
+        let a = Matrix::new(
+            [[1, 2, 3, 4], [0, 1, 4, 9], [5, 6, 0, 1], [1, 1, 1, 1]].map(|row| row.map(M::new)),
+        );
+        let expected = Matrix::new(
+            [[3, 1, 4, 1], [5, 9, 2, 6], [5, 3, 5, 8], [9, 7, 9, 3]].map(|row| row.map(M::new)),
+        );
+        let b = Vector::new([7, 11, 13, 17].map(M::new));
+        let x = solve(&a, &b).unwrap();
+        assert_eq!(a * x, b);
+        let inv = inverse(&a).unwrap();
+        assert_eq!(inv * a, Matrix::identity());
+        assert_eq!(
+            determinant(&(a * expected)),
+            determinant(&a) * determinant(&expected)
+        );
+        assert_eq!(
+            determinant(&Matrix::new([
+                [M::new(2), M::new(3)],
+                [M::new(1), M::new(4)]
+            ])),
+            M::new(5)
+        );
+        assert_eq!(
+            determinant(&Matrix::new([
+                [M::new(0), M::new(1)],
+                [M::new(1), M::new(0)]
+            ])),
+            M::from_i64(-1)
+        );
+
+        let b = Matrix::new([[1, 2], [3, 4]].map(|row| row.map(|v| ModDyn::new(v, 7))));
+        assert_eq!(rank(&b), 2);
+        assert_eq!(determinant(&b), ModDyn::new(5, 7));
+        let singular = Matrix::new([[1, 2], [4, 1]].map(|row| row.map(|v| ModDyn::new(v, 7))));
+        assert_eq!(rank(&singular), 1);
+        assert_eq!(determinant(&singular), ModDyn::new(0, 7));
+
+        let swap = Matrix::new([[0, 1], [1, 0]].map(|row| row.map(|v| ModDyn::new(v, 7))));
+        assert_eq!(determinant(&swap), ModDyn::new(6, 7));
+        assert_eq!(rank(&swap), 2);
+        let y = Vector::new([3, 5].map(|v| ModDyn::new(v, 7)));
+        assert_eq!(
+            solve(&swap, &y),
+            Some(Vector::new([5, 3].map(|v| ModDyn::new(v, 7))))
+        );
+        assert_eq!(inverse(&swap), Some(swap));
+    }
+
+    #[test]
+    fn tiny_but_regular() {
+        let a = Matrix::new([[1e-11f64, 0.0], [0.0, 1e-11]]);
+        let x = solve(&a, &Vector::new([2e-11, 3e-11])).unwrap();
+        assert!((x.values[0] - 2.0).abs() < 1e-9 && (x.values[1] - 3.0).abs() < 1e-9);
+        assert!((determinant(&a) - 1e-22).abs() < 1e-30);
+        assert_eq!(rank(&a), 2);
+        assert_eq!(rank(&Matrix::new([[1e-11f64, 2e-11], [2e-11, 4e-11]])), 1);
+    }
+}
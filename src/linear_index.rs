@@ -1,13 +1,12 @@
+use crate::{aabb::Aabb, math::CheckedOps, vector::Vector};
+
 pub trait LinearIndex<I> {
     fn index_unchecked(&self, i: I) -> Option<usize>;
     fn unindex(&self, i: usize) -> Option<I>;
     fn is_in_bounds(&self, i: &I) -> bool;
 
-    /// This is unsafe and should not be called as overflows are not checked. Uses [Iterator::product]
-    /// # Safety
-    /// Don't use this
-    #[deprecated]
-    unsafe fn cardinality(&self) -> Option<usize>;
+    /// Number of valid positions, `None` if it does not fit in a `usize`.
+    fn checked_cardinality(&self) -> Option<usize>;
 
     fn index(&self, i: I) -> Option<usize> {
         if self.is_in_bounds(&i) {
@@ -17,3 +16,275 @@ pub trait LinearIndex<I> {
         }
     }
 }
+
+/// Affine map `offset + sum(i[k] * strides[k])` from positions below `extent` to linear indices.
+/// Describes rows, columns, sub-boxes and step-sliced parts of a contiguous array without
+/// copying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StridedIndex<const N: usize> {
+    pub offset: usize,
+    pub strides: [usize; N],
+    pub extent: [usize; N],
+}
+
+impl<const N: usize> StridedIndex<N> {
+    pub fn new(offset: usize, strides: [usize; N], extent: [usize; N]) -> Self {
+        Self {
+            offset,
+            strides,
+            extent,
+        }
+    }
+
+    /// Layout of a whole array, the first axis varying fastest.
+    pub fn contiguous(extent: [usize; N]) -> Self {
+        let mut strides = [0; N];
+        let mut stride = 1;
+        for (s, e) in strides.iter_mut().zip(extent) {
+            *s = stride;
+            stride *= e;
+        }
+        Self::new(0, strides, extent)
+    }
+
+    pub fn len(&self) -> usize {
+        self.extent.iter().product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Part starting at `start` with `extent` positions taking every `step`-th along each axis.
+    /// `None` unless it lies within `self`.
+    pub fn slice(&self, start: [usize; N], extent: [usize; N], step: [usize; N]) -> Option<Self> {
+        let mut result = Self::new(self.index_unchecked(start)?, self.strides, extent);
+        for k in 0..N {
+            if step[k] == 0 {
+                return None;
+            }
+            if extent[k] > 0 {
+                let last = start[k].checked_add((extent[k] - 1).checked_mul(step[k])?)?;
+                if last >= self.extent[k] {
+                    return None;
+                }
+            }
+            result.strides[k] = self.strides[k].checked_mul(step[k])?;
+        }
+        Some(result)
+    }
+
+    /// Position `n` in iteration order, the first axis varying fastest.
+    pub fn nth_position(&self, mut n: usize) -> Option<[usize; N]> {
+        if n >= self.len() {
+            return None;
+        }
+        let mut position = [0; N];
+        for (p, e) in position.iter_mut().zip(self.extent) {
+            *p = n % e;
+            n /= e;
+        }
+        Some(position)
+    }
+
+    /// Whether each stride is past the last index reachable with the smaller ones, so that
+    /// positions can be recovered greedily from the largest stride down.
+    fn is_nested(&self) -> bool {
+        let mut axes: Vec<_> = (0..N).filter(|&k| self.extent[k] > 1).collect();
+        axes.sort_by_key(|&k| self.strides[k]);
+        let mut reach = 0usize;
+        for k in axes {
+            if self.strides[k] <= reach {
+                return false;
+            }
+            match (self.extent[k] - 1)
+                .checked_mul(self.strides[k])
+                .and_then(|r| r.checked_add(reach))
+            {
+                Some(r) => reach = r,
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// Fills `position` along `axes` (largest stride first) so that it adds up to `rest`.
+    fn find_position(
+        &self,
+        axes: &[usize],
+        rest: usize,
+        greedy: bool,
+        position: &mut [usize; N],
+    ) -> bool {
+        let Some((&k, axes)) = axes.split_first() else {
+            return rest == 0;
+        };
+        let Some(last) = self.extent[k].checked_sub(1) else {
+            return false;
+        };
+        let top = rest.checked_div(self.strides[k]).map_or(0, |q| q.min(last));
+        // NOTE(lubo): Greedy is exact for nested strides, otherwise backtrack over smaller values.
+        let bottom = if greedy { top } else { 0 };
+        for q in (bottom..=top).rev() {
+            position[k] = q;
+            if self.find_position(axes, rest - q * self.strides[k], greedy, position) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Linear indices of all positions in iteration order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len()).map(|n| self.index_unchecked(self.nth_position(n).unwrap()).unwrap())
+    }
+}
+
+impl<const N: usize> LinearIndex<[usize; N]> for StridedIndex<N> {
+    fn index_unchecked(&self, i: [usize; N]) -> Option<usize> {
+        i.iter()
+            .zip(self.strides)
+            .try_fold(self.offset, |acc, (&a, s)| {
+                acc.checked_add(a.checked_mul(s)?)
+            })
+    }
+
+    fn unindex(&self, i: usize) -> Option<[usize; N]> {
+        let rest = i.checked_sub(self.offset)?;
+        let mut axes: Vec<_> = (0..N).collect();
+        axes.sort_by_key(|&k| std::cmp::Reverse(self.strides[k]));
+        let mut position = [0; N];
+        self.find_position(&axes, rest, self.is_nested(), &mut position)
+            .then_some(position)
+    }
+
+    fn is_in_bounds(&self, i: &[usize; N]) -> bool {
+        i.iter().zip(self.extent).all(|(&a, e)| a < e)
+    }
+
+    fn checked_cardinality(&self) -> Option<usize> {
+        self.extent
+            .iter()
+            .try_fold(1usize, |acc, &e| acc.checked_mul(e))
+    }
+}
+
+/// Box of `dims` cells whose first cell sits at `origin`, for arrays addressed in world
+/// coordinates that may be negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OffsetIndex<const N: usize> {
+    pub origin: Vector<N, i32>,
+    pub dims: [usize; N],
+}
+
+impl<const N: usize> OffsetIndex<N> {
+    pub fn new(origin: Vector<N, i32>, dims: [usize; N]) -> Self {
+        Self { origin, dims }
+    }
+
+    /// All cells of `aabb`, both `min` and `max` included.
+    pub fn covering(aabb: &Aabb<N, i32>) -> Option<Self> {
+        let dims: Vector<N, usize> = aabb
+            .max
+            .checked_sub(aabb.min)?
+            .checked_add(Vector::all(1))?
+            .try_into()
+            .ok()?;
+        Some(Self::new(aabb.min, dims.values))
+    }
+
+    /// Closed box of all valid positions, `None` if there are none.
+    pub fn bounds(&self) -> Option<Aabb<N, i32>> {
+        let mut max = self.origin;
+        for (m, d) in max.values.iter_mut().zip(self.dims) {
+            *m = m.checked_add(i32::try_from(d.checked_sub(1)?).ok()?)?;
+        }
+        Some(Aabb::new(self.origin, max))
+    }
+}
+
+impl<const N: usize> LinearIndex<Vector<N, i32>> for OffsetIndex<N> {
+    fn index_unchecked(&self, i: Vector<N, i32>) -> Option<usize> {
+        let local: Vector<N, usize> = i.checked_sub(self.origin)?.try_into().ok()?;
+        Vector::new(self.dims).index_unchecked(local)
+    }
+
+    fn unindex(&self, i: usize) -> Option<Vector<N, i32>> {
+        if i >= self.checked_cardinality()? {
+            return None;
+        }
+        let local: Vector<N, i32> = Vector::new(self.dims).unindex(i)?.try_into().ok()?;
+        local.checked_add(self.origin)
+    }
+
+    fn is_in_bounds(&self, i: &Vector<N, i32>) -> bool {
+        i.checked_sub(self.origin)
+            .and_then(|local| Vector::<N, usize>::try_from(local).ok())
+            .is_some_and(|local| Vector::new(self.dims).is_in_bounds(&local))
+    }
+
+    fn checked_cardinality(&self) -> Option<usize> {
+        Vector::new(self.dims).checked_cardinality()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinearIndex, OffsetIndex, StridedIndex};
+    use crate::{aabb::Aabb, vector::Vector};
+
+    #[test]
+    fn strided_index() {
+        let grid = StridedIndex::contiguous([4, 3]);
+        assert_eq!(grid.strides, [1, 4]);
+        assert_eq!(grid.index([3, 2]), Some(11));
+        assert_eq!(grid.index([4, 0]), None);
+        assert_eq!(grid.unindex(6), Some([2, 1]));
+        assert_eq!(grid.unindex(12), None);
+
+        let row = grid.slice([0, 1], [4, 1], [1, 1]).unwrap();
+        assert_eq!(row.iter().collect::<Vec<_>>(), vec![4, 5, 6, 7]);
+        let column = grid.slice([2, 0], [1, 3], [1, 1]).unwrap();
+        assert_eq!(column.iter().collect::<Vec<_>>(), vec![2, 6, 10]);
+        let every_other = grid.slice([1, 0], [2, 2], [2, 2]).unwrap();
+        assert_eq!(every_other.iter().collect::<Vec<_>>(), vec![1, 3, 9, 11]);
+        assert_eq!(every_other.unindex(9), Some([0, 1]));
+        assert_eq!(every_other.unindex(5), None);
+
+        assert_eq!(grid.slice([1, 0], [2, 2], [2, 3]), None);
+        assert_eq!(grid.slice([0, 0], [5, 1], [1, 1]), None);
+        assert_eq!(grid.slice([0, 0], [1, 1], [0, 1]), None);
+        assert!(grid.slice([3, 2], [0, 0], [1, 1]).unwrap().is_empty());
+
+        let interleaved = StridedIndex::new(0, [6, 4], [2, 3]);
+        assert_eq!(interleaved.unindex(8), Some([0, 2]));
+        assert_eq!(interleaved.unindex(12), None);
+        for n in 0..interleaved.len() {
+            let p = interleaved.nth_position(n).unwrap();
+            assert_eq!(interleaved.unindex(interleaved.index(p).unwrap()), Some(p));
+        }
+    }
+
+    #[test]
+    fn offset_index() {
+        let index = OffsetIndex::new(Vector::new([-2, -1]), [4, 3]);
+        assert_eq!(Some(12), index.checked_cardinality());
+        assert_eq!(Some(0), index.index(Vector::new([-2, -1])));
+        assert_eq!(Some(6), index.index(Vector::new([0, 0])));
+        assert_eq!(Some(11), index.index(Vector::new([1, 1])));
+        assert_eq!(None, index.index(Vector::new([2, 1])));
+        assert_eq!(None, index.index(Vector::new([-3, 0])));
+        assert_eq!(None, index.index(Vector::new([i32::MIN, 0])));
+        for i in 0..12 {
+            assert_eq!(Some(i), index.index(index.unindex(i).unwrap()));
+        }
+        assert_eq!(None, index.unindex(12));
+
+        let aabb = Aabb::new(Vector::new([-2, -1]), Vector::new([1, 1]));
+        assert_eq!(Some(index), OffsetIndex::covering(&aabb));
+        assert_eq!(Some(aabb), index.bounds());
+        assert_eq!(None, OffsetIndex::new(Vector::new([0, 0]), [0, 3]).bounds());
+        let full = Aabb::new(Vector::new([0, 0]), Vector::new([i32::MAX, 0]));
+        assert_eq!(None, OffsetIndex::covering(&full));
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
 pub trait Zero {
     fn zero() -> Self;
 }
@@ -61,7 +63,239 @@ macro_rules! gcd {
     };
 }
 
-gcd!(usize, i32, i64);
+gcd!(
+    usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128
+);
+
+pub trait ExtendedGcd
+where
+    Self: Gcd + Sized,
+{
+    /// Bezout coefficients, `(g, x, y)` with `a * x + b * y == g` and `g` the non-negative gcd.
+    fn egcd(a: Self, b: Self) -> (Self, Self, Self);
+}
+
+/// Iterative extended Euclid shared by all [`ExtendedGcd`] impls.
+fn extended_euclid<T>(a: T, b: T) -> (T, T, T)
+where
+    T: Zero + One + Clone + PartialOrd + Neg<Output = T>,
+    T: Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+    while r != T::zero() {
+        let q = old_r.clone() / r.clone();
+        (old_r, r) = (r.clone(), old_r - q.clone() * r);
+        (old_x, x) = (x.clone(), old_x - q.clone() * x);
+        (old_y, y) = (y.clone(), old_y - q * y);
+    }
+    if old_r < T::zero() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+macro_rules! extended_gcd {
+    ($($t:ty),*) => {
+        $(
+        impl ExtendedGcd for $t {
+            fn egcd(a: Self, b: Self) -> (Self, Self, Self) {
+                extended_euclid(a, b)
+            }
+        })*
+    };
+}
+
+extended_gcd!(isize, i8, i16, i32, i64, i128);
+
+#[cfg(feature = "bigint")]
+mod bigint {
+    use num_bigint::{BigInt, BigUint};
+
+    use super::{ExtendedGcd, Gcd, One, Zero};
+
+    impl Zero for BigInt {
+        fn zero() -> Self {
+            BigInt::ZERO
+        }
+    }
+
+    impl One for BigInt {
+        fn one() -> Self {
+            BigInt::from(1)
+        }
+    }
+
+    impl Zero for BigUint {
+        fn zero() -> Self {
+            BigUint::ZERO
+        }
+    }
+
+    impl One for BigUint {
+        fn one() -> Self {
+            BigUint::from(1u8)
+        }
+    }
+
+    impl Gcd for BigUint {
+        fn gcd(mut a: Self, mut b: Self) -> Self {
+            while b != BigUint::ZERO {
+                (a, b) = (b.clone(), a % b);
+            }
+            a
+        }
+        fn lcm(a: Self, b: Self) -> Self {
+            let g = Self::gcd(a.clone(), b.clone());
+            a * (b / g)
+        }
+    }
+
+    impl Gcd for BigInt {
+        fn gcd(a: Self, b: Self) -> Self {
+            BigUint::gcd(a.magnitude().clone(), b.magnitude().clone()).into()
+        }
+        fn lcm(a: Self, b: Self) -> Self {
+            BigUint::lcm(a.magnitude().clone(), b.magnitude().clone()).into()
+        }
+    }
+
+    impl ExtendedGcd for BigInt {
+        fn egcd(a: Self, b: Self) -> (Self, Self, Self) {
+            super::extended_euclid(a, b)
+        }
+    }
+}
+
+pub trait Binomial
+where
+    Self: Sized,
+{
+    /// `n!`, `None` on overflow.
+    fn factorial(n: Self) -> Option<Self>;
+    /// Number of `k` element subsets of an `n` element set, `None` on overflow.
+    fn binomial(n: Self, k: Self) -> Option<Self>;
+    /// Number of ways to split `ks.iter().sum()` items into groups of the given sizes, `None` on
+    /// overflow.
+    fn multinomial(ks: &[Self]) -> Option<Self>;
+}
+
+macro_rules! binomial {
+    ($($t:ty),*) => {
+        $(
+        impl Binomial for $t {
+            fn factorial(n: Self) -> Option<Self> {
+                (2..=n).try_fold(1 as $t, |acc, i| acc.checked_mul(i))
+            }
+
+            fn binomial(n: Self, k: Self) -> Option<Self> {
+                if k > n {
+                    return Some(0);
+                }
+                let k = k.min(n - k);
+                let mut result: $t = 1;
+                for i in 0..k {
+                    // NOTE(lubo): `result * (n - i)` is divisible by `i + 1`, cancel the common
+                    // factor first so that only an overflowing result overflows.
+                    let d = i + 1;
+                    let g = Self::gcd(result, d);
+                    result = (result / g).checked_mul((n - i) / (d / g))?;
+                }
+                Some(result)
+            }
+
+            fn multinomial(ks: &[Self]) -> Option<Self> {
+                let mut n: $t = 0;
+                let mut result: $t = 1;
+                for k in ks.iter() {
+                    n = n.checked_add(*k)?;
+                    result = result.checked_mul(Self::binomial(n, *k)?)?;
+                }
+                Some(result)
+            }
+        })*
+    };
+}
+
+binomial!(usize, u32, u64, u128);
+
+/// `binomial(n, k) mod p` for a prime `p`, by Lucas' theorem. Takes `O(p.min(k))` per base `p`
+/// digit of `n`.
+pub fn binomial_mod(mut n: u64, mut k: u64, p: u64) -> u64 {
+    let mut result = 1 % p;
+    while k > 0 {
+        let (ni, ki) = (n % p, k % p);
+        if ki > ni {
+            return 0;
+        }
+        let ki = ki.min(ni - ki);
+        let (mut numerator, mut denominator) = (1u128, 1u128);
+        for i in 0..ki {
+            numerator = numerator * (ni - i) as u128 % p as u128;
+            denominator = denominator * (i + 1) as u128 % p as u128;
+        }
+        // NOTE(lubo): Fermat's little theorem, `denominator` is not divisible by `p`.
+        let inverse = crate::modular::mod_pow(denominator as u64, p - 2, p) as u128;
+        result = (result as u128 * numerator % p as u128 * inverse % p as u128) as u64;
+        n /= p;
+        k /= p;
+    }
+    result
+}
+
+/// Precomputed rows of Pascal's triangle for repeated binomial queries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PascalTable {
+    rows: Vec<Vec<u64>>,
+}
+
+impl PascalTable {
+    /// Exact values up to row `max_n`, which has to be at most 67 to fit in `u64`.
+    pub fn new(max_n: usize) -> Self {
+        assert!(
+            max_n <= 67,
+            "binomial coefficients past row 67 overflow u64"
+        );
+        Self::build(max_n, |a, b| a + b)
+    }
+
+    /// Values modulo `modulus` up to row `max_n`.
+    pub fn with_modulus(max_n: usize, modulus: u64) -> Self {
+        Self::build(max_n, |a, b| {
+            ((a as u128 + b as u128) % modulus as u128) as u64
+        })
+    }
+
+    fn build<F: Fn(u64, u64) -> u64>(max_n: usize, add: F) -> Self {
+        let mut rows: Vec<Vec<u64>> = Vec::with_capacity(max_n + 1);
+        for n in 0..=max_n {
+            let row = (0..=n)
+                .map(|k| match k {
+                    0 => add(1, 0),
+                    k if k == n => add(1, 0),
+                    k => add(rows[n - 1][k - 1], rows[n - 1][k]),
+                })
+                .collect();
+            rows.push(row);
+        }
+        Self { rows }
+    }
+
+    pub fn max_n(&self) -> usize {
+        self.rows.len() - 1
+    }
+
+    /// `binomial(n, k)`, zero when `k > n`. Panics when `n` is past the last row.
+    pub fn get(&self, n: usize, k: usize) -> u64 {
+        self.rows[n].get(k).copied().unwrap_or(0)
+    }
+
+    pub fn row(&self, n: usize) -> &[u64] {
+        &self.rows[n]
+    }
+}
 
 pub trait AbsoluteValue
 where
@@ -81,7 +315,7 @@ macro_rules! checked_absolute_value {
     };
 }
 
-checked_absolute_value!(i32);
+checked_absolute_value!(isize, i8, i16, i32, i64, i128);
 
 macro_rules! identity_absolute_value {
     ($($t:ty),*) => {
@@ -94,7 +328,150 @@ impl AbsoluteValue for $t {
     };
 }
 
-identity_absolute_value!(usize);
+identity_absolute_value!(usize, u8, u16, u32, u64, u128);
+
+macro_rules! float_absolute_value {
+    ($($t:ty),*) => {
+        $(
+impl AbsoluteValue for $t {
+    fn abs(&self) -> Option<Self> {
+        Some(<$t>::abs(*self))
+    }
+        })*
+    };
+}
+
+float_absolute_value!(f32, f64);
+
+/// Arithmetic operators closed over one type.
+pub trait NumOps<Rhs = Self, Output = Self>:
+    Add<Rhs, Output = Output>
+    + Sub<Rhs, Output = Output>
+    + Mul<Rhs, Output = Output>
+    + Div<Rhs, Output = Output>
+{
+}
+
+impl<T, Rhs, Output> NumOps<Rhs, Output> for T where
+    T: Add<Rhs, Output = Output>
+        + Sub<Rhs, Output = Output>
+        + Mul<Rhs, Output = Output>
+        + Div<Rhs, Output = Output>
+{
+}
+
+/// Common bound for generic numeric code, implemented for every type with the arithmetic
+/// operators and [`Zero`] and [`One`], including [`crate::modular::Mod`].
+pub trait Num: Copy + PartialEq + Zero + One + NumOps {}
+
+impl<T> Num for T where T: Copy + PartialEq + Zero + One + NumOps {}
+
+pub trait Signed: Num + Neg<Output = Self> {
+    fn is_negative(&self) -> bool;
+    fn is_positive(&self) -> bool;
+    /// `-1`, `0` or `1`. Floats keep the sign of zero and NaN as [`f64::signum`] does.
+    fn signum(&self) -> Self;
+}
+
+macro_rules! signed {
+    ($($t:ty),*) => {
+        $(
+        impl Signed for $t {
+            fn is_negative(&self) -> bool {
+                *self < Self::zero()
+            }
+            fn is_positive(&self) -> bool {
+                *self > Self::zero()
+            }
+            fn signum(&self) -> Self {
+                <$t>::signum(*self)
+            }
+        })*
+    };
+}
+
+signed!(isize, i8, i16, i32, i64, i128, f32, f64);
+
+/// Floating point numbers, including [`crate::ord_float::OrdF32`] and
+/// [`crate::ord_float::OrdF64`].
+pub trait Float: Signed + PartialOrd {
+    fn epsilon() -> Self;
+    fn sqrt(&self) -> Self;
+}
+
+macro_rules! float {
+    ($($t:ty),*) => {
+        $(
+        impl Float for $t {
+            fn epsilon() -> Self {
+                <$t>::EPSILON
+            }
+            fn sqrt(&self) -> Self {
+                <$t>::sqrt(*self)
+            }
+        })*
+    };
+}
+
+float!(f32, f64);
+
+pub trait Bounded {
+    fn min_value() -> Self;
+    fn max_value() -> Self;
+}
+
+macro_rules! bounded {
+    ($($t:ty),*) => {
+        $(
+        impl Bounded for $t {
+            fn min_value() -> Self {
+                <$t>::MIN
+            }
+            fn max_value() -> Self {
+                <$t>::MAX
+            }
+        })*
+    };
+}
+
+bounded!(
+    isize, i8, i16, i32, i64, i128, usize, u8, u16, u32, u64, u128, f32, f64
+);
+
+/// Arithmetic that reports overflow and division by zero as `None`.
+pub trait CheckedOps
+where
+    Self: Sized,
+{
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! checked_ops {
+    ($($t:ty),*) => {
+        $(
+        impl CheckedOps for $t {
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_div(self, rhs)
+            }
+        })*
+    };
+}
+
+checked_ops!(
+    isize, i8, i16, i32, i64, i128, usize, u8, u16, u32, u64, u128
+);
 
 pub trait InclusiveMin<T> {
     fn inclusive_min(&self) -> &T;
@@ -106,6 +483,52 @@ pub trait ExclusiveMax<T> {
     fn exclusive_max(&self) -> &T;
 }
 
+/// `n = q * d + r` with `0 <= r < d`, for positive `d`.
+fn floor_div_rem<T>(n: T, d: T) -> (T, T)
+where
+    T: Num + Ord + Rem<Output = T>,
+{
+    let (q, r) = (n / d, n % d);
+    if r < T::zero() {
+        (q - T::one(), r + d)
+    } else {
+        (q, r)
+    }
+}
+
+/// Compare fractions `a.0 / a.1` and `b.0 / b.1` exactly. Denominators must be positive.
+///
+/// Compares the continued fraction expansions, so unlike cross-multiplying this never overflows.
+pub fn compare_ratios<T>(mut a: (T, T), mut b: (T, T)) -> std::cmp::Ordering
+where
+    T: Num + Ord + Rem<Output = T>,
+{
+    use std::cmp::Ordering;
+
+    let mut flipped = false;
+    loop {
+        let (qa, ra) = floor_div_rem(a.0, a.1);
+        let (qb, rb) = floor_div_rem(b.0, b.1);
+        let ordering = match (ra == T::zero(), rb == T::zero()) {
+            _ if qa != qb => qa.cmp(&qb),
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => {
+                // NOTE(lubo): ra / a.1 < rb / b.1 exactly when a.1 / ra > b.1 / rb.
+                (a, b) = ((a.1, ra), (b.1, rb));
+                flipped = !flipped;
+                continue;
+            }
+        };
+        return if flipped {
+            ordering.reverse()
+        } else {
+            ordering
+        };
+    }
+}
+
 pub fn triangle_numbers(n: i32) -> i32 {
     // n * (n + 1) / 2
     if n & 0b1 > 0 {
@@ -115,6 +538,134 @@ pub fn triangle_numbers(n: i32) -> i32 {
     }
 }
 
+/// Figurate numbers for non-negative indices, [`triangle_numbers`] for any integer width.
+pub trait Polygonal
+where
+    Self: Sized,
+{
+    /// `n * (n + 1) / 2`, `None` on overflow.
+    fn checked_triangle(n: Self) -> Option<Self>;
+    /// `n`-th `s`-gonal number `((s - 2) * n * n - (s - 4) * n) / 2` for `s >= 2`, `None` on
+    /// overflow.
+    fn checked_polygonal(s: Self, n: Self) -> Option<Self>;
+    /// `n` with `triangle(n) == x`, if there is one.
+    fn triangle_index(x: Self) -> Option<Self>;
+
+    fn triangle(n: Self) -> Self {
+        Self::checked_triangle(n).expect("triangle number overflow")
+    }
+
+    fn polygonal(s: Self, n: Self) -> Self {
+        Self::checked_polygonal(s, n).expect("polygonal number overflow")
+    }
+
+    fn is_triangle(x: Self) -> bool {
+        Self::triangle_index(x).is_some()
+    }
+}
+
+macro_rules! polygonal {
+    ($($t:ty),*) => {
+        $(
+        impl Polygonal for $t {
+            fn checked_triangle(n: Self) -> Option<Self> {
+                // NOTE(lubo): Halve the even factor first, the product is the only overflow.
+                if n % 2 == 0 {
+                    (n / 2).checked_mul(n.checked_add(1)?)
+                } else {
+                    n.checked_mul(n / 2 + 1)
+                }
+            }
+
+            fn checked_polygonal(s: Self, n: Self) -> Option<Self> {
+                // NOTE(lubo): `P(s, n) = n + (s - 2) * T(n - 1)`.
+                if n == 0 {
+                    return Some(0);
+                }
+                Self::checked_triangle(n - 1)?
+                    .checked_mul(s.checked_sub(2)?)?
+                    .checked_add(n)
+            }
+
+            fn triangle_index(x: Self) -> Option<Self> {
+                #[allow(unused_comparisons)]
+                if x < 0 {
+                    return None;
+                }
+                // NOTE(lubo): `T(n) <= x < T(n + 1)` exactly when `n == isqrt(2 * x)` up to
+                // rounding, fix up the estimate with exact checks.
+                let mut n = match x.checked_mul(2) {
+                    Some(y) => y.isqrt(),
+                    None => (x / 2).isqrt() * 2,
+                };
+                while Self::checked_triangle(n).is_none_or(|t| t > x) {
+                    n -= 1;
+                }
+                while Self::checked_triangle(n + 1).is_some_and(|t| t <= x) {
+                    n += 1;
+                }
+                (Self::checked_triangle(n) == Some(x)).then_some(n)
+            }
+        })*
+    };
+}
+
+polygonal!(usize, u32, u64, u128, i32, i64, i128);
+
+/// Closed form sums of arithmetic progressions.
+pub trait ArithmeticSum
+where
+    Self: Sized,
+{
+    /// `first + (first + step) + ... + (first + (count - 1) * step)`, `None` if `count` is
+    /// negative or a term or the sum does not fit.
+    fn checked_sum_arithmetic(first: Self, step: Self, count: Self) -> Option<Self>;
+    /// Sum of the integers in `[a, b]`, zero when the range is empty. `None` if the number of
+    /// terms or the sum does not fit.
+    fn checked_sum_range(a: Self, b: Self) -> Option<Self>;
+
+    fn sum_arithmetic(first: Self, step: Self, count: Self) -> Self {
+        Self::checked_sum_arithmetic(first, step, count).expect("arithmetic sum overflow")
+    }
+
+    fn sum_range(a: Self, b: Self) -> Self {
+        Self::checked_sum_range(a, b).expect("arithmetic sum overflow")
+    }
+}
+
+macro_rules! arithmetic_sum {
+    ($($t:ty),*) => {
+        $(
+        impl ArithmeticSum for $t {
+            fn checked_sum_arithmetic(first: Self, step: Self, count: Self) -> Option<Self> {
+                #[allow(unused_comparisons)]
+                if count <= 0 {
+                    return (count == 0).then_some(0);
+                }
+                // NOTE(lubo): An odd count is `count` times the middle term, an even one adds the
+                // last term to that. Only terms and the sum itself are ever materialized.
+                let odd = if count % 2 == 0 { count - 1 } else { count };
+                let middle = first.checked_add(step.checked_mul((odd - 1) / 2)?)?;
+                let sum = odd.checked_mul(middle)?;
+                if odd == count {
+                    Some(sum)
+                } else {
+                    sum.checked_add(first.checked_add(step.checked_mul(count - 1)?)?)
+                }
+            }
+
+            fn checked_sum_range(a: Self, b: Self) -> Option<Self> {
+                if a > b {
+                    return Some(0);
+                }
+                Self::checked_sum_arithmetic(a, 1, b.checked_sub(a)?.checked_add(1)?)
+            }
+        })*
+    };
+}
+
+arithmetic_sum!(usize, u32, u64, u128, i32, i64, i128);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -132,4 +683,176 @@ mod tests {
         assert_eq!(triangle_numbers(9), 45);
         assert_eq!(triangle_numbers(10), 55);
     }
+
+    #[test]
+    fn test_egcd() {
+        assert_eq!(i32::egcd(240, 46), (2, -9, 47));
+        let pairs = [
+            (240i64, 46),
+            (-240, 46),
+            (46, -240),
+            (17, 0),
+            (0, -5),
+            (0, 0),
+            (7, 7),
+        ];
+        for (a, b) in pairs {
+            let (g, x, y) = i64::egcd(a, b);
+            assert_eq!(g, i64::gcd(a.abs(), b.abs()));
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(u64::factorial(0), Some(1));
+        assert_eq!(u64::factorial(20), Some(2432902008176640000));
+        assert_eq!(u64::factorial(21), None);
+        assert!(u128::factorial(34).is_some());
+
+        assert_eq!(u64::binomial(5, 2), Some(10));
+        assert_eq!(u64::binomial(5, 7), Some(0));
+        assert_eq!(u32::binomial(0, 0), Some(1));
+        assert_eq!(u64::binomial(67, 33), Some(14226520737620288370));
+        assert_eq!(u64::binomial(68, 34), None);
+        assert_eq!(u64::binomial(1_000_000, 2), Some(499999500000));
+        assert_eq!(usize::multinomial(&[2, 1, 1]), Some(12));
+        assert_eq!(u64::multinomial(&[]), Some(1));
+        assert_eq!(u32::multinomial(&[20, 20]), None);
+    }
+
+    #[test]
+    fn test_binomial_mod() {
+        assert_eq!(binomial_mod(10, 3, 1_000_000_007), 120);
+        assert_eq!(binomial_mod(10, 3, 7), 1);
+        assert_eq!(binomial_mod(1000, 500, 13), 0);
+        assert_eq!(binomial_mod(1000, 300, 13), 10);
+        assert_eq!(binomial_mod(1_000_000, 12345, 1_000_003), 782200);
+        assert_eq!(binomial_mod(5, 6, 3), 0);
+
+        let table = PascalTable::with_modulus(60, 13);
+        let exact = PascalTable::new(67);
+        for n in 0..=60 {
+            for k in 0..=n + 1 {
+                assert_eq!(table.get(n, k), exact.get(n, k) % 13);
+                assert_eq!(binomial_mod(n as u64, k as u64, 13), table.get(n, k));
+            }
+        }
+        assert_eq!(exact.get(67, 33), 14226520737620288370);
+        assert_eq!(exact.row(4), &[1, 4, 6, 4, 1]);
+        assert_eq!(exact.max_n(), 67);
+    }
+
+    #[test]
+    fn test_polygonal() {
+        for n in 0..100 {
+            assert_eq!(i32::triangle(n), triangle_numbers(n));
+            assert_eq!(u64::polygonal(3, n as u64), triangle_numbers(n) as u64);
+            assert_eq!(i64::polygonal(4, n as i64), (n * n) as i64);
+        }
+        assert_eq!(
+            (1..=6).map(|n| u32::polygonal(5, n)).collect::<Vec<_>>(),
+            vec![1, 5, 12, 22, 35, 51]
+        );
+        assert_eq!(u32::polygonal(2, 7), 7);
+        assert_eq!(u32::checked_polygonal(1, 7), None);
+        assert_eq!(i32::checked_triangle(65535), Some(2147450880));
+        assert_eq!(i32::checked_triangle(65536), None);
+
+        assert_eq!(u64::triangle_index(0), Some(0));
+        assert_eq!(u64::triangle_index(55), Some(10));
+        assert_eq!(u64::triangle_index(56), None);
+        assert!(!i64::is_triangle(-1));
+        assert!(usize::is_triangle(5050));
+        let n = u128::triangle_index(u128::triangle(u64::MAX as u128)).unwrap();
+        assert_eq!(n, u64::MAX as u128);
+        let largest = u64::triangle(6074000999);
+        assert_eq!(u64::triangle_index(largest), Some(6074000999));
+        assert_eq!(u64::triangle_index(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_arithmetic_sum() {
+        assert_eq!(u64::sum_range(1, 100), 5050);
+        assert_eq!(i32::sum_range(-10, 10), 0);
+        assert_eq!(i32::sum_range(-3, 5), 9);
+        assert_eq!(i64::sum_range(5, 4), 0);
+        assert_eq!(i32::sum_arithmetic(7, -2, 5), 7 + 5 + 3 + 1 - 1);
+        assert_eq!(u32::sum_arithmetic(3, 4, 0), 0);
+        assert_eq!(i64::checked_sum_arithmetic(1, 1, -1), None);
+        for count in 0..30 {
+            let expected: i64 = (0..count).map(|i| -40 + 3 * i).sum();
+            assert_eq!(i64::sum_arithmetic(-40, 3, count), expected);
+        }
+
+        assert_eq!(i32::checked_sum_range(i32::MIN, i32::MAX), None);
+        // NOTE(lubo): The sum fits but the number of terms does not.
+        assert_eq!(i32::checked_sum_range(-i32::MAX, i32::MAX), None);
+        assert_eq!(
+            i32::checked_sum_range(-(i32::MAX / 2), i32::MAX / 2),
+            Some(0)
+        );
+        // NOTE(lubo): `u32::MAX` is `65535 * 65537`, the sum of 65535 terms centered on 65537.
+        assert_eq!(
+            u32::checked_sum_arithmetic(65537 - 32767 * 2, 2, 65535),
+            Some(u32::MAX)
+        );
+        assert_eq!(u32::checked_sum_range(0, 1 << 20), None);
+        assert_eq!(
+            u128::sum_range(1, 1 << 60),
+            (1u128 << 60) * ((1u128 << 60) + 1) / 2
+        );
+    }
+
+    #[test]
+    fn test_numeric_traits() {
+        fn sum_of_squares<T: Num>(values: &[T]) -> T {
+            values.iter().fold(T::zero(), |acc, &x| acc + x * x)
+        }
+        assert_eq!(sum_of_squares(&[1, 2, 3]), 14);
+        assert_eq!(sum_of_squares(&[0.5, 1.5]), 2.5);
+        use crate::modular::Mod;
+        assert_eq!(
+            sum_of_squares(&[Mod::<7>::new(3), Mod::new(5)]),
+            Mod::new(6)
+        );
+
+        assert!(Signed::is_negative(&-3i64));
+        assert!(Signed::is_positive(&0.5f32));
+        assert_eq!(Signed::signum(&-7i8), -1);
+        assert_eq!(<u16 as Bounded>::max_value(), u16::MAX);
+        assert_eq!(<f64 as Bounded>::min_value(), f64::MIN);
+        assert_eq!(AbsoluteValue::abs(&i64::MIN), None);
+        assert_eq!(AbsoluteValue::abs(&-2.5f64), Some(2.5));
+
+        fn checked_dot<T: CheckedOps + Zero + Copy>(a: &[T], b: &[T]) -> Option<T> {
+            a.iter().zip(b).try_fold(T::zero(), |acc, (&x, &y)| {
+                acc.checked_add(x.checked_mul(y)?)
+            })
+        }
+        assert_eq!(checked_dot(&[1u8, 2], &[3, 4]), Some(11));
+        assert_eq!(checked_dot(&[16u8, 1], &[16, 1]), None);
+        assert_eq!(CheckedOps::checked_div(1u32, 0), None);
+    }
+
+    #[test]
+    fn test_compare_ratios() {
+        use std::cmp::Ordering;
+        assert_eq!(compare_ratios((1, 2), (2, 4)), Ordering::Equal);
+        assert_eq!(compare_ratios((1, 3), (1, 2)), Ordering::Less);
+        assert_eq!(compare_ratios((-1, 3), (-1, 2)), Ordering::Greater);
+        assert_eq!(compare_ratios((-7, 3), (-5, 2)), Ordering::Greater);
+        assert_eq!(compare_ratios((6, 3), (4, 2)), Ordering::Equal);
+        assert_eq!(compare_ratios((355, 113), (22, 7)), Ordering::Less);
+        let big = i128::MAX;
+        assert_eq!(
+            compare_ratios((big, big - 1), (big - 1, big - 2)),
+            Ordering::Less
+        );
+        assert_eq!(compare_ratios((big - 1, big), (big, big)), Ordering::Less);
+        assert_eq!(
+            compare_ratios((i64::MIN, 3), (i64::MIN, 2)),
+            Ordering::Greater
+        );
+    }
 }
@@ -0,0 +1,287 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use super::{
+    math::{One, Zero},
+    vector::Vector,
+};
+
+/// Row-major `R x C` matrix.
+#[derive(Hash, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Matrix<const R: usize, const C: usize, T> {
+    pub values: [[T; C]; R],
+}
+
+pub type Mat2<T> = Matrix<2, 2, T>;
+pub type Mat3<T> = Matrix<3, 3, T>;
+pub type Mat4<T> = Matrix<4, 4, T>;
+
+impl<const R: usize, const C: usize, T> Matrix<R, C, T> {
+    pub const fn new(values: [[T; C]; R]) -> Self {
+        Self { values }
+    }
+}
+
+impl<const R: usize, const C: usize, T> Matrix<R, C, T>
+where
+    T: Copy,
+{
+    pub fn all(value: T) -> Self {
+        Self::new([[value; C]; R])
+    }
+
+    pub fn from_rows(rows: [Vector<C, T>; R]) -> Self {
+        Self::new(rows.map(|row| row.values))
+    }
+
+    pub fn from_columns(columns: [Vector<R, T>; C]) -> Self {
+        Matrix::<C, R, T>::from_rows(columns).transpose()
+    }
+
+    pub fn row(&self, r: usize) -> Vector<C, T> {
+        Vector::new(self.values[r])
+    }
+
+    pub fn column(&self, c: usize) -> Vector<R, T> {
+        Vector::new(self.values.map(|row| row[c]))
+    }
+
+    pub fn transpose(&self) -> Matrix<C, R, T> {
+        Matrix::new(std::array::from_fn(|c| {
+            std::array::from_fn(|r| self.values[r][c])
+        }))
+    }
+
+    fn elementwise_binary<F: Fn(T, T) -> T>(&self, rhs: Self, f: F) -> Self {
+        Self::new(std::array::from_fn(|r| {
+            std::array::from_fn(|c| f(self.values[r][c], rhs.values[r][c]))
+        }))
+    }
+}
+
+impl<const R: usize, const C: usize, T> Matrix<R, C, T>
+where
+    T: Copy + Zero,
+{
+    pub fn zero() -> Self {
+        Self::all(T::zero())
+    }
+}
+
+impl<const N: usize, T> Matrix<N, N, T>
+where
+    T: Copy + Zero + One,
+{
+    pub fn identity() -> Self {
+        Self::new(std::array::from_fn(|r| {
+            std::array::from_fn(|c| if r == c { T::one() } else { T::zero() })
+        }))
+    }
+}
+
+impl<T> Matrix<2, 2, T>
+where
+    T: Copy + Neg<Output = T>,
+    T: Sub<Output = T> + Mul<Output = T>,
+{
+    pub fn determinant(&self) -> T {
+        let [[a, b], [c, d]] = self.values;
+        a * d - b * c
+    }
+
+    /// Transposed matrix of cofactors, `m * m.adjugate() == det(m) * I`.
+    pub fn adjugate(&self) -> Self {
+        let [[a, b], [c, d]] = self.values;
+        Self::new([[d, -b], [-c, a]])
+    }
+}
+
+impl<T> Matrix<3, 3, T>
+where
+    T: Copy,
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    pub fn determinant(&self) -> T {
+        let m = self.values;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// Transposed matrix of cofactors, `m * m.adjugate() == det(m) * I`.
+    pub fn adjugate(&self) -> Self {
+        let m = self.values;
+        Self::new(std::array::from_fn(|r| {
+            std::array::from_fn(|c| {
+                // NOTE(lubo): Cyclic indices give the cofactor sign for free.
+                let (r1, r2) = ((c + 1) % 3, (c + 2) % 3);
+                let (c1, c2) = ((r + 1) % 3, (r + 2) % 3);
+                m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1]
+            })
+        }))
+    }
+}
+
+macro_rules! float_matrix_inverse {
+    ($($t:ty),*) => {
+        $(
+        impl Matrix<2, 2, $t> {
+            /// Returns `None` for singular matrices.
+            pub fn inverse(&self) -> Option<Self> {
+                let determinant = self.determinant();
+                (determinant != 0.0).then(|| self.adjugate() * (1.0 / determinant))
+            }
+        }
+
+        impl Matrix<3, 3, $t> {
+            /// Returns `None` for singular matrices.
+            pub fn inverse(&self) -> Option<Self> {
+                let determinant = self.determinant();
+                (determinant != 0.0).then(|| self.adjugate() * (1.0 / determinant))
+            }
+        })*
+    };
+}
+
+macro_rules! integer_matrix_inverse {
+    ($($t:ty),*) => {
+        $(
+        impl Matrix<2, 2, $t> {
+            /// Exact inverse, only exists when the determinant is `1` or `-1`.
+            pub fn inverse(&self) -> Option<Self> {
+                let determinant = self.determinant();
+                (determinant.abs() == 1).then(|| self.adjugate() * determinant)
+            }
+        }
+
+        impl Matrix<3, 3, $t> {
+            /// Exact inverse, only exists when the determinant is `1` or `-1`.
+            pub fn inverse(&self) -> Option<Self> {
+                let determinant = self.determinant();
+                (determinant.abs() == 1).then(|| self.adjugate() * determinant)
+            }
+        })*
+    };
+}
+
+float_matrix_inverse!(f32, f64);
+integer_matrix_inverse!(i32, i64);
+
+impl<const R: usize, const C: usize, T: Add<Output = T> + Copy> Add for Matrix<R, C, T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.elementwise_binary(rhs, |a, b| a + b)
+    }
+}
+
+impl<const R: usize, const C: usize, T: Sub<Output = T> + Copy> Sub for Matrix<R, C, T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.elementwise_binary(rhs, |a, b| a - b)
+    }
+}
+
+impl<const R: usize, const C: usize, T> Mul<T> for Matrix<R, C, T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.values.map(|row| row.map(|x| x * rhs)))
+    }
+}
+
+impl<const R: usize, const C: usize, const K: usize, T> Mul<Matrix<C, K, T>> for Matrix<R, C, T>
+where
+    T: Copy + Zero,
+    T: Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Matrix<R, K, T>;
+
+    fn mul(self, rhs: Matrix<C, K, T>) -> Self::Output {
+        Matrix::new(std::array::from_fn(|r| {
+            std::array::from_fn(|k| {
+                (0..C).fold(T::zero(), |acc, c| {
+                    acc + self.values[r][c] * rhs.values[c][k]
+                })
+            })
+        }))
+    }
+}
+
+impl<const R: usize, const C: usize, T> Mul<Vector<C, T>> for Matrix<R, C, T>
+where
+    T: Copy + Zero,
+    T: Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Vector<R, T>;
+
+    fn mul(self, rhs: Vector<C, T>) -> Self::Output {
+        Vector::new(std::array::from_fn(|r| {
+            (0..C).fold(T::zero(), |acc, c| acc + self.values[r][c] * rhs.values[c])
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Matrix;
+    use crate::vector::{V2, V3};
+
+    #[test]
+    fn determinant_and_inverse() {
+        let a = Matrix::new([[2.0f64, 1.0], [5.0, 3.0]]);
+        assert_eq!(a.determinant(), 1.0);
+        assert_eq!(a * a.inverse().unwrap(), Matrix::identity());
+        assert_eq!(Matrix::new([[1.0f32, 2.0], [2.0, 4.0]]).inverse(), None);
+
+        let b = Matrix::new([[1.0f64, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]]);
+        assert_eq!(b.determinant(), 1.0);
+        assert_eq!(b * b.adjugate(), Matrix::identity() * b.determinant());
+        assert_eq!(b.inverse().unwrap() * b, Matrix::identity());
+
+        let c = Matrix::new([[0i32, 1, 0], [0, 0, 1], [1, 0, 0]]);
+        assert_eq!(c.inverse(), Some(c.transpose()));
+        assert_eq!(Matrix::new([[2i32, 0], [0, 1]]).inverse(), None);
+        assert_eq!(
+            Matrix::new([[0i64, 1], [1, 0]]).inverse(),
+            Some(Matrix::new([[0, 1], [1, 0]]))
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Matrix::new([[1, 2], [3, 4]]);
+        let b = Matrix::new([[5, 6], [7, 8]]);
+        assert_eq!(a + b, Matrix::new([[6, 8], [10, 12]]));
+        assert_eq!(b - a, Matrix::all(4));
+        assert_eq!(a * 2, Matrix::new([[2, 4], [6, 8]]));
+        assert_eq!(a - a, Matrix::zero());
+    }
+
+    #[test]
+    fn multiplication() {
+        let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::new([[7, 8], [9, 10], [11, 12]]);
+        assert_eq!(a * b, Matrix::new([[58, 64], [139, 154]]));
+        assert_eq!(a * V3::from_xyz(1, 0, -1), V2::from_xy(-2, -2));
+        assert_eq!(Matrix::identity() * a, a);
+        assert_eq!(a * Matrix::identity(), a);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(a.transpose(), Matrix::new([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(a.transpose().transpose(), a);
+        assert_eq!(a.row(1), V3::from_xyz(4, 5, 6));
+        assert_eq!(a.column(2), V2::from_xy(3, 6));
+        assert_eq!(Matrix::from_rows([a.row(0), a.row(1)]), a);
+        assert_eq!(
+            Matrix::from_columns([a.column(0), a.column(1), a.column(2)]),
+            a
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Cache of computed values by key, for memoizing recursive functions over hashable states.
+/// The function takes the cache as its first argument and recurses through [`Memoized::call`].
+#[derive(Debug, Clone)]
+pub struct Memoized<K, V> {
+    cache: HashMap<K, V>,
+    limit: Option<usize>,
+    stats: MemoStats,
+}
+
+impl<K, V> Default for Memoized<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+            limit: None,
+            stats: MemoStats::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memoized<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores at most `limit` values, later ones are computed every time they are needed.
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::default()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+
+    /// Forget all values, keep the stats.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    /// Returns `false` if the value was not stored because of the limit.
+    pub fn insert(&mut self, key: K, value: V) -> bool {
+        if self.limit.is_some_and(|limit| self.cache.len() >= limit)
+            && !self.cache.contains_key(&key)
+        {
+            return false;
+        }
+        self.cache.insert(key, value);
+        true
+    }
+
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, f: F) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let value = f();
+        self.insert(key, value.clone());
+        value
+    }
+
+    /// Value of `f(self, key)`, computed only if not cached yet. `f` gets the cache back to
+    /// recurse through [`Memoized::call`] again.
+    pub fn call<F>(&mut self, key: K, f: F) -> V
+    where
+        K: Clone,
+        F: Fn(&mut Self, K) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let value = f(self, key.clone());
+        self.insert(key, value.clone());
+        value
+    }
+}
+
+/// `f(key)` with a fresh cache for the recursion, see [`Memoized::call`].
+pub fn memoize<K, V, F>(key: K, f: F) -> V
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&mut Memoized<K, V>, K) -> V,
+{
+    Memoized::new().call(key, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(memo: &mut Memoized<u32, u64>, n: u32) -> u64 {
+        match n {
+            0 | 1 => n as u64,
+            _ => memo.call(n - 1, fibonacci) + memo.call(n - 2, fibonacci),
+        }
+    }
+
+    #[test]
+    fn recursion() {
+        let mut memo = Memoized::new();
+        assert_eq!(memo.call(90, fibonacci), 2880067194370816120);
+        assert_eq!(memo.len(), 91);
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: 88,
+                misses: 91
+            }
+        );
+        assert_eq!(memo.call(90, fibonacci), 2880067194370816120);
+        assert_eq!(memo.stats().hits, 89);
+
+        fn paths(memo: &mut Memoized<(u64, u64), u64>, (x, y): (u64, u64)) -> u64 {
+            if x == 0 || y == 0 {
+                return 1;
+            }
+            memo.call((x - 1, y), paths) + memo.call((x, y - 1), paths)
+        }
+        assert_eq!(memoize((16, 16), paths), 601080390);
+    }
+
+    #[test]
+    fn limits() {
+        let mut memo = Memoized::with_limit(2);
+        assert_eq!(memo.get_or_insert_with("a", || 1), 1);
+        assert_eq!(memo.get_or_insert_with("b", || 2), 2);
+        assert_eq!(memo.get_or_insert_with("c", || 3), 3);
+        assert_eq!(memo.get_or_insert_with("c", || 4), 4);
+        assert_eq!(memo.get_or_insert_with("a", || 5), 1);
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.get(&"c"), None);
+        assert!(memo.insert("a", 6));
+        assert!(!memo.insert("d", 7));
+        assert_eq!(memo.stats(), MemoStats { hits: 1, misses: 4 });
+        memo.clear();
+        assert!(memo.is_empty());
+    }
+}
@@ -1,11 +1,20 @@
 use std::fmt::Display;
 use std::ops::Add;
 use std::ops::AddAssign;
+use std::ops::Div;
 use std::ops::Mul;
+use std::ops::MulAssign;
+use std::ops::Neg;
+use std::ops::Rem;
 use std::ops::Sub;
 use std::ops::SubAssign;
+use std::str::FromStr;
 
-use super::vector::Vector;
+use super::{
+    group::{Group, IterateGroup, Zn},
+    math::{ExtendedGcd, One, Zero},
+    vector::Vector,
+};
 
 pub trait ModularDecompose<T> {
     fn modular_decompose(&self, n: T) -> (T, T);
@@ -23,6 +32,13 @@ pub trait ModularSub {
 pub trait ModularMul {
     fn mul_n(&self, rhs: Self, n: Self) -> Self;
 }
+pub trait ModularInverse
+where
+    Self: Sized,
+{
+    /// `x` with `self * x == 1` modulo `n`, `None` unless `self` and `n` are coprime.
+    fn inverse_n(&self, n: Self) -> Option<Self>;
+}
 pub trait ModularAddAssign {
     fn addassign_n(&mut self, rhs: Self, n: Self);
 }
@@ -70,6 +86,12 @@ macro_rules! modular_primitives {
                 (self.mod_n(n) * rhs.mod_n(n)).mod_n(n)
             }
         }
+        impl ModularInverse for $t {
+            fn inverse_n(&self, n: Self) -> Option<Self> {
+                let (g, x, _) = extended_gcd(self.mod_n(n) as i128, n as i128);
+                (g == 1).then(|| x.rem_euclid(n as i128) as $t)
+            }
+        }
         impl ModularAddAssign for $t {
             fn addassign_n(&mut self, rhs: Self, n: Self) {
                 *self = self.add_n(rhs, n)
@@ -83,7 +105,18 @@ macro_rules! modular_primitives {
     )*};
 }
 
-modular_primitives!(usize, i32);
+modular_primitives!(usize, u32, u64, i32, i64, i128);
+
+/// Bézout coefficients, `(g, x, y)` with `a * x + b * y == g` where `g` is the non-negative
+/// gcd of `a` and `b`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    i128::egcd(a, b)
+}
+
+/// Multiplicative inverse of `a` modulo `m`, `None` when `gcd(a, m) != 1`.
+pub fn mod_inverse<T: ModularInverse>(a: T, m: T) -> Option<T> {
+    a.inverse_n(m)
+}
 
 impl<const C: usize, T: Copy + ModularAdd> ModularAdd for Vector<C, T> {
     fn add_n(&self, rhs: Self, n: Self) -> Self {
@@ -125,7 +158,7 @@ impl<const C: usize, T: Copy + ModularSubAssign> ModularSubAssign for Vector<C,
 
 macro_rules! modular_type {
     ($n:ident, $t:ty) => {
-        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
         pub struct $n<const C: $t>($t);
 
         impl<const C: $t> $n<C> {
@@ -185,15 +218,549 @@ macro_rules! modular_type {
                 write!(f, "{} (mod {})", self.0, C)
             }
         }
+
+        /// Integers modulo `C` under addition.
+        impl<const C: $t> Group for $n<C> {
+            fn identity() -> Self {
+                Self(0)
+            }
+
+            fn op(self, rhs: Self) -> Self {
+                self + rhs
+            }
+
+            fn inverse(self) -> Self {
+                Self(0) - self
+            }
+        }
+
+        impl<const C: $t> IterateGroup for $n<C> {
+            fn all() -> Vec<Self> {
+                (0..C).map(Self).collect()
+            }
+        }
     };
 }
 
 modular_type!(Modi32, i32);
 modular_type!(Modusize, usize);
 
+impl<const C: usize> From<Zn<C>> for Modusize<C> {
+    fn from(value: Zn<C>) -> Self {
+        Self::new(value.0)
+    }
+}
+
+impl<const C: usize> From<Modusize<C>> for Zn<C> {
+    fn from(value: Modusize<C>) -> Self {
+        Zn::new(value.0)
+    }
+}
+
+/// `base^exp` modulo `m` by repeated squaring, in Montgomery form for odd `m`.
+pub fn mod_pow(base: u64, exp: u64, m: u64) -> u64 {
+    match Montgomery::new(m) {
+        Some(montgomery) => montgomery.pow(base, exp),
+        None => mod_pow_naive(base, exp, m),
+    }
+}
+
+fn mod_pow_naive(base: u64, mut exp: u64, m: u64) -> u64 {
+    let m = m as u128;
+    let mut base = base as u128 % m;
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Modular multiplication and exponentiation without overflow for any modulus the type can hold.
+pub trait MulMod
+where
+    Self: Sized,
+{
+    fn mul_mod(self, rhs: Self, m: Self) -> Self;
+    fn pow_mod(self, exp: Self, m: Self) -> Self;
+}
+
+macro_rules! widening_mul_mod {
+    ($($t:ty),*) => {
+        $(
+        impl MulMod for $t {
+            fn mul_mod(self, rhs: Self, m: Self) -> Self {
+                (self as u128 * rhs as u128 % m as u128) as $t
+            }
+            fn pow_mod(self, exp: Self, m: Self) -> Self {
+                mod_pow(self as u64, exp as u64, m as u64) as $t
+            }
+        })*
+    };
+}
+
+widening_mul_mod!(u32, u64, usize);
+
+impl MulMod for u128 {
+    fn mul_mod(self, rhs: Self, m: Self) -> Self {
+        // NOTE(lubo): Double and add, every intermediate stays below `m`.
+        let add = |a: u128, b: u128| if a >= m - b { a - (m - b) } else { a + b };
+        let (mut a, mut b) = (self % m, rhs % m);
+        let mut result = 0;
+        while b > 0 {
+            if b & 1 == 1 {
+                result = add(result, a);
+            }
+            a = add(a, a);
+            b >>= 1;
+        }
+        result
+    }
+    fn pow_mod(self, mut exp: Self, m: Self) -> Self {
+        let mut base = self % m;
+        let mut result = 1 % m;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul_mod(base, m);
+            }
+            base = base.mul_mod(base, m);
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl MulMod for num_bigint::BigUint {
+    fn mul_mod(self, rhs: Self, m: Self) -> Self {
+        self * rhs % m
+    }
+    fn pow_mod(self, exp: Self, m: Self) -> Self {
+        self.modpow(&exp, &m)
+    }
+}
+
+/// Chinese remainder theorem, the `(x, m)` with `0 <= x < m` such that every `x = a (mod n)`
+/// for `(a, n)` in `congruences`, `m` the lcm of the moduli. Moduli need not be coprime,
+/// `None` when the congruences are inconsistent or a modulus is not positive.
+///
+/// Intermediate products are up to the square of a modulus, for primitive types keep the
+/// moduli below the square root of the type's range or use a big integer.
+pub fn crt<T>(congruences: &[(T, T)]) -> Option<(T, T)>
+where
+    T: ExtendedGcd + Clone + PartialOrd + Zero + One,
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Rem<Output = T>,
+{
+    let rem_euclid = |a: T, m: T| ((a % m.clone()) + m.clone()) % m;
+    let (mut x, mut m) = (T::zero(), T::one());
+    for (a, n) in congruences.iter().cloned() {
+        if n <= T::zero() {
+            return None;
+        }
+        let a = rem_euclid(a, n.clone());
+        let (g, p, _) = T::egcd(m.clone(), n.clone());
+        let diff = a - x.clone();
+        if diff.clone() % g.clone() != T::zero() {
+            return None;
+        }
+        // NOTE(lubo): `x + m * t` solves both for `t = diff / g * (m / g)^-1 (mod n / g)`.
+        let n = n / g.clone();
+        let t = rem_euclid(diff / g, n.clone()) * rem_euclid(p, n.clone()) % n.clone();
+        x = x + m.clone() * t;
+        m = m * n;
+        x = rem_euclid(x, m.clone());
+    }
+    Some((x, m))
+}
+
+/// Precomputed Montgomery reduction for an odd modulus, multiplication without division.
+///
+/// Values in Montgomery form are `x * 2^64 mod m`, convert with [`Montgomery::to_form`] and
+/// [`Montgomery::from_form`]. Sums and differences of such values work as usual modulo `m`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Montgomery {
+    modulus: u64,
+    /// `modulus^-1 mod 2^64`.
+    inverse: u64,
+    /// `2^128 mod modulus`.
+    r2: u64,
+}
+
+impl Montgomery {
+    /// Returns `None` for even moduli.
+    pub fn new(modulus: u64) -> Option<Self> {
+        if modulus.is_multiple_of(2) {
+            return None;
+        }
+        // NOTE(lubo): Newton iteration, every step doubles the number of correct low bits,
+        // `modulus` itself is correct to 3 bits.
+        let mut inverse = modulus;
+        for _ in 0..5 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(modulus.wrapping_mul(inverse)));
+        }
+        let r = (1u128 << 64) % modulus as u128;
+        let r2 = (r * r % modulus as u128) as u64;
+        Some(Self {
+            modulus,
+            inverse,
+            r2,
+        })
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// `t * 2^-64 mod m` for `t < m * 2^64`.
+    fn reduce(&self, t: u128) -> u64 {
+        let q = (t as u64).wrapping_mul(self.inverse);
+        let qm = q as u128 * self.modulus as u128;
+        // NOTE(lubo): The low halves of `t` and `q * m` agree, only the high halves remain.
+        let (hi, borrow) = ((t >> 64) as u64).overflowing_sub((qm >> 64) as u64);
+        if borrow {
+            hi.wrapping_add(self.modulus)
+        } else {
+            hi
+        }
+    }
+
+    pub fn to_form(&self, x: u64) -> u64 {
+        self.reduce((x % self.modulus) as u128 * self.r2 as u128)
+    }
+
+    pub fn from_form(&self, x: u64) -> u64 {
+        self.reduce(x as u128)
+    }
+
+    /// Product of two values in Montgomery form, in Montgomery form.
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        self.reduce(a as u128 * b as u128)
+    }
+
+    /// `base^exp mod m` for a `base` in normal form, the result is in normal form.
+    pub fn pow(&self, base: u64, mut exp: u64) -> u64 {
+        let mut base = self.to_form(base);
+        let mut result = self.to_form(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exp >>= 1;
+        }
+        self.from_form(result)
+    }
+}
+
+/// Modulus `0` means no modulus yet, see [`ModDyn`].
+fn reduce(value: u128, m: u64) -> u64 {
+    match m {
+        0 => value.try_into().expect("overflow without a modulus"),
+        m => (value % m as u128) as u64,
+    }
+}
+
+fn add_mod(a: u64, b: u64, m: u64) -> u64 {
+    reduce(a as u128 + b as u128, m)
+}
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    reduce(a as u128 * b as u128, m)
+}
+
+fn sub_mod(a: u64, b: u64, m: u64) -> u64 {
+    match m {
+        0 => a.checked_sub(b).expect("overflow without a modulus"),
+        m => reduce(a as u128 + m as u128 - (b % m) as u128, m),
+    }
+}
+
+fn neg_mod(a: u64, m: u64) -> u64 {
+    match (a, m) {
+        (0, _) => 0,
+        (_, 0) => panic!("cannot negate without a modulus"),
+        (a, m) => reduce(m as u128 - (a % m) as u128, m),
+    }
+}
+
+fn recip_mod(a: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return (a == 1).then_some(1);
+    }
+    let (g, x, _) = extended_gcd((a % m) as i128, m as i128);
+    (g == 1).then(|| x.rem_euclid(m as i128) as u64)
+}
+
+/// Integer modulo `N`, kept in `0..N`. `N` must be positive.
+///
+/// Division multiplies by [`Mod::recip`] and panics when the divisor is not invertible, like
+/// integer division by zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Mod<const N: u64>(u64);
+
+impl<const N: u64> Mod<N> {
+    pub const fn new(value: u64) -> Self {
+        Self(value % N)
+    }
+
+    pub const fn from_i64(value: i64) -> Self {
+        Self((value as i128).rem_euclid(N as i128) as u64)
+    }
+
+    pub const fn get(&self) -> u64 {
+        self.0
+    }
+
+    pub fn pow(&self, exp: u64) -> Self {
+        Self(mod_pow(self.0, exp, N))
+    }
+
+    /// Multiplicative inverse, `None` unless the value is coprime with `N`.
+    pub fn recip(&self) -> Option<Self> {
+        recip_mod(self.0, N).map(Self)
+    }
+
+    fn from_parts(value: u64, _modulus: u64) -> Self {
+        Self(value)
+    }
+
+    fn common_modulus(&self, _rhs: &Self) -> u64 {
+        N
+    }
+}
+
+impl<const N: u64> Display for Mod<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (mod {})", self.0, N)
+    }
+}
+
+impl<const N: u64> FromStr for Mod<N> {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value: i128 = s.trim().parse()?;
+        Ok(Self(value.rem_euclid(N as i128) as u64))
+    }
+}
+
+impl<const N: u64> From<bool> for Mod<N> {
+    fn from(value: bool) -> Self {
+        Self::new(value as u64)
+    }
+}
+
+impl<const N: u64> Zero for Mod<N> {
+    fn zero() -> Self {
+        Self(0)
+    }
+}
+
+impl<const N: u64> One for Mod<N> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const N: u64> IterateGroup for Mod<N> {
+    fn all() -> Vec<Self> {
+        (0..N).map(Self).collect()
+    }
+}
+
+/// Integer modulo a runtime modulus, see [`Mod`] for the const generic version.
+///
+/// [`Zero::zero`] and [`One::one`] do not know the modulus. They are created without one and
+/// take the modulus of the other operand in arithmetic. Mixing two different moduli panics.
+///
+/// Equality also compares the modulus, a value without one only equals another value without one.
+/// For the same reason there is no [`Group`] implementation, its identity could not know the
+/// modulus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModDyn {
+    value: u64,
+    modulus: u64,
+}
+
+impl ModDyn {
+    pub fn new(value: u64, modulus: u64) -> Self {
+        assert!(modulus > 0, "modulus must be positive");
+        Self::from_parts(value % modulus, modulus)
+    }
+
+    pub fn from_i64(value: i64, modulus: u64) -> Self {
+        assert!(modulus > 0, "modulus must be positive");
+        let value = (value as i128).rem_euclid(modulus as i128) as u64;
+        Self::from_parts(value, modulus)
+    }
+
+    pub fn get(&self) -> u64 {
+        self.value
+    }
+
+    /// `None` for values that have not met a modulus yet.
+    pub fn modulus(&self) -> Option<u64> {
+        (self.modulus > 0).then_some(self.modulus)
+    }
+
+    pub fn pow(&self, exp: u64) -> Self {
+        match self.modulus {
+            0 => self.pow_without_modulus(exp),
+            m => Self::new(mod_pow(self.value, exp, m), m),
+        }
+    }
+
+    /// Multiplicative inverse, `None` unless the value is coprime with the modulus.
+    pub fn recip(&self) -> Option<Self> {
+        recip_mod(self.value, self.modulus).map(|value| Self::from_parts(value, self.modulus))
+    }
+
+    fn pow_without_modulus(&self, exp: u64) -> Self {
+        let exp = u32::try_from(exp).expect("overflow without a modulus");
+        Self::from_parts(self.value.pow(exp), 0)
+    }
+
+    fn from_parts(value: u64, modulus: u64) -> Self {
+        Self { value, modulus }
+    }
+
+    fn common_modulus(&self, rhs: &Self) -> u64 {
+        match (self.modulus, rhs.modulus) {
+            (0, m) | (m, 0) => m,
+            (a, b) => {
+                assert_eq!(a, b, "values with different moduli");
+                a
+            }
+        }
+    }
+}
+
+impl Display for ModDyn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.modulus {
+            0 => write!(f, "{}", self.value),
+            m => write!(f, "{} (mod {})", self.value, m),
+        }
+    }
+}
+
+impl From<bool> for ModDyn {
+    fn from(value: bool) -> Self {
+        Self::from_parts(value as u64, 0)
+    }
+}
+
+impl Zero for ModDyn {
+    fn zero() -> Self {
+        Self::from_parts(0, 0)
+    }
+}
+
+impl One for ModDyn {
+    fn one() -> Self {
+        Self::from_parts(1, 0)
+    }
+}
+
+macro_rules! modular_operators {
+    ([$($g:tt)*] $t:ty) => {
+        impl<$($g)*> Add for $t {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                let m = self.common_modulus(&rhs);
+                Self::from_parts(add_mod(self.get(), rhs.get(), m), m)
+            }
+        }
+
+        impl<$($g)*> Sub for $t {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                let m = self.common_modulus(&rhs);
+                Self::from_parts(sub_mod(self.get(), rhs.get(), m), m)
+            }
+        }
+
+        impl<$($g)*> Mul for $t {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self {
+                let m = self.common_modulus(&rhs);
+                Self::from_parts(mul_mod(self.get(), rhs.get(), m), m)
+            }
+        }
+
+        impl<$($g)*> Div for $t {
+            type Output = Self;
+            fn div(self, rhs: Self) -> Self {
+                let m = self.common_modulus(&rhs);
+                let rhs = Self::from_parts(rhs.get(), m);
+                let recip = rhs.recip().expect("division by a value without an inverse");
+                Mul::mul(self, recip)
+            }
+        }
+
+        impl<$($g)*> Neg for $t {
+            type Output = Self;
+            fn neg(self) -> Self {
+                let m = self.common_modulus(&self);
+                Self::from_parts(neg_mod(self.get(), m), m)
+            }
+        }
+
+        impl<$($g)*> AddAssign for $t {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs
+            }
+        }
+
+        impl<$($g)*> SubAssign for $t {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs
+            }
+        }
+
+        impl<$($g)*> MulAssign for $t {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs
+            }
+        }
+    };
+}
+
+modular_operators!([const N: u64] Mod<N>);
+modular_operators!([] ModDyn);
+
+/// Residues under addition.
+impl<const N: u64> Group for Mod<N> {
+    fn identity() -> Self {
+        Self::zero()
+    }
+
+    fn op(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    fn inverse(self) -> Self {
+        -self
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Modi32, Modusize};
+    use std::collections::HashMap;
+
+    use super::{
+        Mod, ModDyn, Modi32, ModularMul, Modusize, Montgomery, MulMod, crt, extended_gcd,
+        mod_inverse, mod_pow, mod_pow_naive,
+    };
+    use crate::{
+        expr::Expr,
+        group::{Group, IterateGroup, Zn, cayley_table, is_isomorphism, order},
+        math::{One, Zero},
+        vector::V2,
+    };
 
     #[test]
     fn modular_test() {
@@ -213,4 +780,201 @@ mod tests {
         let b = Modusize(4);
         assert_eq!(a + b, Modusize(2));
     }
+
+    #[test]
+    fn modular_group() {
+        assert!(cayley_table(Modi32::<6>::all()).is_ok());
+        assert_eq!(Modi32::<6>::new(2).inverse(), Modi32(4));
+        assert_eq!(Modusize::<7>::identity().op(Modusize(3)), Modusize(3));
+        assert_eq!(order(Modusize::<12>(9)), 4);
+        assert!(is_isomorphism(|g: Zn<10>| Modusize::<10>::from(g)));
+        assert_eq!(Zn::from(Modusize::<10>(4)), Zn(4));
+    }
+
+    #[test]
+    fn bezout_and_inverse() {
+        for (a, b) in [(240, 46), (-240, 46), (17, 0), (0, -5), (0, 0), (12, 18)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert!(g >= 0);
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(extended_gcd(240, 46).0, 2);
+
+        assert_eq!(mod_inverse(3i32, 7), Some(5));
+        assert_eq!(mod_inverse(-3i32, 7), Some(2));
+        assert_eq!(mod_inverse(10usize, 17), Some(12));
+        assert_eq!(mod_inverse(4usize, 8), None);
+        assert_eq!(mod_inverse(5i64, 1), Some(0));
+        assert_eq!(mod_inverse(0i64, 13), None);
+
+        let m = 1_000_000_007i64;
+        let a = 123_456_789i64;
+        assert_eq!(a.mul_n(mod_inverse(a, m).unwrap(), m), 1);
+    }
+
+    #[test]
+    fn const_modulus() {
+        type M7 = Mod<7>;
+        let a = M7::new(12);
+        assert_eq!(a.get(), 5);
+        assert_eq!(M7::from_i64(-2), a);
+        assert_eq!(a + M7::new(3), M7::new(1));
+        assert_eq!(M7::new(1) - a, M7::new(3));
+        assert_eq!(-a, M7::new(2));
+        assert_eq!(a * a, M7::new(4));
+        assert_eq!(a.recip(), Some(M7::new(3)));
+        assert_eq!(M7::new(1) / a, M7::new(3));
+        assert_eq!(M7::zero().recip(), None);
+        assert_eq!(a.pow(6), M7::one());
+        assert_eq!("-9".parse::<M7>(), Ok(M7::new(5)));
+        assert_eq!(a.to_string(), "5 (mod 7)");
+
+        let big = Mod::<{ u64::MAX - 58 }>::new(u64::MAX - 60);
+        assert_eq!(big + big, Mod::new(u64::MAX - 62));
+        assert_eq!(big * big, Mod::new(4));
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+
+        let mut x = M7::new(3);
+        x *= x;
+        x -= M7::one();
+        x += M7::new(2);
+        assert_eq!(x, M7::new(3));
+    }
+
+    #[test]
+    fn const_modulus_interop() {
+        let v = V2::from_xy(Mod::<5>::new(3), Mod::new(4));
+        assert_eq!(v + v, V2::from_xy(Mod::new(1), Mod::new(3)));
+        assert_eq!(v.inner(v), Mod::new(0));
+
+        let no_vals = HashMap::new();
+        let expr = "3/2".parse::<Expr<Mod<11>>>().unwrap();
+        assert_eq!(expr.eval(&no_vals), Ok(Mod::new(7)));
+
+        assert!(cayley_table(Mod::<6>::all()).is_ok());
+        assert_eq!(order(Mod::<12>::new(8)), 3);
+    }
+
+    #[test]
+    fn runtime_modulus() {
+        let a = ModDyn::new(12, 7);
+        assert_eq!(a.get(), 5);
+        assert_eq!(a.modulus(), Some(7));
+        assert_eq!(ModDyn::from_i64(-2, 7), a);
+        assert_eq!(a * a + ModDyn::one(), ModDyn::new(5, 7));
+        assert_eq!(ModDyn::one() / a, ModDyn::new(3, 7));
+        assert_eq!((ModDyn::one() - a).modulus(), Some(7));
+        assert_eq!(a.pow(6), ModDyn::new(1, 7));
+        assert_eq!(a.recip(), Some(ModDyn::new(3, 7)));
+        assert_eq!(ModDyn::new(4, 8).recip(), None);
+        assert_eq!((ModDyn::one() + ModDyn::from(true)).modulus(), None);
+        assert_eq!((ModDyn::one() + ModDyn::one()) * a, ModDyn::new(3, 7));
+        assert_eq!(ModDyn::zero() + a, a);
+    }
+
+    #[test]
+    fn runtime_modulus_sub() {
+        assert_eq!(ModDyn::new(3, 7) - ModDyn::one(), ModDyn::new(2, 7));
+        assert_eq!(ModDyn::one() - ModDyn::new(3, 7), ModDyn::new(5, 7));
+        assert_eq!(ModDyn::new(3, 7) - ModDyn::new(3, 7), ModDyn::new(0, 7));
+        assert_eq!(ModDyn::one() - ModDyn::one(), ModDyn::zero());
+        assert_eq!(Mod::<7>::new(1) - Mod::new(3), Mod::new(5));
+    }
+
+    #[test]
+    fn runtime_modulus_eq() {
+        assert_ne!(ModDyn::new(1, 3), ModDyn::one());
+        assert_ne!(ModDyn::one(), ModDyn::new(1, 4));
+        assert_ne!(ModDyn::new(1, 3), ModDyn::new(1, 4));
+        assert_eq!(ModDyn::one(), ModDyn::from(true));
+        assert_eq!(ModDyn::new(8, 7), ModDyn::new(1, 7));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mixed_moduli() {
+        let _ = ModDyn::new(1, 3) + ModDyn::new(1, 4);
+    }
+
+    #[test]
+    fn montgomery() {
+        assert_eq!(Montgomery::new(10), None);
+        for m in [
+            1,
+            3,
+            7,
+            1_000_000_007,
+            (1 << 61) - 1,
+            u64::MAX,
+            u64::MAX - 58,
+        ] {
+            let montgomery = Montgomery::new(m).unwrap();
+            for x in [0, 1, 2, 12345, m - 1, u64::MAX] {
+                assert_eq!(montgomery.from_form(montgomery.to_form(x)), x % m);
+                let expected = (x as u128 * x as u128 % m as u128) as u64;
+                let square = montgomery.mul(montgomery.to_form(x), montgomery.to_form(x));
+                assert_eq!(montgomery.from_form(square), expected);
+                for exp in [0, 1, 2, 3, 1000, u64::MAX] {
+                    assert_eq!(montgomery.pow(x, exp), mod_pow_naive(x, exp, m));
+                }
+            }
+        }
+        assert_eq!(mod_pow(3, 1_000_000_006, 1_000_000_007), 1);
+        assert_eq!(mod_pow(3, 5, 16), 3);
+    }
+
+    #[test]
+    fn wide_types() {
+        let m = u128::MAX - 158;
+        assert_eq!((m - 1).mul_mod(m - 1, m), 1);
+        assert_eq!(3u128.pow_mod(m - 1, m), 1);
+        assert_eq!(2u128.pow_mod(127, u128::MAX), 1 << 127);
+        assert_eq!(5u64.pow_mod(3, 7), 6);
+        assert_eq!(mod_inverse(3i128, 1 << 100), Some((2i128.pow(101) + 1) / 3));
+        assert_eq!(mod_inverse(7u64, 10), Some(3));
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23i64, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9i32, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 10)]), Some((9i128, 10)));
+        assert_eq!(crt::<i64>(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1, 0)]), None::<(i32, i32)>);
+
+        let (p, q) = ((1i128 << 61) - 1, 1_000_000_007i128);
+        let x = 123_456_789_012_345_678_901_234i128;
+        assert_eq!(crt(&[(x % p, p), (x % q, q)]), Some((x % (p * q), p * q)));
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn bigint() {
+        use num_bigint::{BigInt, BigUint};
+
+        use super::MulMod;
+        use crate::math::{ExtendedGcd, Gcd};
+
+        let big = |s: &str| s.parse::<BigInt>().unwrap();
+        let a = big("340282366920938463463374607431768211507");
+        let b = big("1000000000000000000000000000057");
+        let (x, n) = crt(&[(big("3"), a.clone()), (big("5"), b.clone())]).unwrap();
+        assert_eq!(n, a.clone() * b.clone());
+        assert_eq!(x.clone() % a.clone(), big("3"));
+        assert_eq!(x % b.clone(), big("5"));
+
+        let (g, u, v) = BigInt::egcd(a.clone() * 6, b.clone() * 4);
+        assert_eq!(g, big("2"));
+        assert_eq!(a * 6 * u + b * 4 * v, g);
+        assert_eq!(
+            BigUint::lcm(BigUint::from(4u8), BigUint::from(6u8)),
+            BigUint::from(12u8)
+        );
+        assert_eq!(
+            BigUint::from(3u8).pow_mod(BigUint::from(100u8), BigUint::from(7u8)),
+            BigUint::from(4u8)
+        );
+    }
 }
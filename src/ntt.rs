@@ -0,0 +1,177 @@
+use super::modular::Mod;
+
+/// `119 * 2^23 + 1`, supports transforms of up to `2^23` values.
+pub const MODULUS: u64 = 998244353;
+const PRIMITIVE_ROOT: u64 = 3;
+
+pub type NttMod = Mod<MODULUS>;
+
+/// In-place number-theoretic transform modulo [`MODULUS`], the inverse one (scaled by `1 / n`)
+/// if `invert`. The length must be a power of two.
+pub fn ntt(values: &mut [NttMod], invert: bool) {
+    let n = values.len();
+    assert!(n.is_power_of_two(), "length must be a power of two");
+    assert!(
+        (MODULUS - 1).is_multiple_of(n as u64),
+        "too long for the modulus"
+    );
+
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let mut root = NttMod::new(PRIMITIVE_ROOT).pow((MODULUS - 1) / len as u64);
+        if invert {
+            root = root.recip().unwrap();
+        }
+        for chunk in values.chunks_mut(len) {
+            let (low, high) = chunk.split_at_mut(len / 2);
+            let mut w = NttMod::new(1);
+            for (a, b) in low.iter_mut().zip(high) {
+                let (u, v) = (*a, *b * w);
+                *a = u + v;
+                *b = u - v;
+                w *= root;
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let scale = NttMod::new(n as u64).recip().unwrap();
+        for x in values.iter_mut() {
+            *x *= scale;
+        }
+    }
+}
+
+/// Product of polynomials given by coefficients (lowest degree first), modulo [`MODULUS`].
+pub fn convolve_mod(a: &[NttMod], b: &[NttMod]) -> Vec<NttMod> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let result_len = a.len() + b.len() - 1;
+    if a.len().min(b.len()) <= 32 {
+        // NOTE(lubo): Schoolbook is faster for short inputs.
+        let mut result = vec![NttMod::new(0); result_len];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                result[i + j] += x * y;
+            }
+        }
+        return result;
+    }
+
+    let n = result_len.next_power_of_two();
+    let mut fa = a.to_vec();
+    let mut fb = b.to_vec();
+    fa.resize(n, NttMod::new(0));
+    fb.resize(n, NttMod::new(0));
+    ntt(&mut fa, false);
+    ntt(&mut fb, false);
+    for (x, y) in fa.iter_mut().zip(fb) {
+        *x *= y;
+    }
+    ntt(&mut fa, true);
+    fa.truncate(result_len);
+    fa
+}
+
+/// `result[k] = sum(a[i] * b[k - i])` modulo [`MODULUS`], exact while that stays below it.
+pub fn convolve(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let a: Vec<NttMod> = a.iter().map(|&x| NttMod::new(x)).collect();
+    let b: Vec<NttMod> = b.iter().map(|&x| NttMod::new(x)).collect();
+    convolve_mod(&a, &b).iter().map(|x| x.get()).collect()
+}
+
+/// Product of two non-negative decimal numbers, `None` if either contains a non-digit.
+pub fn multiply_decimal(a: &str, b: &str) -> Option<String> {
+    let digits = |s: &str| -> Option<Vec<u64>> {
+        s.bytes()
+            .rev()
+            .map(|c| c.is_ascii_digit().then(|| (c - b'0') as u64))
+            .collect()
+    };
+    let product = convolve(&digits(a)?, &digits(b)?);
+
+    let mut result = Vec::with_capacity(product.len() + 1);
+    let mut carry = 0;
+    for x in product {
+        let total = x + carry;
+        result.push((total % 10) as u8);
+        carry = total / 10;
+    }
+    while carry > 0 {
+        result.push((carry % 10) as u8);
+        carry /= 10;
+    }
+    while result.len() > 1 && result.last() == Some(&0) {
+        result.pop();
+    }
+    if result.is_empty() {
+        return Some("0".to_string());
+    }
+    Some(result.iter().rev().map(|&d| (b'0' + d) as char).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schoolbook(a: &[u64], b: &[u64]) -> Vec<u64> {
+        let mut result = vec![0; (a.len() + b.len()).saturating_sub(1)];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                result[i + j] = (result[i + j] + x * y) % MODULUS;
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn round_trip() {
+        let original: Vec<NttMod> = (0..16).map(|i| NttMod::new(i * i + 1)).collect();
+        let mut values = original.clone();
+        ntt(&mut values, false);
+        assert_ne!(values, original);
+        ntt(&mut values, true);
+        assert_eq!(values, original);
+    }
+
+    #[test]
+    fn convolution() {
+        assert_eq!(convolve(&[1, 2, 3], &[4, 5]), vec![4, 13, 22, 15]);
+        assert_eq!(convolve(&[], &[4, 5]), Vec::<u64>::new());
+
+        let a: Vec<u64> = (0..300).map(|i| (i * 7919) % 1000).collect();
+        let b: Vec<u64> = (0..257).map(|i| (i * 104729) % 1_000_000).collect();
+        assert_eq!(convolve(&a, &b), schoolbook(&a, &b));
+    }
+
+    #[test]
+    fn big_multiplication() {
+        assert_eq!(multiply_decimal("0", "12345").as_deref(), Some("0"));
+        assert_eq!(multiply_decimal("12", "").as_deref(), Some("0"));
+        assert_eq!(multiply_decimal("1x", "2"), None);
+        let a = "9".repeat(100);
+        let expected = format!("{}8{}1", "9".repeat(99), "0".repeat(99));
+        assert_eq!(multiply_decimal(&a, &a), Some(expected));
+        let x = 123456789012345678u128;
+        let y = 987654321098765432u128;
+        assert_eq!(
+            multiply_decimal(&x.to_string(), &y.to_string()),
+            Some((x * y).to_string())
+        );
+    }
+}
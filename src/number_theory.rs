@@ -0,0 +1,161 @@
+use std::ops::Mul;
+
+use crate::math::One;
+
+/// Prime factorization of `n` by trial division as `(prime, exponent)` pairs in increasing order.
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = vec![];
+    let mut p = 2;
+    while p <= n / p {
+        if n.is_multiple_of(p) {
+            let mut k = 0;
+            while n.is_multiple_of(p) {
+                n /= p;
+                k += 1;
+            }
+            factors.push((p, k));
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+/// Evaluate a multiplicative function given by its values `f(p, k)` on prime powers `p^k`.
+/// `f(1)` is one.
+pub fn multiplicative<T, F>(n: u64, f: F) -> T
+where
+    T: One + Mul<Output = T>,
+    F: Fn(u64, u32) -> T,
+{
+    factorize(n)
+        .into_iter()
+        .fold(T::one(), |acc, (p, k)| acc * f(p, k))
+}
+
+/// Smallest prime factor of every number below `n`, with `0` for `0` and `1`.
+pub fn smallest_prime_factors(n: usize) -> Vec<usize> {
+    let mut spf = vec![0; n];
+    for i in 2..n {
+        if spf[i] == 0 {
+            for j in (i..n).step_by(i) {
+                if spf[j] == 0 {
+                    spf[j] = i;
+                }
+            }
+        }
+    }
+    spf
+}
+
+/// Values of a multiplicative function for every number below `n` (index `0` holds `f(1)` as a
+/// placeholder), sharing one smallest prime factor sieve.
+pub fn multiplicative_sieve<T, F>(n: usize, f: F) -> Vec<T>
+where
+    T: One + Mul<Output = T> + Copy,
+    F: Fn(u64, u32) -> T,
+{
+    let spf = smallest_prime_factors(n);
+    let mut values = vec![T::one(); n];
+    for i in 2..n {
+        let p = spf[i];
+        let mut rest = i;
+        let mut k = 0;
+        while rest.is_multiple_of(p) {
+            rest /= p;
+            k += 1;
+        }
+        values[i] = values[rest] * f(p as u64, k);
+    }
+    values
+}
+
+fn phi_prime_power(p: u64, k: u32) -> u64 {
+    p.pow(k - 1) * (p - 1)
+}
+
+fn mobius_prime_power(_p: u64, k: u32) -> i8 {
+    if k == 1 { -1 } else { 0 }
+}
+
+/// Euler's totient, the count of `1 <= k <= n` coprime to `n`. `euler_phi(0)` is `0`.
+pub fn euler_phi(n: u64) -> u64 {
+    match n {
+        0 => 0,
+        n => multiplicative(n, phi_prime_power),
+    }
+}
+
+/// [`euler_phi`] for every number below `n`.
+pub fn euler_phi_sieve(n: usize) -> Vec<u64> {
+    let mut values = multiplicative_sieve(n, phi_prime_power);
+    if let Some(zero) = values.first_mut() {
+        *zero = 0;
+    }
+    values
+}
+
+/// Möbius function, `0` if `n` has a squared prime factor, otherwise `(-1)^k` for `k` distinct
+/// prime factors. `mobius(0)` is `0`.
+pub fn mobius(n: u64) -> i8 {
+    match n {
+        0 => 0,
+        n => multiplicative(n, mobius_prime_power),
+    }
+}
+
+/// [`mobius`] for every number below `n`.
+pub fn mobius_sieve(n: usize) -> Vec<i8> {
+    let mut values = multiplicative_sieve(n, mobius_prime_power);
+    if let Some(zero) = values.first_mut() {
+        *zero = 0;
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{euler_phi, euler_phi_sieve, factorize, mobius, mobius_sieve, multiplicative};
+    use crate::math::Gcd;
+
+    #[test]
+    fn test_factorize() {
+        assert_eq!(factorize(1), vec![]);
+        assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(factorize(1_000_000_007), vec![(1_000_000_007, 1)]);
+        // NOTE(lubo): Number of divisors is multiplicative too.
+        assert_eq!(multiplicative(360, |_, k| k as u64 + 1), 24);
+    }
+
+    #[test]
+    fn test_euler_phi() {
+        let phi = euler_phi_sieve(200);
+        for n in 0..200u64 {
+            let coprime = (1..=n).filter(|&k| u64::gcd(k, n) == 1).count() as u64;
+            assert_eq!(euler_phi(n), coprime);
+            assert_eq!(phi[n as usize], coprime);
+        }
+        assert_eq!(euler_phi(1_000_000_007), 1_000_000_006);
+        assert!(euler_phi_sieve(0).is_empty());
+    }
+
+    #[test]
+    fn test_mobius() {
+        assert_eq!(
+            mobius_sieve(13),
+            vec![0, 1, -1, -1, 0, -1, 1, -1, 0, 0, 1, -1, 0]
+        );
+        let mu = mobius_sieve(500);
+        for n in 1..500u64 {
+            assert_eq!(mobius(n), mu[n as usize]);
+            // NOTE(lubo): Sum of the Möbius function over the divisors of n vanishes for n > 1.
+            let sum: i32 = (1..=n)
+                .filter(|d| n.is_multiple_of(*d))
+                .map(|d| mobius(d) as i32)
+                .sum();
+            assert_eq!(sum, (n == 1) as i32);
+        }
+    }
+}
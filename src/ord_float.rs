@@ -1,12 +1,21 @@
 use std::{
     fmt::Display,
+    hash::{Hash, Hasher},
     iter::Sum,
-    ops::{Add, Deref, DerefMut, Div, Mul, Neg, Sub},
+    ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
 };
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::math::{AbsoluteValue, Bounded, Float, One, Signed, Zero};
+
 macro_rules! create_ord_float {
     ($wrapper:ident, $inner:ty, $inner_bits:ty) => {
+        /// Serializes as the plain float.
         #[derive(Debug, Clone, Copy)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
         pub struct $wrapper(pub $inner);
 
         impl Deref for $wrapper {
@@ -45,9 +54,55 @@ macro_rules! create_ord_float {
             pub const INFINITY: Self = Self(<$inner>::INFINITY);
             pub const NEG_INFINITY: Self = Self(<$inner>::NEG_INFINITY);
 
+            pub fn new_checked(value: $inner) -> Result<Self, NanError> {
+                if value.is_nan() {
+                    Err(NanError)
+                } else {
+                    Ok(Self(value))
+                }
+            }
+
             fn bits(&self) -> $inner_bits {
                 self.0.to_bits() as $inner_bits
             }
+
+            pub fn sqrt(self) -> Self {
+                Self(self.0.sqrt())
+            }
+
+            pub fn abs(self) -> Self {
+                Self(self.0.abs())
+            }
+
+            pub fn floor(self) -> Self {
+                Self(self.0.floor())
+            }
+
+            pub fn ceil(self) -> Self {
+                Self(self.0.ceil())
+            }
+
+            pub fn powi(self, n: i32) -> Self {
+                Self(self.0.powi(n))
+            }
+
+            /// Smaller in the total order, unlike the float `min` a NaN can win.
+            pub fn min(self, other: Self) -> Self {
+                Ord::min(self, other)
+            }
+
+            /// Larger in the total order, unlike the float `max` a NaN can win.
+            pub fn max(self, other: Self) -> Self {
+                Ord::max(self, other)
+            }
+        }
+
+        impl FromStr for $wrapper {
+            type Err = <$inner as FromStr>::Err;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse().map(Self)
+            }
         }
 
         impl PartialEq for $wrapper {
@@ -64,9 +119,17 @@ macro_rules! create_ord_float {
             }
         }
 
+        /// IEEE 754 total order, see [`f64::total_cmp`]. `-0.0 < 0.0` and NaNs sort past the
+        /// infinities by sign, consistent with the bitwise [`PartialEq`].
         impl Ord for $wrapper {
             fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-                self.bits().cmp(&other.bits())
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Hash for $wrapper {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.bits().hash(state);
             }
         }
 
@@ -76,6 +139,12 @@ macro_rules! create_ord_float {
             }
         }
 
+        impl<'a> Sum<&'a $wrapper> for $wrapper {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                Self(iter.map(|x| x.0).sum())
+            }
+        }
+
         impl Add for $wrapper {
             type Output = Self;
 
@@ -115,8 +184,322 @@ macro_rules! create_ord_float {
                 Self(-self.0)
             }
         }
+
+        impl AddAssign for $wrapper {
+            fn add_assign(&mut self, other: Self) {
+                self.0 += other.0;
+            }
+        }
+
+        impl SubAssign for $wrapper {
+            fn sub_assign(&mut self, other: Self) {
+                self.0 -= other.0;
+            }
+        }
+
+        impl MulAssign for $wrapper {
+            fn mul_assign(&mut self, other: Self) {
+                self.0 *= other.0;
+            }
+        }
+
+        impl DivAssign for $wrapper {
+            fn div_assign(&mut self, other: Self) {
+                self.0 /= other.0;
+            }
+        }
+
+        impl Zero for $wrapper {
+            fn zero() -> Self {
+                Self(0.0)
+            }
+        }
+
+        impl One for $wrapper {
+            fn one() -> Self {
+                Self(1.0)
+            }
+        }
+
+        impl AbsoluteValue for $wrapper {
+            fn abs(&self) -> Option<Self> {
+                Some(Self(self.0.abs()))
+            }
+        }
+
+        impl Signed for $wrapper {
+            fn is_negative(&self) -> bool {
+                self.0 < 0.0
+            }
+            fn is_positive(&self) -> bool {
+                self.0 > 0.0
+            }
+            fn signum(&self) -> Self {
+                Self(self.0.signum())
+            }
+        }
+
+        impl Bounded for $wrapper {
+            fn min_value() -> Self {
+                Self(<$inner>::MIN)
+            }
+            fn max_value() -> Self {
+                Self(<$inner>::MAX)
+            }
+        }
+        impl Float for $wrapper {
+            fn epsilon() -> Self {
+                Self(<$inner>::EPSILON)
+            }
+            fn sqrt(&self) -> Self {
+                Self(self.0.sqrt())
+            }
+        }
     };
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NanError;
+
+impl Display for NanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The value is NaN.")
+    }
+}
+
+impl std::error::Error for NanError {}
+
 create_ord_float!(OrdF32, f32, i32);
 create_ord_float!(OrdF64, f64, i64);
+
+macro_rules! create_not_nan {
+    ($wrapper:ident, $ord:ident, $inner:ty, $inner_name:literal) => {
+        /// Float that is never NaN, arithmetic that would produce one returns [`NanError`].
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "serde", serde(try_from = $inner_name, into = $inner_name))]
+        pub struct $wrapper($ord);
+
+        impl $wrapper {
+            pub const INFINITY: Self = Self($ord::INFINITY);
+            pub const NEG_INFINITY: Self = Self($ord::NEG_INFINITY);
+
+            pub fn new(value: $inner) -> Result<Self, NanError> {
+                $ord::new_checked(value).map(Self)
+            }
+
+            pub fn get(self) -> $inner {
+                self.0.0
+            }
+        }
+
+        impl Deref for $wrapper {
+            type Target = $inner;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0.0
+            }
+        }
+
+        impl Display for $wrapper {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl TryFrom<$inner> for $wrapper {
+            type Error = NanError;
+
+            fn try_from(value: $inner) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl TryFrom<$ord> for $wrapper {
+            type Error = NanError;
+
+            fn try_from(value: $ord) -> Result<Self, Self::Error> {
+                Self::new(value.0)
+            }
+        }
+
+        impl From<$wrapper> for $ord {
+            fn from(value: $wrapper) -> Self {
+                value.0
+            }
+        }
+
+        impl From<$wrapper> for $inner {
+            fn from(value: $wrapper) -> Self {
+                value.get()
+            }
+        }
+
+        impl Add for $wrapper {
+            type Output = Result<Self, NanError>;
+
+            fn add(self, other: Self) -> Self::Output {
+                Self::new(self.get() + other.get())
+            }
+        }
+
+        impl Sub for $wrapper {
+            type Output = Result<Self, NanError>;
+
+            fn sub(self, other: Self) -> Self::Output {
+                Self::new(self.get() - other.get())
+            }
+        }
+
+        impl Mul for $wrapper {
+            type Output = Result<Self, NanError>;
+
+            fn mul(self, other: Self) -> Self::Output {
+                Self::new(self.get() * other.get())
+            }
+        }
+
+        impl Div for $wrapper {
+            type Output = Result<Self, NanError>;
+
+            fn div(self, other: Self) -> Self::Output {
+                Self::new(self.get() / other.get())
+            }
+        }
+
+        impl Neg for $wrapper {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+    };
+}
+
+create_not_nan!(NotNanF32, OrdF32, f32, "f32");
+create_not_nan!(NotNanF64, OrdF64, f64, "f64");
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{NanError, NotNanF32, NotNanF64, OrdF32, OrdF64};
+    use crate::{
+        geometric_traits::{EuclideanDistanceSquared, ManhattanDistance},
+        interval_set::IntervalSet,
+        math::{Num, Zero},
+        vector::Vector,
+    };
+
+    #[test]
+    fn total_order() {
+        let mut values: Vec<_> = [3.0, -1.5, 0.0, -0.0, f64::INFINITY, -2.0, f64::NEG_INFINITY]
+            .into_iter()
+            .map(OrdF64)
+            .collect();
+        values.sort();
+        let sorted: Vec<_> = values.iter().map(|x| x.0.to_bits()).collect();
+        let expected: Vec<_> = [f64::NEG_INFINITY, -2.0, -1.5, -0.0, 0.0, 3.0, f64::INFINITY]
+            .iter()
+            .map(|x| x.to_bits())
+            .collect();
+        assert_eq!(sorted, expected);
+
+        assert!(OrdF32(-0.0) < OrdF32(0.0));
+        assert!(OrdF32(f32::NAN) > OrdF32::INFINITY);
+        assert!(OrdF32(-f32::NAN) < OrdF32::NEG_INFINITY);
+        assert_eq!(OrdF32(f32::NAN), OrdF32(f32::NAN));
+    }
+
+    #[test]
+    fn hash_matches_eq() {
+        let set: HashSet<_> = [0.0, -0.0, 0.0, 1.5, f64::NAN, f64::NAN]
+            .into_iter()
+            .map(OrdF64)
+            .collect();
+        assert_eq!(set.len(), 4);
+        assert!(set.contains(&OrdF64(-0.0)));
+    }
+
+    #[test]
+    fn as_scalar() {
+        fn norm<T: Num>(x: T, y: T) -> T {
+            x * x + y * y
+        }
+        assert_eq!(norm(OrdF32(3.0), OrdF32(4.0)), OrdF32(25.0));
+
+        let a = Vector::new([OrdF64(1.0), OrdF64(-2.0)]);
+        let b = Vector::new([OrdF64(4.0), OrdF64(2.0)]);
+        assert_eq!(a.manhattan_distance(&b), OrdF64(7.0));
+        assert_eq!(a.euclidean_distance_squared(&b), OrdF64(25.0));
+        assert_eq!(Vector::<2, OrdF64>::zero(), Vector::all(OrdF64(0.0)));
+        let mut c = a;
+        c += b;
+        assert_eq!(c, Vector::new([OrdF64(5.0), OrdF64(0.0)]));
+
+        let mut set = IntervalSet::new();
+        set.union(OrdF64(0.0)..OrdF64(1.5));
+        set.union(OrdF64(2.0)..OrdF64(2.25));
+        assert_eq!(set.measure(), OrdF64(1.75));
+    }
+
+    #[test]
+    fn passthroughs() {
+        let x = OrdF64(-2.25);
+        assert_eq!(x.abs().sqrt(), OrdF64(1.5));
+        assert_eq!(x.floor(), OrdF64(-3.0));
+        assert_eq!(x.ceil(), OrdF64(-2.0));
+        assert_eq!(x.powi(2), OrdF64(5.0625));
+        assert_eq!(x.min(OrdF64(1.0)), x);
+        assert_eq!(x.max(OrdF64(1.0)), OrdF64(1.0));
+        assert_eq!(OrdF64(f64::NAN).max(OrdF64::INFINITY), OrdF64(f64::NAN));
+
+        assert_eq!("0.5".parse::<OrdF32>(), Ok(OrdF32(0.5)));
+        assert!("half".parse::<OrdF32>().is_err());
+
+        let values = [OrdF32(0.5), OrdF32(1.0), OrdF32(2.0)];
+        assert_eq!(values.iter().sum::<OrdF32>(), OrdF32(3.5));
+    }
+
+    #[test]
+    fn not_nan() {
+        assert_eq!(OrdF64::new_checked(f64::NAN), Err(NanError));
+        assert_eq!(OrdF64::new_checked(1.0), Ok(OrdF64(1.0)));
+
+        let one = NotNanF64::new(1.0).unwrap();
+        let two = NotNanF64::new(2.0).unwrap();
+        assert_eq!((one + two).map(NotNanF64::get), Ok(3.0));
+        assert_eq!((one / two).unwrap(), NotNanF64::new(0.5).unwrap());
+        assert_eq!(*-one, -1.0);
+        assert!(one < two);
+
+        let inf = NotNanF32::INFINITY;
+        assert_eq!(inf - inf, Err(NanError));
+        assert_eq!(inf * NotNanF32::new(0.0).unwrap(), Err(NanError));
+        assert_eq!(NotNanF32::try_from(OrdF32(f32::NAN)), Err(NanError));
+        assert_eq!(
+            OrdF32::from(NotNanF32::try_from(0.25).unwrap()),
+            OrdF32(0.25)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let json = serde_json::to_string(&OrdF64(1.5)).unwrap();
+        assert_eq!(json, "1.5");
+        assert_eq!(serde_json::from_str::<OrdF64>(&json).unwrap(), OrdF64(1.5));
+
+        let values = vec![OrdF32(-0.25), OrdF32(3.0)];
+        let json = serde_json::to_string(&values).unwrap();
+        assert_eq!(json, "[-0.25,3.0]");
+        assert_eq!(serde_json::from_str::<Vec<OrdF32>>(&json).unwrap(), values);
+
+        let value = NotNanF64::new(2.5).unwrap();
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, "2.5");
+        assert_eq!(serde_json::from_str::<NotNanF64>(&json).unwrap(), value);
+        assert!(serde_json::from_str::<NotNanF32>("null").is_err());
+    }
+}
@@ -1,10 +1,111 @@
-use std::ops::Mul;
+use std::{fmt::Display, ops::Mul};
 
-#[derive(Debug, PartialEq, Eq)]
+use super::{
+    group::{
+        action::GroupAction,
+        group::{Group, IterateGroup},
+    },
+    math::Gcd,
+};
+
+/// Permutation of `0..M` in one-line notation, sends `i` to `self.values()[i]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Perm<const M: usize>([usize; M]);
 
 impl<const M: usize> Perm<M> {
-    fn chain(&self, other: &Self) -> Self
+    pub fn identity() -> Self {
+        Self(std::array::from_fn(|i| i))
+    }
+
+    /// Returns `None` unless every value in `0..M` appears exactly once.
+    pub fn from_array(values: [usize; M]) -> Option<Self> {
+        let mut seen = [false; M];
+        for x in values {
+            if x >= M || seen[x] {
+                return None;
+            }
+            seen[x] = true;
+        }
+        Some(Self(values))
+    }
+
+    pub fn values(&self) -> &[usize; M] {
+        &self.0
+    }
+
+    /// Where `i` is sent.
+    pub fn apply(&self, i: usize) -> usize {
+        self.0[i]
+    }
+
+    pub fn inverse(&self) -> Self {
+        let mut result = [0; M];
+        for (i, x) in self.0.iter().enumerate() {
+            result[*x] = i;
+        }
+        Self(result)
+    }
+
+    /// Disjoint cycles of length at least two, each starting with its smallest element, ordered
+    /// by that element. `(0 2 1)` sends 0 to 2, 2 to 1 and 1 to 0.
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut visited = [false; M];
+        let mut cycles = vec![];
+        for start in 0..M {
+            if visited[start] || self.0[start] == start {
+                continue;
+            }
+            let mut cycle = vec![];
+            let mut i = start;
+            while !visited[i] {
+                visited[i] = true;
+                cycle.push(i);
+                i = self.0[i];
+            }
+            cycles.push(cycle);
+        }
+        cycles
+    }
+
+    /// Inverse of [`Perm::cycles`], returns `None` when the cycles are not disjoint or an element
+    /// is out of range. Elements not mentioned stay in place.
+    pub fn from_cycles<C: AsRef<[usize]>>(cycles: &[C]) -> Option<Self> {
+        let mut values: [usize; M] = std::array::from_fn(|i| i);
+        let mut seen = [false; M];
+        for cycle in cycles.iter() {
+            let cycle = cycle.as_ref();
+            for (i, x) in cycle.iter().enumerate() {
+                if *x >= M || seen[*x] {
+                    return None;
+                }
+                seen[*x] = true;
+                values[*x] = cycle[(i + 1) % cycle.len()];
+            }
+        }
+        Some(Self(values))
+    }
+
+    /// `self` applied `n` times, each cycle is rotated by `n` modulo its length.
+    pub fn pow(&self, n: u128) -> Self {
+        let mut values = self.0;
+        for cycle in self.cycles() {
+            let shift = (n % cycle.len() as u128) as usize;
+            for (i, x) in cycle.iter().enumerate() {
+                values[*x] = cycle[(i + shift) % cycle.len()];
+            }
+        }
+        Self(values)
+    }
+
+    /// Smallest positive `n` with `self.pow(n)` the identity, the lcm of the cycle lengths.
+    pub fn order(&self) -> usize {
+        self.cycles()
+            .iter()
+            .fold(1, |order, cycle| usize::lcm(order, cycle.len()))
+    }
+
+    /// Composition applying `other` first, `a.chain(&b).apply(i) == a.apply(b.apply(i))`.
+    pub fn chain(&self, other: &Self) -> Self
     where
         Self: Sized,
     {
@@ -27,8 +128,70 @@ impl<const M: usize> Mul for Perm<M> {
     }
 }
 
-#[derive(Debug)]
-struct PermId<const M: usize>(usize);
+/// Symmetric group, `a.op(b)` applies `b` first like [`Perm::chain`].
+impl<const M: usize> Group for Perm<M> {
+    fn identity() -> Self {
+        Perm::identity()
+    }
+
+    fn op(self, rhs: Self) -> Self {
+        self.chain(&rhs)
+    }
+
+    fn inverse(self) -> Self {
+        Perm::inverse(&self)
+    }
+}
+
+/// Cycle notation, `(0 2 1)(3 4)`, the identity is `()`.
+impl<const M: usize> Display for Perm<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let cycles = self.cycles();
+        if cycles.is_empty() {
+            return write!(f, "()");
+        }
+        for cycle in cycles {
+            write!(f, "(")?;
+            for (i, x) in cycle.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{x}")?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+impl<const M: usize> TryFrom<[usize; M]> for Perm<M> {
+    type Error = [usize; M];
+
+    fn try_from(value: [usize; M]) -> Result<Self, Self::Error> {
+        Self::from_array(value).ok_or(value)
+    }
+}
+
+impl<const M: usize> IterateGroup for Perm<M> {
+    fn all() -> Vec<Self> {
+        (0..(1..=M).product()).map(|id| PermId(id).perm()).collect()
+    }
+}
+
+/// Action of permutations on the indices `0..M`, `p` sends `i` to `p.0[i]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct OnIndices;
+
+impl<const M: usize> GroupAction<Perm<M>, usize> for OnIndices {
+    fn act(&self, g: &Perm<M>, x: &usize) -> usize {
+        g.0[*x]
+    }
+}
+
+/// Rank of a permutation among all `M!` permutations in lexicographic order (its Lehmer code
+/// read as a factoradic number), usable as an array index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PermId<const M: usize>(pub usize);
 
 impl<const M: usize> PermId<M> {
     pub fn perm(self) -> Perm<M> {
@@ -57,14 +220,23 @@ impl<const M: usize> From<PermId<M>> for Perm<M> {
 }
 
 impl<const M: usize> From<Perm<M>> for PermId<M> {
-    fn from(_value: Perm<M>) -> Self {
-        todo!()
+    fn from(value: Perm<M>) -> Self {
+        // NOTE(lubo): Digit `i` of the Lehmer code counts the smaller values right of position
+        // `i`, it has weight `(M - 1 - i)!`.
+        let id = (0..M).fold(0, |id, i| {
+            let smaller = value.0[i + 1..].iter().filter(|x| **x < value.0[i]).count();
+            id * (M - i) + smaller
+        });
+        Self(id)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Perm, PermId};
+    use super::{OnIndices, Perm, PermId};
+    use crate::group::{
+        Dn, Group, IterateGroup, action::GroupAction, cayley_table, find_isomorphism, generate,
+    };
 
     #[test]
     fn test1() {
@@ -118,6 +290,90 @@ mod tests {
         assert_eq!(e, r.chain(&r).chain(&r));
     }
 
+    #[test]
+    fn lehmer_round_trip() {
+        assert_eq!(PermId::from(Perm([2, 0, 1])), PermId::<3>(4));
+        assert_eq!(PermId::from(Perm([3, 2, 1, 0])), PermId::<4>(23));
+        assert_eq!(PermId::from(Perm::<0>([])), PermId(0));
+
+        for id in 0..720 {
+            let perm = PermId::<6>(id).perm();
+            assert_eq!(PermId::from(perm), PermId(id));
+        }
+
+        let mut seen = [false; 24];
+        for perm in Perm::<4>::all() {
+            let id = PermId::from(perm);
+            assert!(!seen[id.0]);
+            seen[id.0] = true;
+            assert_eq!(Perm::from(id), perm);
+        }
+    }
+
+    #[test]
+    fn construction() {
+        assert_eq!(Perm::from_array([2, 0, 1]), Some(Perm([2, 0, 1])));
+        assert_eq!(Perm::from_array([0, 0, 1]), None);
+        assert_eq!(Perm::from_array([0, 3, 1]), None);
+        assert_eq!(Perm::try_from([1, 1]), Err([1, 1]));
+        assert_eq!(Perm::<0>::from_array([]), Some(Perm::identity()));
+        assert_eq!(Perm::<4>::identity().values(), &[0, 1, 2, 3]);
+
+        let p = Perm::from_array([3, 0, 2, 1]).unwrap();
+        assert_eq!(p.apply(0), 3);
+        assert_eq!(p.inverse(), Perm([1, 3, 2, 0]));
+        assert_eq!(p.chain(&p.inverse()), Perm::identity());
+        assert_eq!(p.inverse().apply(p.apply(1)), 1);
+        assert_eq!(p.chain(&p).apply(0), p.apply(p.apply(0)));
+    }
+
+    #[test]
+    fn cycle_notation() {
+        let p = Perm([2, 0, 1, 4, 3, 5]);
+        assert_eq!(p.cycles(), vec![vec![0, 2, 1], vec![3, 4]]);
+        assert_eq!(p.to_string(), "(0 2 1)(3 4)");
+        assert_eq!(Perm::from_cycles(&[vec![0, 2, 1], vec![3, 4]]), Some(p));
+        assert_eq!(
+            Perm::from_cycles(&[[4, 3], [1, 0]]),
+            Some(Perm([1, 0, 2, 4, 3, 5]))
+        );
+        assert_eq!(Perm::<6>::from_cycles(&[[0, 1], [1, 2]]), None);
+        assert_eq!(Perm::<3>::from_cycles(&[[0, 3]]), None);
+        assert_eq!(
+            Perm::<3>::from_cycles::<[usize; 0]>(&[]),
+            Some(Perm::identity())
+        );
+        assert_eq!(Perm::<3>::identity().to_string(), "()");
+
+        for perm in Perm::<5>::all() {
+            assert_eq!(Perm::from_cycles(&perm.cycles()), Some(perm));
+        }
+    }
+
+    #[test]
+    fn power_and_order() {
+        let p = Perm::<9>::from_cycles(&[vec![0, 1, 2], vec![3, 4], vec![5, 6, 7, 8]]).unwrap();
+        assert_eq!(p.order(), 12);
+        assert_eq!(Perm::<9>::identity().order(), 1);
+        assert_eq!(p.pow(0), Perm::identity());
+        assert_eq!(p.pow(12), Perm::identity());
+        assert_eq!(p.pow(1), p);
+        assert_eq!(p.pow(11), p.inverse());
+
+        let mut q = Perm::identity();
+        for n in 0..30 {
+            assert_eq!(p.pow(n), q);
+            q = p.chain(&q);
+        }
+
+        let n = 10u128.pow(18) + 7;
+        assert_eq!(p.pow(n), p.pow(n % 12));
+
+        for perm in Perm::<5>::all() {
+            assert_eq!(perm.order(), crate::group::order(perm));
+        }
+    }
+
     #[test]
     #[should_panic]
     fn test1_oob() {
@@ -135,4 +391,32 @@ mod tests {
     fn test3_oob() {
         PermId::<3>(6).perm();
     }
+
+    #[test]
+    fn index_action() {
+        let elements = [Perm([1, 2, 0, 3]), Perm([1, 0, 2, 3])];
+        let (r, f) = (&elements[0], &elements[1]);
+        assert_eq!(OnIndices.act(r, &0), 1);
+        assert_eq!(OnIndices.act(&(*r * *f), &0), OnIndices.act(r, &1));
+        assert_eq!(OnIndices.orbit(&elements[..1], &0), vec![0, 1, 2]);
+        assert_eq!(OnIndices.orbit(&elements, &3), vec![3]);
+        assert_eq!(OnIndices.stabilizer(&elements, &2), vec![*f]);
+    }
+
+    #[test]
+    fn symmetric_group() {
+        let all = Perm::<4>::all();
+        assert_eq!(all.len(), 24);
+        assert_eq!(all[0], Perm::identity());
+        assert!(cayley_table(all).is_ok());
+
+        let r = Perm([1, 2, 0]);
+        assert_eq!(r.inverse(), Perm([2, 0, 1]));
+        assert_eq!(r.op(r.inverse()), Perm::identity());
+        assert_eq!(
+            generate(&[Perm([1, 2, 3, 0]), Perm([1, 0, 2, 3])]).len(),
+            24
+        );
+        assert!(find_isomorphism::<Perm<3>, Dn<3>>().is_some());
+    }
 }
@@ -0,0 +1,258 @@
+use std::ops::{Add, Mul, Sub};
+
+use super::{
+    line::Line,
+    math::{Gcd, Zero},
+    transformations::Transform,
+    vector::V2,
+};
+
+/// Closed polygon, the last point connects back to the first.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon<V> {
+    pub points: Vec<V>,
+}
+
+impl<V> Polygon<V> {
+    pub fn new(points: Vec<V>) -> Self {
+        Self { points }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+}
+
+impl<V> Default for Polygon<V> {
+    fn default() -> Self {
+        Self::new(vec![])
+    }
+}
+
+impl<V> FromIterator<V> for Polygon<V> {
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<V: Copy> Polygon<V> {
+    pub fn transform<X: Transform<V>>(&mut self, transform: &X) {
+        transform.apply_in_place(&mut self.points);
+    }
+
+    pub fn transformed<X: Transform<V>>(&self, transform: &X) -> Self {
+        let mut result = self.clone();
+        result.transform(transform);
+        result
+    }
+
+    /// All sides, including the closing one from the last point to the first.
+    pub fn edges(&self) -> impl Iterator<Item = Line<V>> + '_ {
+        let n = self.points.len();
+        (0..n).map(move |i| Line::new(self.points[i], self.points[(i + 1) % n]))
+    }
+}
+
+impl<T> Polygon<V2<T>>
+where
+    T: Copy + PartialOrd + Zero,
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Shoelace formula, twice the area, positive for counterclockwise polygons. Exact for
+    /// integers.
+    pub fn doubled_signed_area(&self) -> T {
+        self.edges()
+            .fold(T::zero(), |acc, e| acc + e.start.wedge(e.end).signed_area())
+    }
+
+    fn side(e: &Line<V2<T>>, p: &V2<T>) -> T {
+        (e.end - e.start).wedge(*p - e.start).signed_area()
+    }
+
+    /// `p` lies on one of the sides.
+    pub fn on_boundary(&self, p: &V2<T>) -> bool {
+        self.edges().any(|e| {
+            let within = |a: T, b: T, x: T| (a <= x && x <= b) || (b <= x && x <= a);
+            Self::side(&e, p) == T::zero()
+                && within(e.start.x(), e.end.x(), p.x())
+                && within(e.start.y(), e.end.y(), p.y())
+        })
+    }
+
+    /// How many times the boundary winds counterclockwise around `p`. Points on the boundary
+    /// give unspecified results, check [`Polygon::on_boundary`] first.
+    pub fn winding_number(&self, p: &V2<T>) -> i32 {
+        let mut winding = 0;
+        for e in self.edges() {
+            if e.start.y() <= p.y() {
+                if e.end.y() > p.y() && Self::side(&e, p) > T::zero() {
+                    winding += 1;
+                }
+            } else if e.end.y() <= p.y() && Self::side(&e, p) < T::zero() {
+                winding -= 1;
+            }
+        }
+        winding
+    }
+
+    /// Nonzero rule, boundary points included.
+    pub fn contains_winding(&self, p: &V2<T>) -> bool {
+        self.on_boundary(p) || self.winding_number(p) != 0
+    }
+
+    /// Even-odd rule by casting a ray towards `+x`, boundary points included. Differs from
+    /// [`Polygon::contains_winding`] only for self-intersecting polygons.
+    pub fn contains_ray_casting(&self, p: &V2<T>) -> bool {
+        if self.on_boundary(p) {
+            return true;
+        }
+        let mut inside = false;
+        for e in self.edges() {
+            if (e.start.y() > p.y()) != (e.end.y() > p.y()) {
+                // NOTE(lubo): The crossing is right of `p` when `p` is left of the upward edge.
+                let side = Self::side(&e, p);
+                let upward = e.end.y() > e.start.y();
+                if (side > T::zero()) == upward {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+}
+
+macro_rules! float_polygon {
+    ($($t:ty),*) => {
+        $(
+        impl Polygon<V2<$t>> {
+            pub fn area(&self) -> $t {
+                self.doubled_signed_area().abs() / 2.0
+            }
+
+            pub fn perimeter(&self) -> $t {
+                self.edges()
+                    .map(|e| {
+                        let delta = e.end - e.start;
+                        delta.inner(delta).sqrt()
+                    })
+                    .sum()
+            }
+        })*
+    };
+}
+
+macro_rules! integer_polygon {
+    ($($t:ty),*) => {
+        $(
+        impl Polygon<V2<$t>> {
+            pub fn perimeter(&self) -> f64 {
+                self.edges()
+                    .map(|e| {
+                        let delta = e.end - e.start;
+                        (delta.inner(delta) as f64).sqrt()
+                    })
+                    .sum()
+            }
+
+            /// Lattice points on the boundary, vertices included.
+            pub fn boundary_points(&self) -> $t {
+                self.edges()
+                    .map(|e| {
+                        let delta = e.end - e.start;
+                        <$t>::gcd(delta.x().abs(), delta.y().abs())
+                    })
+                    .sum()
+            }
+
+            /// Lattice points strictly inside a simple polygon, by Pick's theorem
+            /// `A = I + B / 2 - 1`.
+            pub fn interior_points(&self) -> $t {
+                (self.doubled_signed_area().abs() - self.boundary_points() + 2) / 2
+            }
+        })*
+    };
+}
+
+float_polygon!(f32, f64);
+integer_polygon!(i32, i64);
+
+#[cfg(test)]
+mod tests {
+    use super::Polygon;
+    use crate::vector::V2;
+
+    #[test]
+    fn area_and_lattice_points() {
+        let square = Polygon::new(vec![
+            V2::from_xy(0i32, 0),
+            V2::from_xy(4, 0),
+            V2::from_xy(4, 4),
+            V2::from_xy(0, 4),
+        ]);
+        assert_eq!(square.doubled_signed_area(), 32);
+        assert_eq!(square.boundary_points(), 16);
+        assert_eq!(square.interior_points(), 9);
+        assert_eq!(square.perimeter(), 16.0);
+
+        let triangle: Polygon<V2<i64>> = [V2::from_xy(0, 0), V2::from_xy(0, 3), V2::from_xy(6, 0)]
+            .into_iter()
+            .collect();
+        assert_eq!(triangle.doubled_signed_area(), -18);
+        assert_eq!(triangle.boundary_points(), 12);
+        assert_eq!(triangle.interior_points(), 4);
+
+        let floats = Polygon::new(vec![
+            V2::from_xy(0.0f64, 0.0),
+            V2::from_xy(3.0, 0.0),
+            V2::from_xy(0.0, 4.0),
+        ]);
+        assert_eq!(floats.area(), 6.0);
+        assert_eq!(floats.perimeter(), 12.0);
+    }
+
+    #[test]
+    fn containment() {
+        // NOTE(lubo): A "U" shape, the notch is outside.
+        let u = Polygon::new(vec![
+            V2::from_xy(0i32, 0),
+            V2::from_xy(6, 0),
+            V2::from_xy(6, 6),
+            V2::from_xy(4, 6),
+            V2::from_xy(4, 2),
+            V2::from_xy(2, 2),
+            V2::from_xy(2, 6),
+            V2::from_xy(0, 6),
+        ]);
+        for (p, inside) in [
+            (V2::from_xy(1, 1), true),
+            (V2::from_xy(1, 5), true),
+            (V2::from_xy(3, 4), false),
+            (V2::from_xy(3, 2), true),
+            (V2::from_xy(6, 3), true),
+            (V2::from_xy(7, 3), false),
+            (V2::from_xy(-1, 0), false),
+            (V2::from_xy(5, 6), true),
+        ] {
+            assert_eq!(u.contains_winding(&p), inside, "{p:?}");
+            assert_eq!(u.contains_ray_casting(&p), inside, "{p:?}");
+        }
+        assert_eq!(u.winding_number(&V2::from_xy(1, 1)), 1);
+
+        // NOTE(lubo): A pentagram, the center is wound twice.
+        let star = Polygon::new(vec![
+            V2::from_xy(0i32, 10),
+            V2::from_xy(6, -8),
+            V2::from_xy(-9, 3),
+            V2::from_xy(9, 3),
+            V2::from_xy(-6, -8),
+        ]);
+        let center = V2::from_xy(0, 0);
+        assert_eq!(star.winding_number(&center).abs(), 2);
+        assert!(star.contains_winding(&center));
+        assert!(!star.contains_ray_casting(&center));
+    }
+}
@@ -0,0 +1,268 @@
+use super::{
+    line::{Line, LineVector},
+    line_iterator::LineIterator,
+    transformations::Transform,
+    vector::Vector,
+};
+
+/// Open path through a sequence of points.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polyline<V> {
+    pub points: Vec<V>,
+}
+
+impl<V> Polyline<V> {
+    pub fn new(points: Vec<V>) -> Self {
+        Self { points }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+}
+
+impl<V> Default for Polyline<V> {
+    fn default() -> Self {
+        Self::new(vec![])
+    }
+}
+
+impl<V> FromIterator<V> for Polyline<V> {
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<V: Copy> Polyline<V> {
+    pub fn transform<X: Transform<V>>(&mut self, transform: &X) {
+        transform.apply_in_place(&mut self.points);
+    }
+
+    pub fn transformed<X: Transform<V>>(&self, transform: &X) -> Self {
+        let mut result = self.clone();
+        result.transform(transform);
+        result
+    }
+
+    pub fn segments(&self) -> impl Iterator<Item = Line<V>> + '_ {
+        self.points.windows(2).map(|w| Line::new(w[0], w[1]))
+    }
+
+    /// Ramer-Douglas-Peucker simplification. Points farther than `epsilon` from the
+    /// simplified path (as measured by `distance`) are kept. End points are always kept.
+    fn simplified_by<D, F>(&self, epsilon: D, distance: F) -> Self
+    where
+        D: PartialOrd,
+        F: Fn(&Line<V>, &V) -> D,
+    {
+        let count = self.points.len();
+        if count < 3 {
+            return self.clone();
+        }
+
+        let mut keep = vec![false; count];
+        keep[0] = true;
+        keep[count - 1] = true;
+
+        let mut open = vec![(0, count - 1)];
+        while let Some((lo, hi)) = open.pop() {
+            let line = Line::new(self.points[lo], self.points[hi]);
+            let farthest = (lo + 1..hi)
+                .map(|i| (i, distance(&line, &self.points[i])))
+                .reduce(|a, b| if b.1 > a.1 { b } else { a });
+
+            if let Some((i, d)) = farthest
+                && d > epsilon
+            {
+                keep[i] = true;
+                open.push((lo, i));
+                open.push((i, hi));
+            }
+        }
+
+        self.points
+            .iter()
+            .zip(keep)
+            .filter(|(_, k)| *k)
+            .map(|(p, _)| *p)
+            .collect()
+    }
+}
+
+impl<const C: usize> Polyline<Vector<C, i32>> {
+    /// Cells along all segments, shared vertices are visited only once.
+    ///
+    /// `B` selects whether the last point is included, see [`LineIterator`].
+    pub fn cells<const B: bool>(&self) -> impl Iterator<Item = Vector<C, i32>> + '_ {
+        let last = if B { self.points.last().copied() } else { None };
+        self.segments()
+            .flat_map(|segment| LineIterator::<false, C>::new(segment.start, segment.end))
+            .chain(last)
+    }
+
+    /// Ramer-Douglas-Peucker simplification, interior points farther than `epsilon` from the
+    /// simplified path are kept.
+    ///
+    /// With `epsilon = 0.0` exactly the collinear interior points are removed.
+    pub fn simplified(&self, epsilon: f64) -> Self {
+        self.simplified_by(epsilon, |line, p| {
+            // NOTE(lubo): On overflow the point is kept.
+            line.distance_squared_to_point_ratio(p)
+                .map_or(f64::INFINITY, |(numerator, denominator)| {
+                    ((numerator as f64) / (denominator as f64)).sqrt()
+                })
+        })
+    }
+}
+
+macro_rules! float_polyline {
+    ($($t:ty),*) => {
+        $(
+        impl<const C: usize> Polyline<Vector<C, $t>> {
+            pub fn length(&self) -> $t {
+                self.segments()
+                    .map(|segment| {
+                        let delta = segment.delta();
+                        delta.inner(delta).sqrt()
+                    })
+                    .sum()
+            }
+
+            /// Point at arc length parameter `t`, `t = 0` is the first point and `t = 1` the last.
+            /// `t` is clamped to `[0, 1]`.
+            pub fn point_at(&self, t: $t) -> Option<Vector<C, $t>> {
+                self.point_at_distance(t.clamp(0.0, 1.0) * self.length())
+            }
+
+            /// Point at arc length `distance` from the first point, clamped to the path.
+            pub fn point_at_distance(&self, distance: $t) -> Option<Vector<C, $t>> {
+                let mut remaining = distance.max(0.0);
+                for segment in self.segments() {
+                    let delta = segment.delta();
+                    let length = delta.inner(delta).sqrt();
+                    if remaining <= length {
+                        let t = if length > 0.0 { remaining / length } else { 0.0 };
+                        return Some(segment.start + delta * t);
+                    }
+                    remaining -= length;
+                }
+                self.points.last().copied()
+            }
+
+            /// Ramer-Douglas-Peucker simplification, interior points farther than `epsilon` from
+            /// the simplified path are kept.
+            pub fn simplified(&self, epsilon: $t) -> Self {
+                self.simplified_by(epsilon, |line: &LineVector<C, $t>, p| {
+                    line.distance_to_point(p)
+                })
+            }
+        })*
+    };
+}
+
+float_polyline!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::Polyline;
+    use crate::{symmetry::GridSymmetry, transformations::Translation, vector::V2};
+
+    #[test]
+    fn transformed() {
+        let path = Polyline::new(vec![
+            V2::from_xy(0, 0),
+            V2::from_xy(2, 0),
+            V2::from_xy(2, 1),
+        ]);
+        let rotated = path.transformed(&GridSymmetry::Rotate90);
+        assert_eq!(
+            rotated.points,
+            vec![V2::from_xy(0, 0), V2::from_xy(0, 2), V2::from_xy(-1, 2)]
+        );
+
+        let mut moved = path.clone();
+        moved.transform(&Translation::new(V2::from_xy(1, 1)));
+        assert_eq!(moved.points[2], V2::from_xy(3, 2));
+    }
+
+    #[test]
+    fn length_and_point_at() {
+        let path = Polyline::new(vec![
+            V2::from_xy(0.0f32, 0.0),
+            V2::from_xy(3.0, 0.0),
+            V2::from_xy(3.0, 4.0),
+        ]);
+        assert_eq!(path.length(), 7.0);
+        assert_eq!(path.point_at(0.0), Some(V2::from_xy(0.0, 0.0)));
+        assert_eq!(path.point_at(1.0), Some(V2::from_xy(3.0, 4.0)));
+        assert_eq!(path.point_at(2.0), Some(V2::from_xy(3.0, 4.0)));
+        assert_eq!(path.point_at_distance(1.5), Some(V2::from_xy(1.5, 0.0)));
+        assert_eq!(path.point_at_distance(5.0), Some(V2::from_xy(3.0, 2.0)));
+        assert_eq!(Polyline::<V2<f32>>::default().point_at(0.5), None);
+    }
+
+    #[test]
+    fn cells() {
+        let path = Polyline::new(vec![
+            V2::from_xy(0, 0),
+            V2::from_xy(2, 0),
+            V2::from_xy(2, 2),
+        ]);
+        assert_eq!(
+            path.cells::<true>().collect::<Vec<_>>(),
+            vec![
+                V2::from_xy(0, 0),
+                V2::from_xy(1, 0),
+                V2::from_xy(2, 0),
+                V2::from_xy(2, 1),
+                V2::from_xy(2, 2),
+            ]
+        );
+        assert_eq!(path.cells::<false>().count(), 4);
+    }
+
+    #[test]
+    fn simplified_integer() {
+        let path: Polyline<_> = [(0, 0), (1, 0), (2, 0), (3, 1), (4, 2), (4, 3)]
+            .into_iter()
+            .map(|(x, y)| V2::from_xy(x, y))
+            .collect();
+        assert_eq!(
+            path.simplified(0.0).points,
+            vec![
+                V2::from_xy(0, 0),
+                V2::from_xy(2, 0),
+                V2::from_xy(4, 2),
+                V2::from_xy(4, 3)
+            ]
+        );
+        assert_eq!(
+            path.simplified(10.0).points,
+            vec![V2::from_xy(0, 0), V2::from_xy(4, 3)]
+        );
+    }
+
+    #[test]
+    fn simplified_float() {
+        let path = Polyline::new(vec![
+            V2::from_xy(0.0f64, 0.0),
+            V2::from_xy(1.0, 0.1),
+            V2::from_xy(2.0, -0.1),
+            V2::from_xy(3.0, 5.0),
+            V2::from_xy(4.0, 6.0),
+        ]);
+        assert_eq!(
+            path.simplified(0.5).points,
+            vec![
+                V2::from_xy(0.0, 0.0),
+                V2::from_xy(2.0, -0.1),
+                V2::from_xy(3.0, 5.0),
+                V2::from_xy(4.0, 6.0)
+            ]
+        );
+    }
+}
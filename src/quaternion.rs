@@ -0,0 +1,230 @@
+use std::ops::{Mul, Neg};
+
+use super::{
+    geometric_algebra::Rotor3,
+    matrix::{Mat3, Matrix},
+    vector::V3,
+};
+
+/// Quaternion `w + x * i + y * j + z * k`, unit quaternions represent 3D rotations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quat<T> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Quat<T> {
+    pub const fn new(w: T, x: T, y: T, z: T) -> Self {
+        Self { w, x, y, z }
+    }
+}
+
+// NOTE(lubo): The unit bivector of a rotor is the plane perpendicular to the quaternion axis,
+// e12 corresponds to k, e23 to i and e13 to -j. Rotors carry the opposite sign convention.
+impl<T: Copy + Neg<Output = T>> From<Rotor3<T>> for Quat<T> {
+    fn from(r: Rotor3<T>) -> Self {
+        Self::new(r.s, -r.yz, r.xz, -r.xy)
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> From<Quat<T>> for Rotor3<T> {
+    fn from(q: Quat<T>) -> Self {
+        Self {
+            s: q.w,
+            xy: -q.z,
+            yz: -q.x,
+            xz: q.y,
+        }
+    }
+}
+
+macro_rules! float_quaternion {
+    ($($t:ty),*) => {
+        $(
+        impl Quat<$t> {
+            pub fn identity() -> Self {
+                Self::new(1.0, 0.0, 0.0, 0.0)
+            }
+
+            /// Counterclockwise rotation by `angle` radians around `axis` (right hand rule).
+            pub fn from_axis_angle(axis: V3<$t>, angle: $t) -> Self {
+                let (sin, cos) = (angle / 2.0).sin_cos();
+                let axis = axis * (sin / axis.inner(axis).sqrt());
+                Self::new(cos, axis.x(), axis.y(), axis.z())
+            }
+
+            pub fn conjugate(&self) -> Self {
+                Self::new(self.w, -self.x, -self.y, -self.z)
+            }
+
+            pub fn magnitude(&self) -> $t {
+                (self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+            }
+
+            pub fn normalized(&self) -> Self {
+                let k = 1.0 / self.magnitude();
+                Self::new(self.w * k, self.x * k, self.y * k, self.z * k)
+            }
+
+            /// Rotate by a unit quaternion, `q v q*`.
+            pub fn rotate(&self, v: V3<$t>) -> V3<$t> {
+                let p = *self * Self::new(0.0, v.x(), v.y(), v.z()) * self.conjugate();
+                V3::from_xyz(p.x, p.y, p.z)
+            }
+
+            /// Rotation matrix of a unit quaternion, `m * v == q.rotate(v)`.
+            pub fn to_matrix(&self) -> Mat3<$t> {
+                let Self { w, x, y, z } = *self;
+                Matrix::new([
+                    [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - w * z), 2.0 * (x * z + w * y)],
+                    [2.0 * (x * y + w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - w * x)],
+                    [2.0 * (x * z - w * y), 2.0 * (y * z + w * x), 1.0 - 2.0 * (x * x + y * y)],
+                ])
+            }
+
+            /// Unit quaternion of a rotation matrix, the result has `w >= 0`.
+            pub fn from_matrix(m: Mat3<$t>) -> Self {
+                // NOTE(lubo): Shepperd's method, divide by the largest of the four candidates to
+                // stay accurate for all rotation angles.
+                let m = m.values;
+                let trace = m[0][0] + m[1][1] + m[2][2];
+                let q = if trace > 0.0 {
+                    let s = (trace + 1.0).sqrt() * 2.0;
+                    Self::new(
+                        s / 4.0,
+                        (m[2][1] - m[1][2]) / s,
+                        (m[0][2] - m[2][0]) / s,
+                        (m[1][0] - m[0][1]) / s,
+                    )
+                } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+                    let s = (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt() * 2.0;
+                    Self::new(
+                        (m[2][1] - m[1][2]) / s,
+                        s / 4.0,
+                        (m[0][1] + m[1][0]) / s,
+                        (m[0][2] + m[2][0]) / s,
+                    )
+                } else if m[1][1] > m[2][2] {
+                    let s = (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt() * 2.0;
+                    Self::new(
+                        (m[0][2] - m[2][0]) / s,
+                        (m[0][1] + m[1][0]) / s,
+                        s / 4.0,
+                        (m[1][2] + m[2][1]) / s,
+                    )
+                } else {
+                    let s = (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt() * 2.0;
+                    Self::new(
+                        (m[1][0] - m[0][1]) / s,
+                        (m[0][2] + m[2][0]) / s,
+                        (m[1][2] + m[2][1]) / s,
+                        s / 4.0,
+                    )
+                };
+                if q.w < 0.0 {
+                    Self::new(-q.w, -q.x, -q.y, -q.z)
+                } else {
+                    q
+                }
+            }
+        }
+
+        impl Mul for Quat<$t> {
+            type Output = Self;
+
+            fn mul(self, b: Self) -> Self::Output {
+                let a = self;
+                Self::new(
+                    a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+                    a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+                    a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+                    a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+                )
+            }
+        }
+
+        impl Rotor3<$t> {
+            /// Rotation matrix of a unit rotor, `m * v == r.rotate(v)`.
+            pub fn to_matrix(&self) -> Mat3<$t> {
+                Quat::<$t>::from(*self).to_matrix()
+            }
+
+            pub fn from_matrix(m: Mat3<$t>) -> Self {
+                Quat::<$t>::from_matrix(m).into()
+            }
+        })*
+    };
+}
+
+float_quaternion!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::Quat;
+    use crate::{
+        geometric_algebra::Rotor3,
+        vector::{V3, Vector},
+    };
+
+    fn assert_close<const C: usize>(a: Vector<C, f64>, b: Vector<C, f64>) {
+        let d = a - b;
+        assert!(d.inner(d) < 1e-20, "{a:?} != {b:?}");
+    }
+
+    fn assert_quat_close(a: Quat<f64>, b: Quat<f64>) {
+        assert_close(
+            Vector::new([a.w, a.x, a.y, a.z]),
+            Vector::new([b.w, b.x, b.y, b.z]),
+        );
+    }
+
+    #[test]
+    fn quaternion_rotation() {
+        let q = Quat::<f64>::from_axis_angle(V3::from_xyz(0.0, 0.0, 2.0), FRAC_PI_2);
+        assert_close(
+            q.rotate(V3::from_xyz(1.0, 0.0, 3.0)),
+            V3::from_xyz(0.0, 1.0, 3.0),
+        );
+        let p = Quat::<f64>::from_axis_angle(V3::from_xyz(1.0, 0.0, 0.0), FRAC_PI_2);
+        let v = V3::from_xyz(1.0, 2.0, 3.0);
+        assert_close((p * q).rotate(v), p.rotate(q.rotate(v)));
+        assert_close(q.conjugate().rotate(q.rotate(v)), v);
+        assert_quat_close(q * Quat::<f64>::identity(), q);
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        let v = V3::from_xyz(0.3, -1.0, 2.0);
+        let rotors = [
+            Rotor3::<f64>::identity(),
+            Rotor3::<f64>::from_vectors(V3::from_xyz(1.0, 2.0, 3.0), V3::from_xyz(-2.0, 0.5, 1.0)),
+            Rotor3::<f64>::from_plane_angle(
+                V3::from_xyz(1.0, 0.0, 0.0).wedge(V3::from_xyz(0.0, 0.0, 1.0)),
+                3.0,
+            ),
+            Rotor3::<f64>::from_vectors(V3::from_xyz(1.0, 0.0, 0.0), V3::from_xyz(-1.0, 0.0, 0.0)),
+            Rotor3::<f64>::from_vectors(V3::from_xyz(0.0, 1.0, 1.0), V3::from_xyz(0.0, -1.0, -1.0)),
+        ];
+
+        for r in rotors {
+            let q = Quat::from(r);
+            assert_eq!(Rotor3::from(q), r);
+            assert_close(q.rotate(v), r.rotate(v));
+
+            let m = r.to_matrix();
+            assert_close(m * v, r.rotate(v));
+            assert_close(Rotor3::<f64>::from_matrix(m).rotate(v), r.rotate(v));
+
+            let q = if q.w < 0.0 {
+                Quat::new(-q.w, -q.x, -q.y, -q.z)
+            } else {
+                q
+            };
+            assert_quat_close(Quat::<f64>::from_matrix(q.to_matrix()), q);
+        }
+    }
+}
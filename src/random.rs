@@ -0,0 +1,198 @@
+use std::ops::Range;
+
+/// Small seedable generator (xoshiro256**), reproducible across platforms. Not for
+/// cryptography.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rng {
+    state: [u64; 4],
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // NOTE(lubo): Expanding the seed with splitmix64 never gives the all-zero state.
+        let mut s = seed;
+        Self {
+            state: std::array::from_fn(|_| splitmix64(&mut s)),
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let [s0, s1, s2, s3] = &mut self.state;
+        let result = s1.wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = *s1 << 17;
+        *s2 ^= *s0;
+        *s3 ^= *s1;
+        *s1 ^= *s2;
+        *s0 ^= *s3;
+        *s2 ^= t;
+        *s3 = s3.rotate_left(45);
+        result
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Uniform in `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        self.next_f64() < p
+    }
+
+    /// Uniform in `0..n`, without modulo bias (Lemire's method). Panics if `n` is zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        let threshold = n.wrapping_neg() % n;
+        loop {
+            let m = self.next_u64() as u128 * n as u128;
+            if (m as u64) >= threshold {
+                return (m >> 64) as u64;
+            }
+        }
+    }
+
+    /// Uniform in `range`, panics if it is empty.
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(range.start < range.end, "empty range");
+        let width = range.end.wrapping_sub(range.start) as u64;
+        range.start.wrapping_add(self.below(width) as i64)
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// Fisher–Yates.
+    pub fn shuffle<T>(&mut self, values: &mut [T]) {
+        for i in (1..values.len()).rev() {
+            values.swap(i, self.index(i + 1));
+        }
+    }
+
+    pub fn choose<'a, T>(&mut self, values: &'a [T]) -> Option<&'a T> {
+        (!values.is_empty()).then(|| &values[self.index(values.len())])
+    }
+
+    /// Index `i` with probability proportional to `weights[i]`. `None` if no weight is positive.
+    pub fn weighted_index(&mut self, weights: &[f64]) -> Option<usize> {
+        let total: f64 = weights.iter().filter(|&&w| w > 0.0).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut target = self.next_f64() * total;
+        let mut last = None;
+        for (i, &w) in weights.iter().enumerate() {
+            if w > 0.0 {
+                if target < w {
+                    return Some(i);
+                }
+                target -= w;
+                last = Some(i);
+            }
+        }
+        // NOTE(lubo): Rounding can leave a sliver past the last positive weight.
+        last
+    }
+
+    pub fn choose_weighted<'a, T>(&mut self, values: &'a [T], weights: &[f64]) -> Option<&'a T> {
+        assert_eq!(values.len(), weights.len());
+        self.weighted_index(weights).map(|i| &values[i])
+    }
+
+    /// `k` distinct indices below `len` in random order, panics if `k > len`.
+    pub fn sample_indices(&mut self, len: usize, k: usize) -> Vec<usize> {
+        assert!(k <= len, "sample larger than population");
+        let mut indices: Vec<usize> = (0..len).collect();
+        for i in 0..k {
+            let j = i + self.index(len - i);
+            indices.swap(i, j);
+        }
+        indices.truncate(k);
+        indices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reproducible() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let xs: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+        let ys: Vec<u64> = (0..8).map(|_| b.next_u64()).collect();
+        assert_eq!(xs, ys);
+        assert_ne!(Rng::new(43).next_u64(), xs[0]);
+
+        // NOTE(lubo): Reference output of xoshiro256** from the state [1, 2, 3, 4].
+        let mut r = Rng {
+            state: [1, 2, 3, 4],
+        };
+        assert_eq!(r.next_u64(), 11520);
+        assert_eq!(r.next_u64(), 0);
+        assert_eq!(r.next_u64(), 1509978240);
+        assert_eq!(r.next_u64(), 1215971899390074240);
+    }
+
+    #[test]
+    fn distributions() {
+        let mut rng = Rng::new(7);
+        let mut counts = [0; 6];
+        for _ in 0..60000 {
+            counts[rng.index(6)] += 1;
+        }
+        assert!(
+            counts.iter().all(|&c| (9500..10500).contains(&c)),
+            "{counts:?}"
+        );
+        for _ in 0..1000 {
+            let x = rng.range(-3..4);
+            assert!((-3..4).contains(&x));
+            let f = rng.next_f64();
+            assert!((0.0..1.0).contains(&f));
+        }
+        assert_eq!(rng.range(i64::MIN..i64::MIN + 1), i64::MIN);
+
+        let mut weighted = [0; 3];
+        for _ in 0..30000 {
+            weighted[rng.weighted_index(&[1.0, 0.0, 2.0]).unwrap()] += 1;
+        }
+        assert_eq!(weighted[1], 0);
+        assert!((9000..11000).contains(&weighted[0]), "{weighted:?}");
+        assert_eq!(rng.weighted_index(&[0.0, -1.0]), None);
+        assert_eq!(rng.choose_weighted(&['a', 'b'], &[0.0, 1.0]), Some(&'b'));
+    }
+
+    #[test]
+    fn sampling() {
+        let mut rng = Rng::new(1);
+        let mut values: Vec<u32> = (0..50).collect();
+        rng.shuffle(&mut values);
+        assert_ne!(values, (0..50).collect::<Vec<_>>());
+        values.sort();
+        assert_eq!(values, (0..50).collect::<Vec<_>>());
+
+        assert_eq!(rng.choose::<u8>(&[]), None);
+        assert!(rng.choose(&[1, 2, 3]).is_some());
+
+        let mut sample = rng.sample_indices(10, 4);
+        assert_eq!(sample.len(), 4);
+        sample.sort();
+        sample.dedup();
+        assert_eq!(sample.len(), 4);
+        assert!(sample.iter().all(|&i| i < 10));
+    }
+}
@@ -0,0 +1,508 @@
+use std::{
+    marker::PhantomData,
+    ops::{Add, Bound, RangeBounds, Sub},
+};
+
+use super::{group::Group, math::Zero};
+
+fn to_range<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&a) => a,
+        Bound::Excluded(&a) => a + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&b) => b + 1,
+        Bound::Excluded(&b) => b,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end && end <= len, "range out of bounds");
+    (start, end)
+}
+
+/// Binary indexed tree, prefix sums and point updates in `O(log n)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FenwickTree<T> {
+    tree: Vec<T>,
+}
+
+impl<T> FenwickTree<T>
+where
+    T: Copy + Zero + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new(len: usize) -> Self {
+        Self {
+            tree: vec![T::zero(); len],
+        }
+    }
+
+    pub fn from_slice(values: &[T]) -> Self {
+        let mut tree = values.to_vec();
+        for i in 0..tree.len() {
+            let parent = i | (i + 1);
+            if parent < tree.len() {
+                tree[parent] = tree[parent] + tree[i];
+            }
+        }
+        Self { tree }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    pub fn add(&mut self, mut i: usize, delta: T) {
+        while i < self.tree.len() {
+            self.tree[i] = self.tree[i] + delta;
+            i |= i + 1;
+        }
+    }
+
+    /// Sum of the first `end` values.
+    pub fn prefix_sum(&self, mut end: usize) -> T {
+        let mut result = T::zero();
+        while end > 0 {
+            result = result + self.tree[end - 1];
+            end &= end - 1;
+        }
+        result
+    }
+
+    pub fn sum<R: RangeBounds<usize>>(&self, range: R) -> T {
+        let (start, end) = to_range(range, self.len());
+        self.prefix_sum(end) - self.prefix_sum(start)
+    }
+
+    pub fn get(&self, i: usize) -> T {
+        self.sum(i..=i)
+    }
+
+    pub fn set(&mut self, mut i: usize, value: T) {
+        // NOTE(lubo): Every node covering `i` includes the old value, so removing it first cannot
+        // underflow for unsigned `T`, unlike adding `value - old`.
+        let old = self.get(i);
+        while i < self.tree.len() {
+            self.tree[i] = self.tree[i] - old + value;
+            i |= i + 1;
+        }
+    }
+}
+
+/// Associative operation on `T` with an identity, the aggregate of a [`SegmentTree`].
+pub trait Monoid<T> {
+    fn identity() -> T;
+    fn op(a: &T, b: &T) -> T;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SumOp;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MinOp;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MaxOp;
+
+/// [`Group::op`], not necessarily commutative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct GroupOp;
+
+impl<T: Copy + Zero + Add<Output = T>> Monoid<T> for SumOp {
+    fn identity() -> T {
+        T::zero()
+    }
+    fn op(a: &T, b: &T) -> T {
+        *a + *b
+    }
+}
+
+macro_rules! extremum_monoid {
+    (@impl $top:ident, $bottom:ident; $($t:ty),*) => {
+        $(
+        impl Monoid<$t> for MinOp {
+            fn identity() -> $t {
+                <$t>::$top
+            }
+            fn op(a: &$t, b: &$t) -> $t {
+                if b < a { *b } else { *a }
+            }
+        }
+
+        impl Monoid<$t> for MaxOp {
+            fn identity() -> $t {
+                <$t>::$bottom
+            }
+            fn op(a: &$t, b: &$t) -> $t {
+                if b > a { *b } else { *a }
+            }
+        }
+        )*
+    };
+    // NOTE(lubo): `MAX` is not the top of the floats, infinities would be lost.
+    (float: $($t:ty),*) => {
+        extremum_monoid!(@impl INFINITY, NEG_INFINITY; $($t),*);
+    };
+    ($($t:ty),*) => {
+        extremum_monoid!(@impl MAX, MIN; $($t),*);
+    };
+}
+
+extremum_monoid!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+extremum_monoid!(float: f32, f64);
+
+impl<G: Group> Monoid<G> for GroupOp {
+    fn identity() -> G {
+        G::identity()
+    }
+    fn op(a: &G, b: &G) -> G {
+        a.op(*b)
+    }
+}
+
+/// Point updates and range aggregates under any [`Monoid`] in `O(log n)`. Order is kept, `op`
+/// need not be commutative.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SegmentTree<T, Op> {
+    len: usize,
+    tree: Vec<T>,
+    op: PhantomData<Op>,
+}
+
+impl<T: Clone, Op: Monoid<T>> SegmentTree<T, Op> {
+    pub fn new(len: usize) -> Self {
+        Self {
+            len,
+            tree: vec![Op::identity(); 2 * len],
+            op: PhantomData,
+        }
+    }
+
+    pub fn from_slice(values: &[T]) -> Self {
+        let len = values.len();
+        let mut tree = vec![Op::identity(); len];
+        tree.extend_from_slice(values);
+        for i in (1..len).rev() {
+            tree[i] = Op::op(&tree[2 * i], &tree[2 * i + 1]);
+        }
+        Self {
+            len,
+            tree,
+            op: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, i: usize) -> &T {
+        &self.tree[self.len + i]
+    }
+
+    pub fn set(&mut self, i: usize, value: T) {
+        let mut i = self.len + i;
+        self.tree[i] = value;
+        while i > 1 {
+            i /= 2;
+            self.tree[i] = Op::op(&self.tree[2 * i], &self.tree[2 * i + 1]);
+        }
+    }
+
+    /// Aggregate of the values in `range`, the identity for an empty range.
+    pub fn query<R: RangeBounds<usize>>(&self, range: R) -> T {
+        let (start, end) = to_range(range, self.len);
+        let (mut left, mut right) = (Op::identity(), Op::identity());
+        let (mut l, mut r) = (start + self.len, end + self.len);
+        while l < r {
+            if l % 2 == 1 {
+                left = Op::op(&left, &self.tree[l]);
+                l += 1;
+            }
+            if r % 2 == 1 {
+                r -= 1;
+                right = Op::op(&self.tree[r], &right);
+            }
+            l /= 2;
+            r /= 2;
+        }
+        Op::op(&left, &right)
+    }
+}
+
+impl<T: Clone, Op: Monoid<T>> FromIterator<T> for SegmentTree<T, Op> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_slice(&iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+/// Pending update of a whole range in a [`LazySegmentTree`].
+pub trait RangeUpdate<T>: Clone {
+    fn identity() -> Self;
+    /// The update doing `earlier` first and then `self`.
+    fn compose(&self, earlier: &Self) -> Self;
+    /// Updated aggregate of `len` values.
+    fn apply(&self, aggregate: &T, len: usize) -> T;
+}
+
+/// Adds the value to every element of a range aggregated by [`SumOp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AddToSum<T>(pub T);
+
+/// Adds the value to every element of a range aggregated by [`MinOp`] or [`MaxOp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AddToExtremum<T>(pub T);
+
+macro_rules! add_updates {
+    ($($t:ty),*) => {
+        $(
+        impl RangeUpdate<$t> for AddToSum<$t> {
+            fn identity() -> Self {
+                Self(<$t>::zero())
+            }
+            fn compose(&self, earlier: &Self) -> Self {
+                Self(self.0 + earlier.0)
+            }
+            fn apply(&self, aggregate: &$t, len: usize) -> $t {
+                *aggregate + self.0 * len as $t
+            }
+        }
+
+        impl RangeUpdate<$t> for AddToExtremum<$t> {
+            fn identity() -> Self {
+                Self(<$t>::zero())
+            }
+            fn compose(&self, earlier: &Self) -> Self {
+                Self(self.0 + earlier.0)
+            }
+            fn apply(&self, aggregate: &$t, _len: usize) -> $t {
+                *aggregate + self.0
+            }
+        }
+        )*
+    };
+}
+
+add_updates!(u32, u64, u128, usize, i32, i64, i128, isize, f32, f64);
+
+/// [`SegmentTree`] with range updates, both in `O(log n)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LazySegmentTree<T, Op, U> {
+    len: usize,
+    tree: Vec<T>,
+    pending: Vec<U>,
+    op: PhantomData<Op>,
+}
+
+impl<T, Op, U> LazySegmentTree<T, Op, U>
+where
+    T: Clone,
+    Op: Monoid<T>,
+    U: RangeUpdate<T>,
+{
+    pub fn from_slice(values: &[T]) -> Self {
+        let len = values.len();
+        let mut result = Self {
+            len,
+            tree: vec![Op::identity(); 4 * len.max(1)],
+            pending: vec![U::identity(); 4 * len.max(1)],
+            op: PhantomData,
+        };
+        if len > 0 {
+            result.build(1, 0, len, values);
+        }
+        result
+    }
+
+    fn build(&mut self, node: usize, start: usize, end: usize, values: &[T]) {
+        if end - start == 1 {
+            self.tree[node] = values[start].clone();
+            return;
+        }
+        let mid = (start + end) / 2;
+        self.build(2 * node, start, mid, values);
+        self.build(2 * node + 1, mid, end, values);
+        self.tree[node] = Op::op(&self.tree[2 * node], &self.tree[2 * node + 1]);
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn push(&mut self, node: usize, start: usize, end: usize) {
+        let mid = (start + end) / 2;
+        let update = std::mem::replace(&mut self.pending[node], U::identity());
+        for (child, len) in [(2 * node, mid - start), (2 * node + 1, end - mid)] {
+            self.tree[child] = update.apply(&self.tree[child], len);
+            self.pending[child] = update.compose(&self.pending[child]);
+        }
+    }
+
+    pub fn update<R: RangeBounds<usize>>(&mut self, range: R, update: U) {
+        let (start, end) = to_range(range, self.len);
+        if start < end {
+            self.update_in(1, 0, self.len, start, end, &update);
+        }
+    }
+
+    fn update_in(&mut self, node: usize, s: usize, e: usize, start: usize, end: usize, update: &U) {
+        if end <= s || e <= start {
+            return;
+        }
+        if start <= s && e <= end {
+            self.tree[node] = update.apply(&self.tree[node], e - s);
+            self.pending[node] = update.compose(&self.pending[node]);
+            return;
+        }
+        self.push(node, s, e);
+        let mid = (s + e) / 2;
+        self.update_in(2 * node, s, mid, start, end, update);
+        self.update_in(2 * node + 1, mid, e, start, end, update);
+        self.tree[node] = Op::op(&self.tree[2 * node], &self.tree[2 * node + 1]);
+    }
+
+    pub fn query<R: RangeBounds<usize>>(&mut self, range: R) -> T {
+        let (start, end) = to_range(range, self.len);
+        if start < end {
+            self.query_in(1, 0, self.len, start, end)
+        } else {
+            Op::identity()
+        }
+    }
+
+    fn query_in(&mut self, node: usize, s: usize, e: usize, start: usize, end: usize) -> T {
+        if end <= s || e <= start {
+            return Op::identity();
+        }
+        if start <= s && e <= end {
+            return self.tree[node].clone();
+        }
+        self.push(node, s, e);
+        let mid = (s + e) / 2;
+        let left = self.query_in(2 * node, s, mid, start, end);
+        let right = self.query_in(2 * node + 1, mid, e, start, end);
+        Op::op(&left, &right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::group::{Dn, IterateGroup};
+
+    #[test]
+    fn fenwick() {
+        let values = [5i64, -2, 7, 0, 3, 3, -8, 1];
+        let mut tree = FenwickTree::from_slice(&values);
+        assert_eq!(tree.prefix_sum(0), 0);
+        assert_eq!(tree.prefix_sum(8), 9);
+        assert_eq!(tree.sum(2..5), 10);
+        assert_eq!(tree.sum(..=1), 3);
+        tree.add(3, 10);
+        tree.set(0, 1);
+        assert_eq!(tree.get(3), 10);
+        assert_eq!(tree.sum(..), 15);
+
+        let mut empty = FenwickTree::<u32>::new(4);
+        empty.add(2, 5);
+        assert_eq!(empty.sum(2..), 5);
+        assert_eq!(empty.sum(..2), 0);
+
+        let mut unsigned = FenwickTree::<u32>::from_slice(&[1, 5, 2]);
+        unsigned.set(1, 0);
+        assert_eq!(unsigned.get(1), 0);
+        assert_eq!(unsigned.sum(..), 3);
+        unsigned.set(0, 4);
+        assert_eq!(unsigned.sum(..2), 4);
+    }
+
+    #[test]
+    fn segment_tree() {
+        let values = [5i64, -2, 7, 0, 3, 3, -8, 1, 4];
+        let mut min: SegmentTree<i64, MinOp> = values.iter().copied().collect();
+        let max = SegmentTree::<i64, MaxOp>::from_slice(&values);
+        let sum = SegmentTree::<i64, SumOp>::from_slice(&values);
+        for a in 0..values.len() {
+            for b in a..=values.len() {
+                let slice = &values[a..b];
+                assert_eq!(
+                    min.query(a..b),
+                    slice.iter().copied().min().unwrap_or(i64::MAX)
+                );
+                assert_eq!(
+                    max.query(a..b),
+                    slice.iter().copied().max().unwrap_or(i64::MIN)
+                );
+                assert_eq!(sum.query(a..b), slice.iter().sum::<i64>());
+            }
+        }
+        min.set(6, 2);
+        assert_eq!(min.query(..), -2);
+        assert_eq!(*min.get(6), 2);
+
+        let infinities = [f64::INFINITY, f64::NEG_INFINITY];
+        let min = SegmentTree::<f64, MinOp>::from_slice(&infinities);
+        let max = SegmentTree::<f32, MaxOp>::from_slice(&[f32::NEG_INFINITY]);
+        assert_eq!(min.query(..1), f64::INFINITY);
+        assert_eq!(min.query(..), f64::NEG_INFINITY);
+        assert_eq!(max.query(..), f32::NEG_INFINITY);
+        assert_eq!(max.query(..0), f32::NEG_INFINITY);
+
+        // NOTE(lubo): Dihedral composition is not commutative, the order has to survive.
+        let elements = Dn::<5>::all();
+        let tree = SegmentTree::<Dn<5>, GroupOp>::from_slice(&elements);
+        for a in 0..elements.len() {
+            for b in a..=elements.len() {
+                let expected = elements[a..b]
+                    .iter()
+                    .fold(Dn::identity(), |acc, &g| acc.op(g));
+                assert_eq!(tree.query(a..b), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn lazy_segment_tree() {
+        let mut values = vec![5i64, -2, 7, 0, 3, 3, -8, 1, 4, 9, -1];
+        let mut sum = LazySegmentTree::<i64, SumOp, AddToSum<i64>>::from_slice(&values);
+        let mut min = LazySegmentTree::<i64, MinOp, AddToExtremum<i64>>::from_slice(&values);
+        for (i, (a, b, delta)) in [(0, 4, 3), (2, 11, -5), (5, 6, 100), (1, 9, 2)]
+            .into_iter()
+            .enumerate()
+        {
+            sum.update(a..b, AddToSum(delta));
+            min.update(a..b, AddToExtremum(delta));
+            for v in &mut values[a..b] {
+                *v += delta;
+            }
+            for a in 0..values.len() {
+                for b in a + 1..=values.len() {
+                    let slice = &values[a..b];
+                    assert_eq!(sum.query(a..b), slice.iter().sum::<i64>(), "{i}");
+                    assert_eq!(min.query(a..b), *slice.iter().min().unwrap(), "{i}");
+                }
+            }
+        }
+        assert_eq!(sum.query(3..3), 0);
+
+        let infinities = [f64::INFINITY, 1.0, f64::NEG_INFINITY];
+        let mut min = LazySegmentTree::<f64, MinOp, AddToExtremum<f64>>::from_slice(&infinities);
+        assert_eq!(min.query(..1), f64::INFINITY);
+        min.update(..2, AddToExtremum(1.0));
+        assert_eq!(min.query(..2), 2.0);
+        assert_eq!(min.query(..), f64::NEG_INFINITY);
+    }
+}
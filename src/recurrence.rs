@@ -0,0 +1,213 @@
+/// `n`-th Fibonacci number by fast doubling, `None` past `fibonacci(186)` which is the last one
+/// fitting in `u128`.
+pub fn fibonacci(n: u64) -> Option<u128> {
+    match n {
+        0 => Some(0),
+        // NOTE(lubo): Asking for `(F(n - 1), F(n))` keeps the top pair from overflowing first.
+        n => fibonacci_pair(n - 1).map(|(_, f)| f),
+    }
+}
+
+/// `(F(n), F(n + 1))`.
+fn fibonacci_pair(n: u64) -> Option<(u128, u128)> {
+    if n == 0 {
+        return Some((0, 1));
+    }
+    let (a, b) = fibonacci_pair(n / 2)?;
+    // NOTE(lubo): F(2k) = F(k) * (2 F(k + 1) - F(k)), F(2k + 1) = F(k)^2 + F(k + 1)^2.
+    let odd = a.checked_mul(a)?.checked_add(b.checked_mul(b)?)?;
+    let even = a.checked_mul(b.checked_mul(2)? - a)?;
+    if n.is_multiple_of(2) {
+        Some((even, odd))
+    } else {
+        Some((odd, even.checked_add(odd)?))
+    }
+}
+
+/// `n`-th Fibonacci number modulo `m`.
+pub fn fibonacci_mod(n: u64, m: u64) -> u64 {
+    fibonacci_mod_pair(n, m as u128).0 as u64
+}
+
+fn fibonacci_mod_pair(n: u64, m: u128) -> (u128, u128) {
+    if n == 0 {
+        return (0, 1 % m);
+    }
+    let (a, b) = fibonacci_mod_pair(n / 2, m);
+    let even = a * ((2 * b + m - a) % m) % m;
+    let odd = (a * a % m + b * b % m) % m;
+    if n.is_multiple_of(2) {
+        (even, odd)
+    } else {
+        (odd, (even + odd) % m)
+    }
+}
+
+/// Fixed linear recurrence `a(n) = c[0] * a(n - 1) + c[1] * a(n - 2) + ... + c[k - 1] * a(n - k)`
+/// with the first `k` terms given, evaluated by exponentiating its companion matrix in
+/// `O(k^3 log n)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinearRecurrence {
+    coefficients: Vec<i64>,
+    initial: Vec<i64>,
+}
+
+type SquareMatrix<T> = Vec<Vec<T>>;
+
+fn matrix_mul<T, F>(
+    a: &SquareMatrix<T>,
+    b: &SquareMatrix<T>,
+    zero: T,
+    fma: &F,
+) -> Option<SquareMatrix<T>>
+where
+    T: Copy,
+    F: Fn(T, T, T) -> Option<T>,
+{
+    let k = a.len();
+    (0..k)
+        .map(|r| {
+            (0..k)
+                .map(|c| (0..k).try_fold(zero, |acc, i| fma(acc, a[r][i], b[i][c])))
+                .collect()
+        })
+        .collect()
+}
+
+fn matrix_pow<T, F>(
+    mut base: SquareMatrix<T>,
+    mut exp: u64,
+    zero: T,
+    one: T,
+    fma: &F,
+) -> Option<SquareMatrix<T>>
+where
+    T: Copy,
+    F: Fn(T, T, T) -> Option<T>,
+{
+    let k = base.len();
+    let mut result: SquareMatrix<T> = (0..k)
+        .map(|r| (0..k).map(|c| if r == c { one } else { zero }).collect())
+        .collect();
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = matrix_mul(&result, &base, zero, fma)?;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = matrix_mul(&base, &base, zero, fma)?;
+        }
+    }
+    Some(result)
+}
+
+impl LinearRecurrence {
+    /// Returns `None` unless there is at least one coefficient and as many initial terms as
+    /// coefficients.
+    pub fn new(coefficients: Vec<i64>, initial: Vec<i64>) -> Option<Self> {
+        (!coefficients.is_empty() && coefficients.len() == initial.len()).then_some(Self {
+            coefficients,
+            initial,
+        })
+    }
+
+    pub fn order(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// Companion matrix acting on `[a(n + k - 1), ..., a(n)]`.
+    fn companion<T: Copy>(&self, zero: T, one: T, map: impl Fn(i64) -> T) -> SquareMatrix<T> {
+        let k = self.order();
+        (0..k)
+            .map(|r| {
+                (0..k)
+                    .map(|c| match r {
+                        0 => map(self.coefficients[c]),
+                        r if c + 1 == r => one,
+                        _ => zero,
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Exact `a(n)`, `None` when an intermediate value overflows `i128`.
+    pub fn nth(&self, n: u64) -> Option<i128> {
+        let k = self.order();
+        if (n as u128) < k as u128 {
+            return Some(self.initial[n as usize] as i128);
+        }
+        let fma = |acc: i128, a: i128, b: i128| acc.checked_add(a.checked_mul(b)?);
+        let companion = self.companion(0, 1, |c| c as i128);
+        let power = matrix_pow(companion, n - (k as u64 - 1), 0, 1, &fma)?;
+        // NOTE(lubo): The last row lands on `a(n)` from `[a(k - 1), ..., a(0)]`.
+        (0..k).try_fold(0i128, |acc, i| {
+            fma(acc, power[0][i], self.initial[k - 1 - i] as i128)
+        })
+    }
+
+    /// `a(n)` modulo `m`.
+    pub fn nth_mod(&self, n: u64, m: u64) -> u64 {
+        let k = self.order();
+        let m128 = m as u128;
+        let reduce = |x: i64| (x as i128).rem_euclid(m as i128) as u128;
+        if (n as u128) < k as u128 {
+            return reduce(self.initial[n as usize]) as u64;
+        }
+        let fma = |acc: u128, a: u128, b: u128| Some((acc + a * b % m128) % m128);
+        let companion = self.companion(0, 1 % m128, reduce);
+        let power = matrix_pow(companion, n - (k as u64 - 1), 0, 1 % m128, &fma).unwrap();
+        (0..k).fold(0, |acc, i| {
+            (acc + power[0][i] * reduce(self.initial[k - 1 - i]) % m128) % m128
+        }) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinearRecurrence, fibonacci, fibonacci_mod};
+
+    #[test]
+    fn test_fibonacci() {
+        let first: Vec<_> = (0..10).map(|n| fibonacci(n).unwrap()).collect();
+        assert_eq!(first, vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+        assert_eq!(fibonacci(90), Some(2880067194370816120));
+        assert!(fibonacci(186).is_some());
+        assert_eq!(fibonacci(187), None);
+
+        assert_eq!(
+            fibonacci_mod(90, 1_000_000_007),
+            (2880067194370816120u128 % 1_000_000_007) as u64
+        );
+        assert_eq!(fibonacci_mod(1_000_000_000_000, 1), 0);
+        // NOTE(lubo): Pisano period of 10 is 60.
+        assert_eq!(fibonacci_mod(60 * 12345 + 7, 10), 3);
+    }
+
+    #[test]
+    fn test_linear_recurrence() {
+        let fib = LinearRecurrence::new(vec![1, 1], vec![0, 1]).unwrap();
+        for n in 0..150 {
+            assert_eq!(fib.nth(n), fibonacci(n).map(|f| f as i128));
+            assert_eq!(fib.nth_mod(n, 97), fibonacci_mod(n, 97));
+        }
+
+        // NOTE(lubo): Tribonacci.
+        let trib = LinearRecurrence::new(vec![1, 1, 1], vec![0, 0, 1]).unwrap();
+        let first: Vec<_> = (0..10).map(|n| trib.nth(n).unwrap()).collect();
+        assert_eq!(first, vec![0, 0, 1, 1, 2, 4, 7, 13, 24, 44]);
+
+        // NOTE(lubo): a(n) = 2 a(n - 1) - a(n - 2) is arithmetic, negative terms reduce mod m.
+        let line = LinearRecurrence::new(vec![2, -1], vec![5, 2]).unwrap();
+        assert_eq!(line.nth(10), Some(5 - 30));
+        assert_eq!(line.nth_mod(10, 7), (-25i64).rem_euclid(7) as u64);
+        assert_eq!(line.nth(1 << 40), Some(5 - 3 * (1i128 << 40)));
+
+        assert_eq!(LinearRecurrence::new(vec![1], vec![]), None);
+        assert_eq!(LinearRecurrence::new(vec![], vec![]), None);
+        assert_eq!(
+            LinearRecurrence::new(vec![3], vec![1]).unwrap().nth(100),
+            None
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use super::{
+    symmetry::CubeRotation,
+    transformations::Transform,
+    vector::{V3, Vector},
+};
+
+/// Rigid alignment of one point set onto another, see [`register`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Registration<const C: usize, R> {
+    pub rotation: R,
+    pub translation: Vector<C, i32>,
+    /// Pairs of indices `(reference, other)` of points that coincide after alignment.
+    pub pairs: Vec<(usize, usize)>,
+}
+
+impl<const C: usize, R: Transform<Vector<C, i32>>> Registration<C, R> {
+    /// Map a point from the frame of the `other` set into the frame of the `reference` set.
+    pub fn apply(&self, point: Vector<C, i32>) -> Vector<C, i32> {
+        self.rotation.apply(point) + self.translation
+    }
+}
+
+/// Find a rotation out of `rotations` and a translation mapping at least `min_overlap` points
+/// of `other` onto points of `reference`.
+///
+/// Points within each set are expected to be distinct. Candidates are tried in order and the
+/// first alignment found is returned. Runs in `O(rotations * reference * other)`.
+pub fn register<const C: usize, R, I>(
+    rotations: I,
+    reference: &[Vector<C, i32>],
+    other: &[Vector<C, i32>],
+    min_overlap: usize,
+) -> Option<Registration<C, R>>
+where
+    R: Transform<Vector<C, i32>> + Copy,
+    I: IntoIterator<Item = R>,
+{
+    if min_overlap == 0 {
+        return None;
+    }
+
+    let lookup: HashMap<_, _> = reference.iter().enumerate().map(|(i, p)| (*p, i)).collect();
+
+    for rotation in rotations {
+        let rotated: Vec<_> = rotation.apply_iter(other.iter().copied()).collect();
+
+        let mut votes = HashMap::new();
+        let translation = reference.iter().find_map(|a| {
+            rotated.iter().find_map(|b| {
+                let translation = *a - *b;
+                let count = votes.entry(translation).or_insert(0);
+                *count += 1;
+                (*count == min_overlap).then_some(translation)
+            })
+        });
+
+        if let Some(translation) = translation {
+            let pairs = rotated
+                .iter()
+                .enumerate()
+                .filter_map(|(j, b)| lookup.get(&(*b + translation)).map(|i| (*i, j)))
+                .collect();
+            return Some(Registration {
+                rotation,
+                translation,
+                pairs,
+            });
+        }
+    }
+
+    None
+}
+
+/// [`register`] over all 24 orientations of 3D space.
+pub fn register_cube(
+    reference: &[V3<i32>],
+    other: &[V3<i32>],
+    min_overlap: usize,
+) -> Option<Registration<3, CubeRotation>> {
+    register(CubeRotation::all(), reference, other, min_overlap)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{register, register_cube};
+    use crate::{
+        group::group::Group,
+        symmetry::{CubeRotation, GridSymmetry},
+        vector::{V2, V3},
+    };
+
+    #[test]
+    fn cube_registration() {
+        let reference: Vec<_> = [(0, 0, 0), (1, 2, 3), (-4, 5, 1), (7, -1, 2), (3, 3, -3)]
+            .into_iter()
+            .map(|(x, y, z)| V3::from_xyz(x, y, z))
+            .collect();
+
+        let rotation = CubeRotation::rotate_z().op(CubeRotation::rotate_x());
+        let translation = V3::from_xyz(10, -20, 5);
+        let inverse = rotation.inverse();
+        let mut other: Vec<_> = reference[1..]
+            .iter()
+            .map(|p| inverse.apply(*p - translation))
+            .collect();
+        other.push(V3::from_xyz(100, 100, 100));
+
+        let registration = register_cube(&reference, &other, 4).unwrap();
+        assert_eq!(registration.pairs.len(), 4);
+        for (i, j) in registration.pairs.iter() {
+            assert_eq!(registration.apply(other[*j]), reference[*i]);
+        }
+        assert_eq!(registration.pairs, vec![(1, 0), (2, 1), (3, 2), (4, 3)]);
+
+        assert!(register_cube(&reference, &other, 5).is_none());
+    }
+
+    #[test]
+    fn grid_registration() {
+        let reference = vec![V2::from_xy(0, 0), V2::from_xy(3, 1), V2::from_xy(5, 5)];
+        let other: Vec<_> = reference
+            .iter()
+            .map(|p| GridSymmetry::FlipX.apply(*p) + V2::from_xy(2, 2))
+            .collect();
+
+        let registration = register(GridSymmetry::all(), &reference, &other, 3).unwrap();
+        assert_eq!(registration.rotation, GridSymmetry::FlipX);
+        assert_eq!(registration.translation, V2::from_xy(2, -2));
+    }
+}
@@ -0,0 +1,118 @@
+use super::arraynd::Array2d;
+
+/// Iterator over runs of equal consecutive items, see [`runs`].
+pub struct Runs<I: Iterator> {
+    iter: std::iter::Peekable<I>,
+}
+
+impl<I> Iterator for Runs<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = (I::Item, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        let mut count = 1;
+        while self.iter.next_if(|x| *x == first).is_some() {
+            count += 1;
+        }
+        Some((first, count))
+    }
+}
+
+/// Lazily group equal consecutive items into `(item, count)` runs.
+pub fn runs<I: IntoIterator>(iter: I) -> Runs<I::IntoIter>
+where
+    I::Item: PartialEq,
+{
+    Runs {
+        iter: iter.into_iter().peekable(),
+    }
+}
+
+pub fn encode<I: IntoIterator>(iter: I) -> Vec<(I::Item, usize)>
+where
+    I::Item: PartialEq,
+{
+    runs(iter).collect()
+}
+
+/// Inverse of [`encode`], lazily repeating every item `count` times.
+pub fn decode<T: Clone, I: IntoIterator<Item = (T, usize)>>(runs: I) -> impl Iterator<Item = T> {
+    runs.into_iter()
+        .flat_map(|(item, count)| std::iter::repeat_n(item, count))
+}
+
+/// Maximal slices of consecutive items with equal `key`, with the key.
+pub fn group_by_key<T, K: PartialEq, F: Fn(&T) -> K>(items: &[T], key: F) -> Vec<(K, &[T])> {
+    items
+        .chunk_by(|a, b| key(a) == key(b))
+        .map(|group| (key(&group[0]), group))
+        .collect()
+}
+
+impl<T: Clone + PartialEq> Array2d<T> {
+    /// Runs of row `y`, left to right. `None` if the row does not exist.
+    pub fn row_runs(&self, y: usize) -> Option<Vec<(T, usize)>> {
+        Some(encode(self.row(y)?.iter().cloned()))
+    }
+
+    /// [`Array2d::row_runs`] of every row, top to bottom.
+    pub fn runs_by_row(&self) -> Vec<Vec<(T, usize)>> {
+        (0..self.height())
+            .map(|y| self.row_runs(y).unwrap())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arraynd::CharArray2d;
+
+    #[test]
+    fn round_trip() {
+        let text = "aaabccddddde";
+        let encoded = encode(text.chars());
+        assert_eq!(
+            encoded,
+            vec![('a', 3), ('b', 1), ('c', 2), ('d', 5), ('e', 1)]
+        );
+        assert_eq!(decode(encoded).collect::<String>(), text);
+        assert_eq!(encode(Vec::<u8>::new()), vec![]);
+        assert_eq!(runs([1, 1, 1]).next(), Some((1, 3)));
+        assert_eq!(decode([(7, 0), (8, 2)]).collect::<Vec<_>>(), vec![8, 8]);
+    }
+
+    #[test]
+    fn groups() {
+        let values = [1, 3, 5, 2, 4, 7, 9, 8];
+        let groups = group_by_key(&values, |x| x % 2 == 0);
+        assert_eq!(
+            groups,
+            vec![
+                (false, &values[0..3]),
+                (true, &values[3..5]),
+                (false, &values[5..7]),
+                (true, &values[7..8]),
+            ]
+        );
+    }
+
+    #[test]
+    fn grid_rows() {
+        let grid: CharArray2d = "..##.\n.....\n#####\n".parse().unwrap();
+        assert_eq!(grid.row_runs(0), Some(vec![('.', 2), ('#', 2), ('.', 1)]));
+        assert_eq!(grid.row_runs(3), None);
+        assert_eq!(
+            grid.runs_by_row(),
+            vec![
+                vec![('.', 2), ('#', 2), ('.', 1)],
+                vec![('.', 5)],
+                vec![('#', 5)],
+            ]
+        );
+    }
+}
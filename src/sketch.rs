@@ -1,5 +1,7 @@
 use std::collections::VecDeque;
 
+use crate::arraynd::Array2d;
+
 pub trait Bag<T> {
     fn new() -> Self;
     fn put(&mut self, t: T);
@@ -51,3 +53,200 @@ pub fn triangle_numbers(n: i32) -> i32 {
         (n / 2) * (n + 1)
     }
 }
+
+const PLOT_HEIGHT: usize = 10;
+const PLOT_WIDTH: usize = 72;
+const HISTOGRAM_WIDTH: usize = 50;
+
+fn axis_label(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{value}")
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+fn finite_range(values: &[f64]) -> Option<(f64, f64)> {
+    values
+        .iter()
+        .copied()
+        .filter(|v| v.is_finite())
+        .fold(None, |range, v| match range {
+            None => Some((v, v)),
+            Some((lo, hi)) => Some((lo.min(v), hi.max(v))),
+        })
+}
+
+/// Line chart of `values` in order, autoscaled to fit 10 rows. Longer sequences are averaged down
+/// to 72 columns. Non-finite values are left out.
+pub fn plot(values: &[f64]) -> String {
+    let chunk = values.len().div_ceil(PLOT_WIDTH).max(1);
+    let columns: Vec<f64> = values
+        .chunks(chunk)
+        .map(|c| c.iter().sum::<f64>() / c.len() as f64)
+        .collect();
+    let Some((lo, hi)) = finite_range(&columns) else {
+        return String::new();
+    };
+
+    let span = if hi > lo { hi - lo } else { 1.0 };
+    let mut rows = vec![vec![' '; columns.len()]; PLOT_HEIGHT];
+    for (x, v) in columns.iter().enumerate() {
+        if v.is_finite() {
+            let y = ((v - lo) / span * (PLOT_HEIGHT - 1) as f64).round() as usize;
+            rows[PLOT_HEIGHT - 1 - y][x] = '*';
+        }
+    }
+
+    let (top, bottom) = (axis_label(hi), axis_label(lo));
+    let margin = top.len().max(bottom.len());
+    let mut out = String::new();
+    for (i, row) in rows.iter().enumerate() {
+        let label = match i {
+            0 => top.as_str(),
+            i if i == PLOT_HEIGHT - 1 => bottom.as_str(),
+            _ => "",
+        };
+        let line: String = row.iter().collect();
+        out += &format!("{label:>margin$} |{}\n", line.trim_end());
+    }
+    out += &format!("{:margin$} +{}\n", "", "-".repeat(columns.len()));
+    let last = (values.len() - 1).to_string();
+    let gap = columns.len().saturating_sub(1 + last.len()).max(1);
+    out += &format!("{:margin$}  0{}{last}\n", "", " ".repeat(gap));
+    out
+}
+
+/// Horizontal bar chart counting `values` in `buckets` equal width ranges between their minimum
+/// and maximum. Non-finite values are left out.
+pub fn histogram(values: &[f64], buckets: usize) -> String {
+    let Some((lo, hi)) = finite_range(values) else {
+        return String::new();
+    };
+    if buckets == 0 {
+        return String::new();
+    }
+
+    let span = if hi > lo { hi - lo } else { 1.0 };
+    let mut counts = vec![0usize; buckets];
+    for v in values.iter().filter(|v| v.is_finite()) {
+        let bucket = ((v - lo) / span * buckets as f64) as usize;
+        counts[bucket.min(buckets - 1)] += 1;
+    }
+
+    let bounds: Vec<_> = (0..=buckets)
+        .map(|i| axis_label(lo + span * i as f64 / buckets as f64))
+        .collect();
+    let margin = bounds.iter().map(|b| b.len()).max().unwrap_or(0);
+    let most = counts.iter().copied().max().unwrap_or(0).max(1);
+    let mut out = String::new();
+    for (i, count) in counts.iter().enumerate() {
+        let bar = (count * HISTOGRAM_WIDTH).div_ceil(most);
+        out += &format!(
+            "{:>margin$} .. {:>margin$} |{} {count}\n",
+            bounds[i],
+            bounds[i + 1],
+            "#".repeat(bar)
+        );
+    }
+    out
+}
+
+fn pixel(grid: &Array2d<bool>, x: usize, y: usize) -> bool {
+    x < grid.width() && y < grid.height() && *grid.get_linear(x + y * grid.width())
+}
+
+/// Render 2x4 cells per character with Unicode Braille patterns, row 0 on top.
+pub fn braille(grid: &Array2d<bool>) -> String {
+    // NOTE(lubo): Dot bits of U+2800 + bits, columns left to right, rows top to bottom.
+    const DOTS: [[u32; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+    let mut out = String::new();
+    for cy in 0..grid.height().div_ceil(4) {
+        for cx in 0..grid.width().div_ceil(2) {
+            let mut bits = 0;
+            for (dx, column) in DOTS.iter().enumerate() {
+                for (dy, dot) in column.iter().enumerate() {
+                    if pixel(grid, 2 * cx + dx, 4 * cy + dy) {
+                        bits |= dot;
+                    }
+                }
+            }
+            out.push(char::from_u32(0x2800 + bits).unwrap());
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Render 1x2 cells per character with half blocks, for fonts without Braille.
+pub fn half_blocks(grid: &Array2d<bool>) -> String {
+    let mut out = String::new();
+    for cy in 0..grid.height().div_ceil(2) {
+        for x in 0..grid.width() {
+            out.push(match (pixel(grid, x, 2 * cy), pixel(grid, x, 2 * cy + 1)) {
+                (true, true) => '█',
+                (true, false) => '▀',
+                (false, true) => '▄',
+                (false, false) => ' ',
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{braille, half_blocks, histogram, plot};
+    use crate::arraynd::{Array2d, CharArray2d};
+
+    #[test]
+    fn plot_sequence() {
+        let values: Vec<f64> = (0..10).map(|x| x as f64).collect();
+        let chart = plot(&values);
+        let lines: Vec<_> = chart.lines().collect();
+        assert_eq!(lines.len(), 12);
+        assert_eq!(lines[0], "9 |         *");
+        assert_eq!(lines[9], "0 |*");
+        assert_eq!(lines[10], "  +----------");
+        assert_eq!(lines[11], "   0        9");
+
+        let flat = plot(&[2.5, 2.5, f64::NAN]);
+        assert_eq!(flat.lines().nth(9), Some("2.50 |**"));
+
+        let long: Vec<f64> = (0..1000).map(|x| (x as f64 / 50.0).sin()).collect();
+        let axis = plot(&long).lines().nth(10).unwrap().to_string();
+        assert!(axis.ends_with(&format!("+{}", "-".repeat(72))));
+        assert_eq!(plot(&[]), "");
+    }
+
+    #[test]
+    fn histogram_buckets() {
+        let values = [1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 3.0, 5.0];
+        assert_eq!(
+            histogram(&values, 4),
+            "1 .. 2 |############# 1\n\
+             2 .. 3 |######################### 2\n\
+             3 .. 4 |################################################## 4\n\
+             4 .. 5 |############# 1\n"
+        );
+        assert_eq!(
+            histogram(&[7.0, 7.0], 2),
+            format!("   7 .. 7.50 |{} 2\n7.50 ..    8 | 0\n", "#".repeat(50))
+        );
+        assert_eq!(histogram(&values, 0), "");
+        assert_eq!(histogram(&[f64::NAN], 3), "");
+    }
+
+    #[test]
+    fn raster() {
+        let grid: CharArray2d = "#...#\n.#...\n..#..\n...#.\n#...#".parse().unwrap();
+        let grid = grid.map(|c| *c == '#');
+        assert_eq!(braille(&grid), "⠑⢄⠁\n⠁⠀⠁\n");
+        assert_eq!(half_blocks(&grid), "▀▄  ▀\n  ▀▄ \n▀   ▀\n");
+
+        let full = Array2d::with_dimensions(4, 8, true);
+        assert_eq!(braille(&full), "⣿⣿\n⣿⣿\n");
+        assert_eq!(braille(&Array2d::with_dimensions(0, 0, true)), "");
+    }
+}
@@ -0,0 +1,444 @@
+use std::ops::Neg;
+
+use super::{
+    arraynd::Array2d,
+    group::{
+        action::{GroupAction, count_orbits},
+        group::{Group, IterateGroup},
+    },
+    matrix::Matrix,
+    transformations::Transform,
+    vector::{V2, V3, Vector},
+};
+
+/// Symmetries of the square grid (the dihedral group D4).
+///
+/// Rotations are counterclockwise when y points up, so [`GridSymmetry::Rotate90`] maps x to y.
+/// Composition follows functions, `a.op(b)` applies `b` first and then `a`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GridSymmetry {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    /// Mirror along the y axis, `(x, y) -> (-x, y)`.
+    FlipX,
+    /// Mirror along the x axis, `(x, y) -> (x, -y)`.
+    FlipY,
+    /// Mirror along the main diagonal, `(x, y) -> (y, x)`.
+    Transpose,
+    /// Mirror along the anti-diagonal, `(x, y) -> (-y, -x)`.
+    AntiTranspose,
+}
+
+impl GridSymmetry {
+    pub const ALL: [Self; 8] = [
+        Self::Identity,
+        Self::Rotate90,
+        Self::Rotate180,
+        Self::Rotate270,
+        Self::FlipX,
+        Self::FlipY,
+        Self::Transpose,
+        Self::AntiTranspose,
+    ];
+
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    /// Rotations only (the cyclic subgroup C4).
+    pub fn rotations() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter().take(4)
+    }
+
+    pub fn is_reflection(self) -> bool {
+        matches!(
+            self,
+            Self::FlipX | Self::FlipY | Self::Transpose | Self::AntiTranspose
+        )
+    }
+
+    pub fn matrix(self) -> Matrix<2, 2, i32> {
+        let x = self.apply(V2::from_xy(1, 0));
+        let y = self.apply(V2::from_xy(0, 1));
+        Matrix::from_columns([x, y])
+    }
+
+    pub fn from_matrix(matrix: Matrix<2, 2, i32>) -> Option<Self> {
+        Self::all().find(|s| s.matrix() == matrix)
+    }
+
+    pub fn apply<T: Copy + Neg<Output = T>>(self, v: V2<T>) -> V2<T> {
+        self.mapping()(v)
+    }
+
+    // NOTE(lubo): Resolving the variant once lets bulk application skip the match per point.
+    fn mapping<T: Copy + Neg<Output = T>>(self) -> fn(V2<T>) -> V2<T> {
+        match self {
+            Self::Identity => |v| v,
+            Self::Rotate90 => |v| V2::from_xy(-v.y(), v.x()),
+            Self::Rotate180 => |v| V2::from_xy(-v.x(), -v.y()),
+            Self::Rotate270 => |v| V2::from_xy(v.y(), -v.x()),
+            Self::FlipX => |v| V2::from_xy(-v.x(), v.y()),
+            Self::FlipY => |v| V2::from_xy(v.x(), -v.y()),
+            Self::Transpose => |v| V2::from_xy(v.y(), v.x()),
+            Self::AntiTranspose => |v| V2::from_xy(-v.y(), -v.x()),
+        }
+    }
+
+    /// Transform the whole array, the result is translated back so that it starts at `(0, 0)`.
+    ///
+    /// Rotating by 90 or 270 degrees and transposing swap width and height.
+    pub fn apply_to<T: Copy>(self, array: &Array2d<T>) -> Array2d<T> {
+        let (width, height) = (array.width() as i32, array.height() as i32);
+        let far = self.apply(V2::from_xy(width - 1, height - 1));
+        let offset = far.elementwise_min(Vector::all(0));
+        let dims = self.apply(V2::from_xy(width, height));
+        let (new_width, new_height) = (dims.x().abs(), dims.y().abs());
+
+        let inverse = self.inverse();
+        let mut data = Vec::with_capacity(array.data.len());
+        for y in 0..new_height {
+            for x in 0..new_width {
+                data.push(array[inverse.apply(V2::from_xy(x, y) + offset)]);
+            }
+        }
+
+        Array2d::from_slice([new_width, new_height], &data)
+    }
+}
+
+impl Group for GridSymmetry {
+    fn identity() -> Self {
+        Self::Identity
+    }
+
+    fn op(self, rhs: Self) -> Self {
+        Self::from_matrix(self.matrix() * rhs.matrix()).unwrap()
+    }
+
+    fn inverse(self) -> Self {
+        // NOTE(lubo): Signed permutation matrices are orthogonal.
+        Self::from_matrix(self.matrix().transpose()).unwrap()
+    }
+}
+
+impl IterateGroup for GridSymmetry {
+    fn all() -> Vec<Self> {
+        Self::ALL.to_vec()
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Transform<V2<T>> for GridSymmetry {
+    fn apply(&self, object: V2<T>) -> V2<T> {
+        GridSymmetry::apply(*self, object)
+    }
+
+    fn apply_in_place(&self, objects: &mut [V2<T>]) {
+        if *self == Self::Identity {
+            return;
+        }
+        let mapping = self.mapping();
+        for object in objects {
+            *object = mapping(*object);
+        }
+    }
+
+    fn compose(&self, other: &Self) -> Self {
+        self.op(*other)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Some(Group::inverse(*self))
+    }
+}
+
+/// One of the 24 rotations of the cube, orientations of an axis aligned object in 3D.
+///
+/// Stored as a signed permutation matrix, so application to integer vectors is exact.
+/// Composition follows functions, `a.op(b)` applies `b` first and then `a`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CubeRotation {
+    matrix: Matrix<3, 3, i32>,
+}
+
+impl CubeRotation {
+    /// Quarter turn around the x axis, y goes to z.
+    pub fn rotate_x() -> Self {
+        Self {
+            matrix: Matrix::new([[1, 0, 0], [0, 0, -1], [0, 1, 0]]),
+        }
+    }
+
+    /// Quarter turn around the y axis, z goes to x.
+    pub fn rotate_y() -> Self {
+        Self {
+            matrix: Matrix::new([[0, 0, 1], [0, 1, 0], [-1, 0, 0]]),
+        }
+    }
+
+    /// Quarter turn around the z axis, x goes to y.
+    pub fn rotate_z() -> Self {
+        Self {
+            matrix: Matrix::new([[0, -1, 0], [1, 0, 0], [0, 0, 1]]),
+        }
+    }
+
+    /// All 24 rotations, starting with the identity.
+    pub fn all() -> impl Iterator<Item = Self> {
+        // NOTE(lubo): Permutations of the axes together with their parity.
+        const PERMUTATIONS: [([usize; 3], i32); 6] = [
+            ([0, 1, 2], 1),
+            ([1, 2, 0], 1),
+            ([2, 0, 1], 1),
+            ([0, 2, 1], -1),
+            ([2, 1, 0], -1),
+            ([1, 0, 2], -1),
+        ];
+
+        PERMUTATIONS.into_iter().flat_map(|(axes, parity)| {
+            (0..8).filter_map(move |bits| {
+                let signs: [i32; 3] =
+                    std::array::from_fn(|i| if bits & (1 << i) == 0 { 1 } else { -1 });
+                if parity * signs.iter().product::<i32>() != 1 {
+                    return None;
+                }
+                let mut matrix = Matrix::zero();
+                for (row, (&axis, sign)) in axes.iter().zip(signs).enumerate() {
+                    matrix.values[row][axis] = sign;
+                }
+                Some(Self { matrix })
+            })
+        })
+    }
+
+    pub fn matrix(self) -> Matrix<3, 3, i32> {
+        self.matrix
+    }
+
+    /// Returns `None` unless `matrix` is a rotation of the cube.
+    pub fn from_matrix(matrix: Matrix<3, 3, i32>) -> Option<Self> {
+        Self::all().find(|r| r.matrix == matrix)
+    }
+
+    pub fn apply(self, v: V3<i32>) -> V3<i32> {
+        self.matrix * v
+    }
+}
+
+impl Group for CubeRotation {
+    fn identity() -> Self {
+        Self {
+            matrix: Matrix::identity(),
+        }
+    }
+
+    fn op(self, rhs: Self) -> Self {
+        Self {
+            matrix: self.matrix * rhs.matrix,
+        }
+    }
+
+    fn inverse(self) -> Self {
+        Self {
+            matrix: self.matrix.transpose(),
+        }
+    }
+}
+
+impl IterateGroup for CubeRotation {
+    fn all() -> Vec<Self> {
+        CubeRotation::all().collect()
+    }
+}
+
+impl Transform<V3<i32>> for CubeRotation {
+    fn apply(&self, object: V3<i32>) -> V3<i32> {
+        CubeRotation::apply(*self, object)
+    }
+
+    fn compose(&self, other: &Self) -> Self {
+        self.op(*other)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Some(Group::inverse(*self))
+    }
+}
+
+/// Number of `width x height` grids filled with `colors` colors that are distinct up to the
+/// symmetries keeping the shape of the grid, all of D4 for squares and the four symmetries
+/// without a quarter turn otherwise. `None` when the count does not fit `u128`.
+pub fn count_distinct_grids(width: usize, height: usize, colors: u128) -> Option<u128> {
+    let cells: Vec<_> = (0..width * height).collect();
+    let grid = Array2d::from_slice([width, height], &cells);
+    let symmetries: Vec<_> = GridSymmetry::all()
+        .filter(|s| width == height || s.matrix().values[0][0] != 0)
+        .collect();
+
+    count_orbits(&symmetries, |s| {
+        // NOTE(lubo): A grid is fixed when it is constant on every cycle of the cell permutation.
+        let image = s.apply_to(&grid).data;
+        let mut visited = vec![false; cells.len()];
+        let mut cycles = 0;
+        for start in cells.iter() {
+            if visited[*start] {
+                continue;
+            }
+            cycles += 1;
+            let mut i = *start;
+            while !visited[i] {
+                visited[i] = true;
+                i = image[i];
+            }
+        }
+        colors.checked_pow(cycles)
+    })
+}
+
+/// Action of [`GridSymmetry`] on whole grids through [`GridSymmetry::apply_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct OnGrid;
+
+impl<T: Copy> GroupAction<GridSymmetry, Array2d<T>> for OnGrid {
+    fn act(&self, g: &GridSymmetry, x: &Array2d<T>) -> Array2d<T> {
+        g.apply_to(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{CubeRotation, GridSymmetry, OnGrid, count_distinct_grids};
+    use crate::{
+        arraynd::Array2d,
+        group::{action::GroupAction, group::Group},
+        vector::{V2, V3},
+    };
+
+    #[test]
+    fn group_axioms() {
+        for a in GridSymmetry::all() {
+            assert_eq!(GridSymmetry::identity().op(a), a);
+            assert_eq!(a.op(a.inverse()), GridSymmetry::identity());
+            for b in GridSymmetry::all() {
+                let v = V2::from_xy(2, 5);
+                assert_eq!(a.op(b).apply(v), a.apply(b.apply(v)));
+                for c in GridSymmetry::all() {
+                    assert_eq!(a.op(b).op(c), a.op(b.op(c)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn composition() {
+        use GridSymmetry::*;
+        assert_eq!(Rotate90.op(Rotate90), Rotate180);
+        assert_eq!(Rotate90.op(Rotate270), Identity);
+        assert_eq!(FlipX.op(FlipY), Rotate180);
+        assert_eq!(Rotate90.op(FlipY), Transpose);
+        assert_eq!(Rotate90.op(FlipX), AntiTranspose);
+        assert_eq!(Rotate90.inverse(), Rotate270);
+        assert_eq!(GridSymmetry::all().filter(|s| s.is_reflection()).count(), 4);
+        assert!(GridSymmetry::rotations().all(|s| !s.is_reflection()));
+    }
+
+    #[test]
+    fn distinct_grids() {
+        assert_eq!(count_distinct_grids(2, 2, 2), Some(6));
+        assert_eq!(count_distinct_grids(3, 3, 2), Some(102));
+        assert_eq!(count_distinct_grids(2, 3, 2), Some(24));
+        assert_eq!(count_distinct_grids(1, 1, 5), Some(5));
+        assert!(count_distinct_grids(8, 8, 2).is_some());
+        assert_eq!(count_distinct_grids(12, 12, 2), None);
+
+        for (width, height) in [(3, 3), (2, 3), (4, 1)] {
+            let n = width * height;
+            let mut seen = HashSet::new();
+            let mut orbits = 0;
+            for bits in 0..1 << n {
+                let cells: Vec<_> = (0..n).map(|i| bits >> i & 1).collect();
+                let grid = Array2d::from_slice([width, height], &cells);
+                if seen.contains(&grid) {
+                    continue;
+                }
+                orbits += 1;
+                let generators: Vec<_> = GridSymmetry::all()
+                    .filter(|s| width == height || s.matrix().values[0][0] != 0)
+                    .collect();
+                seen.extend(OnGrid.orbit(&generators, &grid));
+            }
+            assert_eq!(count_distinct_grids(width, height, 2), Some(orbits));
+        }
+    }
+
+    #[test]
+    fn apply_to_array() {
+        // 1 2 3
+        // 4 5 6
+        let array = Array2d::from_slice([3, 2], &[1, 2, 3, 4, 5, 6]);
+
+        let transposed = GridSymmetry::Transpose.apply_to(&array);
+        assert_eq!(transposed.dims, [2, 3]);
+        assert_eq!(transposed.data, vec![1, 4, 2, 5, 3, 6]);
+
+        let flipped = GridSymmetry::FlipX.apply_to(&array);
+        assert_eq!(flipped.data, vec![3, 2, 1, 6, 5, 4]);
+
+        let rotated = GridSymmetry::Rotate180.apply_to(&array);
+        assert_eq!(rotated.data, vec![6, 5, 4, 3, 2, 1]);
+
+        for s in GridSymmetry::all() {
+            assert_eq!(s.inverse().apply_to(&s.apply_to(&array)), array);
+        }
+    }
+
+    #[test]
+    fn cube_rotations() {
+        let all: Vec<_> = CubeRotation::all().collect();
+        assert_eq!(all.len(), 24);
+        assert_eq!(all[0], CubeRotation::identity());
+        for (i, a) in all.iter().enumerate() {
+            assert!(!all[i + 1..].contains(a));
+            assert_eq!(a.op(a.inverse()), CubeRotation::identity());
+            for b in all.iter() {
+                assert!(all.contains(&a.op(*b)));
+            }
+        }
+
+        let v = V3::from_xyz(1, 2, 3);
+        let images: Vec<_> = all.iter().map(|r| r.apply(v)).collect();
+        for (i, image) in images.iter().enumerate() {
+            assert!(!images[i + 1..].contains(image));
+            assert_eq!(image.inner(*image), v.inner(v));
+        }
+    }
+
+    #[test]
+    fn cube_quarter_turns() {
+        let (x, y, z) = (
+            CubeRotation::rotate_x(),
+            CubeRotation::rotate_y(),
+            CubeRotation::rotate_z(),
+        );
+        assert_eq!(x.apply(V3::from_xyz(0, 1, 0)), V3::from_xyz(0, 0, 1));
+        assert_eq!(y.apply(V3::from_xyz(0, 0, 1)), V3::from_xyz(1, 0, 0));
+        assert_eq!(z.apply(V3::from_xyz(1, 0, 0)), V3::from_xyz(0, 1, 0));
+        assert_eq!(z.op(z).op(z).op(z), CubeRotation::identity());
+
+        let v = V3::from_xyz(1, 2, 3);
+        assert_eq!(y.op(x).apply(v), y.apply(x.apply(v)));
+        assert_eq!(CubeRotation::from_matrix(x.op(y).matrix()), Some(x.op(y)));
+        assert_eq!(
+            CubeRotation::from_matrix(crate::matrix::Matrix::new([
+                [-1, 0, 0],
+                [0, 1, 0],
+                [0, 0, 1]
+            ])),
+            None
+        );
+    }
+}
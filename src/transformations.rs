@@ -1,11 +1,47 @@
-use std::ops::{Add, Sub};
+use std::ops::{Add, Mul, Sub};
 
-pub trait Transform<T> {
-    fn transform(&self, object: T) -> T;
-    fn inverse_transform(&self, object: T) -> T;
+use super::{
+    math::{One, Zero},
+    matrix::{Mat3, Mat4, Matrix},
+    vector::Vector,
+};
+
+/// Invertible map of `V`, e.g. a point.
+pub trait Transform<V>
+where
+    Self: Sized,
+{
+    fn apply(&self, object: V) -> V;
+
+    /// `self.compose(other)` applies `other` first and then `self`.
+    fn compose(&self, other: &Self) -> Self;
+
+    /// Returns `None` if the transform is not invertible (in the type it is defined over).
+    fn inverse(&self) -> Option<Self>;
+
+    fn apply_inverse(&self, object: V) -> Option<V> {
+        Some(self.inverse()?.apply(object))
+    }
+
+    /// Bulk application, implementors may override this with a faster loop.
+    fn apply_in_place(&self, objects: &mut [V])
+    where
+        V: Copy,
+    {
+        for object in objects {
+            *object = self.apply(*object);
+        }
+    }
+
+    fn apply_iter<I>(&self, objects: I) -> impl Iterator<Item = V>
+    where
+        I: IntoIterator<Item = V>,
+    {
+        objects.into_iter().map(|object| self.apply(object))
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Translation<T> {
     pub translation: T,
 }
@@ -18,15 +54,388 @@ impl<T> Translation<T> {
 
 impl<T> Transform<T> for Translation<T>
 where
-    T: Copy,
+    T: Copy + Zero,
     T: Add<Output = T>,
     T: Sub<Output = T>,
 {
-    fn transform(&self, object: T) -> T {
+    fn apply(&self, object: T) -> T {
         object + self.translation
     }
 
-    fn inverse_transform(&self, object: T) -> T {
-        object - self.translation
+    fn compose(&self, other: &Self) -> Self {
+        Self::new(self.translation + other.translation)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Some(Self::new(T::zero() - self.translation))
+    }
+
+    fn apply_inverse(&self, object: T) -> Option<T> {
+        Some(object - self.translation)
+    }
+}
+
+/// Affine map `p -> linear * p + translation`.
+///
+/// Points are affected by the translation, directions only by the linear part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Affine<const C: usize, T> {
+    pub linear: Matrix<C, C, T>,
+    pub translation: Vector<C, T>,
+}
+
+pub type Affine2<T> = Affine<2, T>;
+pub type Affine3<T> = Affine<3, T>;
+
+impl<const C: usize, T> Affine<C, T> {
+    pub const fn new(linear: Matrix<C, C, T>, translation: Vector<C, T>) -> Self {
+        Self {
+            linear,
+            translation,
+        }
+    }
+}
+
+impl<const C: usize, T> Affine<C, T>
+where
+    T: Copy + Zero + One,
+    T: Add<Output = T> + Mul<Output = T>,
+{
+    pub fn identity() -> Self {
+        Self::new(Matrix::identity(), Vector::all(T::zero()))
+    }
+
+    pub fn from_linear(linear: Matrix<C, C, T>) -> Self {
+        Self::new(linear, Vector::all(T::zero()))
+    }
+
+    pub fn from_translation(translation: Vector<C, T>) -> Self {
+        Self::new(Matrix::identity(), translation)
+    }
+
+    pub fn transform_point(&self, point: Vector<C, T>) -> Vector<C, T> {
+        self.linear * point + self.translation
+    }
+
+    pub fn transform_direction(&self, direction: Vector<C, T>) -> Vector<C, T> {
+        self.linear * direction
+    }
+
+    /// `self.compose(other)` applies `other` first and then `self`.
+    pub fn compose(&self, other: &Self) -> Self {
+        Self::new(
+            self.linear * other.linear,
+            self.linear * other.translation + self.translation,
+        )
+    }
+}
+
+macro_rules! affine_inverse {
+    ($c:literal; $($t:ty),*) => {
+        $(
+        impl Affine<$c, $t> {
+            /// Returns `None` if the linear part is not invertible, see [`Matrix::inverse`].
+            pub fn inverse(&self) -> Option<Self> {
+                let linear = self.linear.inverse()?;
+                let translation = Vector::all(0 as $t) - linear * self.translation;
+                Some(Self::new(linear, translation))
+            }
+        }
+
+        impl Transform<Vector<$c, $t>> for Affine<$c, $t> {
+            fn apply(&self, object: Vector<$c, $t>) -> Vector<$c, $t> {
+                self.transform_point(object)
+            }
+
+            fn compose(&self, other: &Self) -> Self {
+                Self::compose(self, other)
+            }
+
+            fn inverse(&self) -> Option<Self> {
+                Self::inverse(self)
+            }
+        })*
+    };
+}
+
+affine_inverse!(2; f32, f64, i32, i64);
+affine_inverse!(3; f32, f64, i32, i64);
+
+macro_rules! affine_homogeneous {
+    ($($c:literal => $h:ident),*) => {
+        $(
+        impl<T> Affine<$c, T>
+        where
+            T: Copy + Zero + One + PartialEq,
+        {
+            /// Matrix acting on homogeneous coordinates, the last row is `[0, .., 0, 1]`.
+            pub fn to_homogeneous(&self) -> $h<T> {
+                Matrix::new(std::array::from_fn(|r| {
+                    std::array::from_fn(|c| match (r < $c, c < $c) {
+                        (true, true) => self.linear.values[r][c],
+                        (true, false) => self.translation.values[r],
+                        (false, true) => T::zero(),
+                        (false, false) => T::one(),
+                    })
+                }))
+            }
+
+            /// Returns `None` for projective matrices (last row other than `[0, .., 0, 1]`).
+            pub fn from_homogeneous(matrix: $h<T>) -> Option<Self> {
+                let last = matrix.values[$c];
+                let affine = last[..$c].iter().all(|x| *x == T::zero()) && last[$c] == T::one();
+                affine.then(|| {
+                    Self::new(
+                        Matrix::new(std::array::from_fn(|r| {
+                            std::array::from_fn(|c| matrix.values[r][c])
+                        })),
+                        Vector::new(std::array::from_fn(|r| matrix.values[r][$c])),
+                    )
+                })
+            }
+        })*
+    };
+}
+
+affine_homogeneous!(2 => Mat3, 3 => Mat4);
+
+impl<T> Affine2<T>
+where
+    T: Copy + Zero + One,
+    T: Add<Output = T> + Mul<Output = T>,
+{
+    /// `(x, y) -> (x + k * y, y)`, determinant is 1 so integer shears are invertible.
+    pub fn shear_x(k: T) -> Self {
+        Self::from_linear(Matrix::new([[T::one(), k], [T::zero(), T::one()]]))
+    }
+
+    /// `(x, y) -> (x, y + k * x)`, determinant is 1 so integer shears are invertible.
+    pub fn shear_y(k: T) -> Self {
+        Self::from_linear(Matrix::new([[T::one(), T::zero()], [k, T::one()]]))
+    }
+
+    pub fn scale_xy(sx: T, sy: T) -> Self {
+        Self::from_linear(Matrix::new([[sx, T::zero()], [T::zero(), sy]]))
+    }
+}
+
+impl<T> Affine3<T>
+where
+    T: Copy + Zero + One,
+    T: Add<Output = T> + Mul<Output = T>,
+{
+    pub fn scale_xyz(sx: T, sy: T, sz: T) -> Self {
+        let z = T::zero();
+        Self::from_linear(Matrix::new([[sx, z, z], [z, sy, z], [z, z, sz]]))
+    }
+}
+
+// NOTE(lubo): Reflection about the line spanned by `axis` is `2 * a * a^T / (a . a) - I`.
+macro_rules! float_affine_reflection {
+    ($($t:ty),*) => {
+        $(
+        impl Affine2<$t> {
+            /// Reflection about the line through the origin with direction `axis`.
+            ///
+            /// Returns `None` for a zero `axis`.
+            pub fn reflection(axis: Vector<2, $t>) -> Option<Self> {
+                let length_squared = axis.inner(axis);
+                if length_squared == 0.0 {
+                    return None;
+                }
+                let [x, y] = axis.values;
+                let k = 2.0 / length_squared;
+                Some(Self::from_linear(Matrix::new([
+                    [k * x * x - 1.0, k * x * y],
+                    [k * x * y, k * y * y - 1.0],
+                ])))
+            }
+        })*
+    };
+}
+
+macro_rules! integer_affine_reflection {
+    ($($t:ty),*) => {
+        $(
+        impl Affine2<$t> {
+            /// Reflection about the line through the origin with direction `axis`.
+            ///
+            /// Returns `None` for a zero `axis` or when the reflection does not map the integer
+            /// lattice onto itself (only axis aligned and diagonal axes do).
+            pub fn reflection(axis: Vector<2, $t>) -> Option<Self> {
+                let length_squared = axis.inner(axis);
+                if length_squared == 0 {
+                    return None;
+                }
+                let [x, y] = axis.values;
+                let [xx, xy, yy] = [2 * x * x, 2 * x * y, 2 * y * y];
+                if [xx, xy, yy].iter().any(|n| n % length_squared != 0) {
+                    return None;
+                }
+                let (xx, xy, yy) = (xx / length_squared, xy / length_squared, yy / length_squared);
+                Some(Self::from_linear(Matrix::new([[xx - 1, xy], [xy, yy - 1]])))
+            }
+        })*
+    };
+}
+
+float_affine_reflection!(f32, f64);
+integer_affine_reflection!(i32, i64);
+
+#[cfg(test)]
+mod tests {
+    use super::{Affine2, Affine3, Transform, Translation};
+    use crate::{
+        matrix::Matrix,
+        symmetry::{CubeRotation, GridSymmetry},
+        vector::{V2, V3, V4},
+    };
+
+    fn round_trip<V, X>(transform: &X, object: V)
+    where
+        V: Copy + PartialEq + std::fmt::Debug,
+        X: Transform<V>,
+    {
+        let inverse = transform.inverse().unwrap();
+        assert_eq!(inverse.apply(transform.apply(object)), object);
+        assert_eq!(
+            transform.apply_inverse(transform.apply(object)),
+            Some(object)
+        );
+        assert_eq!(transform.compose(&inverse).apply(object), object);
+    }
+
+    #[test]
+    fn transform_trait() {
+        let p2 = V2::from_xy(3, -7);
+        let p3 = V3::from_xyz(3, -7, 11);
+
+        round_trip(&Translation::new(V2::from_xy(1, 2)), p2);
+        round_trip(&Translation::new(5), 8);
+        round_trip(&GridSymmetry::Transpose, p2);
+        round_trip(&GridSymmetry::Rotate90, V2::from_xy(0.5f32, 2.0));
+        round_trip(&CubeRotation::rotate_x(), p3);
+        round_trip(
+            &Affine2::new(Matrix::new([[2, 1], [1, 1]]), V2::from_xy(4, 5)),
+            p2,
+        );
+        round_trip(&Affine3::from_linear(CubeRotation::rotate_y().matrix()), p3);
+
+        let t = Translation::new(V2::from_xy(1, 2));
+        assert_eq!(t.compose(&t).apply(p2), V2::from_xy(5, -3));
+        assert_eq!(
+            Transform::<V2<i32>>::compose(&GridSymmetry::Rotate90, &GridSymmetry::Rotate90),
+            GridSymmetry::Rotate180
+        );
+    }
+
+    #[test]
+    fn bulk_application() {
+        let points = vec![V2::from_xy(1, 2), V2::from_xy(-3, 4), V2::from_xy(0, 0)];
+        for s in GridSymmetry::all() {
+            let mut in_place = points.clone();
+            s.apply_in_place(&mut in_place);
+            let expected: Vec<_> = points.iter().map(|p| s.apply(*p)).collect();
+            assert_eq!(in_place, expected);
+            assert_eq!(
+                Transform::apply_iter(&s, points.iter().copied()).collect::<Vec<_>>(),
+                expected
+            );
+        }
+
+        let mut moved = points.clone();
+        Translation::new(V2::from_xy(1, 1)).apply_in_place(&mut moved);
+        assert_eq!(moved[1], V2::from_xy(-2, 5));
+    }
+
+    #[test]
+    fn affine_points_and_directions() {
+        let rotate = Matrix::new([[0, -1, 0], [1, 0, 0], [0, 0, 1]]);
+        let a = Affine3::new(rotate, V3::from_xyz(10, 20, 30));
+        assert_eq!(
+            a.transform_point(V3::from_xyz(1, 0, 0)),
+            V3::from_xyz(10, 21, 30)
+        );
+        assert_eq!(
+            a.transform_direction(V3::from_xyz(1, 0, 0)),
+            V3::from_xyz(0, 1, 0)
+        );
+
+        let b = Affine3::from_translation(V3::from_xyz(1, 1, 1));
+        let p = V3::from_xyz(3, 4, 5);
+        assert_eq!(
+            a.compose(&b).transform_point(p),
+            a.transform_point(b.transform_point(p))
+        );
+        assert_eq!(Affine3::identity().compose(&a), a);
+    }
+
+    #[test]
+    fn affine_constructors() {
+        let p = V2::from_xy(2i32, 3);
+        assert_eq!(Affine2::shear_x(2).apply(p), V2::from_xy(8, 3));
+        assert_eq!(Affine2::shear_y(-1).apply(p), V2::from_xy(2, 1));
+        assert!(Affine2::shear_x(5i32).inverse().is_some());
+        assert_eq!(Affine2::scale_xy(2, -1).apply(p), V2::from_xy(4, -3));
+        assert_eq!(
+            Affine3::scale_xyz(1, 2, 3).apply(V3::from_xyz(1, 1, 1)),
+            V3::from_xyz(1, 2, 3)
+        );
+
+        let diagonal = Affine2::<i32>::reflection(V2::from_xy(3, 3)).unwrap();
+        assert_eq!(diagonal.apply(p), V2::from_xy(3, 2));
+        let anti = Affine2::<i32>::reflection(V2::from_xy(-1, 1)).unwrap();
+        assert_eq!(anti.apply(p), V2::from_xy(-3, -2));
+        let x_axis = Affine2::<i64>::reflection(V2::from_xy(4, 0)).unwrap();
+        assert_eq!(x_axis.apply(V2::from_xy(2, 3)), V2::from_xy(2, -3));
+        assert_eq!(Affine2::<i32>::reflection(V2::from_xy(2, 1)), None);
+        assert_eq!(Affine2::<i64>::reflection(V2::from_xy(0, 0)), None);
+        assert_eq!(diagonal.compose(&diagonal), Affine2::identity());
+
+        let float = Affine2::<f64>::reflection(V2::from_xy(3.0, 4.0)).unwrap();
+        assert_eq!(float.apply(V2::from_xy(3.0, 4.0)), V2::from_xy(3.0, 4.0));
+        assert_eq!(float.apply(V2::from_xy(-4.0, 3.0)), V2::from_xy(4.0, -3.0));
+        assert_eq!(Affine2::<f32>::reflection(V2::from_xy(0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn affine_inverse() {
+        let a = Affine2::new(
+            Matrix::new([[2.0f64, 1.0], [5.0, 3.0]]),
+            V2::from_xy(1.0, -2.0),
+        );
+        let inverse = a.inverse().unwrap();
+        assert_eq!(inverse.compose(&a), Affine2::identity());
+        assert_eq!(
+            inverse.transform_point(a.transform_point(V2::from_xy(3.0, 7.0))),
+            V2::from_xy(3.0, 7.0)
+        );
+
+        let b = Affine3::new(
+            Matrix::new([[0i32, 0, 1], [1, 0, 0], [0, 1, 0]]),
+            V3::from_xyz(1, 2, 3),
+        );
+        assert_eq!(b.compose(&b.inverse().unwrap()), Affine3::identity());
+        assert_eq!(
+            Affine2::from_linear(Matrix::new([[2i32, 0], [0, 1]])).inverse(),
+            None
+        );
+    }
+
+    #[test]
+    fn affine_homogeneous() {
+        let a = Affine3::new(
+            Matrix::new([[0, -1, 0], [1, 0, 0], [0, 0, 1]]),
+            V3::from_xyz(10, 20, 30),
+        );
+        let m = a.to_homogeneous();
+        assert_eq!(m * V4::from_xyzw(1, 0, 0, 1), V4::from_xyzw(10, 21, 30, 1));
+        assert_eq!(m * V4::from_xyzw(1, 0, 0, 0), V4::from_xyzw(0, 1, 0, 0));
+        assert_eq!(Affine3::from_homogeneous(m), Some(a));
+        assert_eq!(Affine3::from_homogeneous(m * 2), None);
+        assert_eq!(
+            Affine3::from_homogeneous(a.compose(&a).to_homogeneous()),
+            Affine3::from_homogeneous(m * m)
+        );
     }
 }
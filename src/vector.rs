@@ -1,6 +1,6 @@
 use std::{
     fmt::{Debug, Display},
-    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
     str::FromStr,
 };
 
@@ -11,7 +11,7 @@ use super::{
         EuclideanDistanceSquared, IterateNeighbours, ManhattanDistance, Movement4Directions,
     },
     linear_index::LinearIndex,
-    math::AbsoluteValue,
+    math::{AbsoluteValue, Bounded, CheckedOps, Float, Zero},
 };
 
 #[derive(Hash, Debug, Clone, Copy, PartialEq, Eq)]
@@ -129,6 +129,54 @@ impl<const C: usize, T> Vector<C, T> {
     pub const fn new(values: [T; C]) -> Self {
         Self { values }
     }
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.values.iter()
+    }
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.values.iter_mut()
+    }
+}
+
+// NOTE(lubo): Not imported, `index` would be ambiguous with `LinearIndex::index`.
+impl<const C: usize, T> std::ops::Index<usize> for Vector<C, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.values[index]
+    }
+}
+
+impl<const C: usize, T> std::ops::IndexMut<usize> for Vector<C, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.values[index]
+    }
+}
+
+impl<const C: usize, T> IntoIterator for Vector<C, T> {
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<'a, const C: usize, T> IntoIterator for &'a Vector<C, T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
+}
+
+impl<'a, const C: usize, T> IntoIterator for &'a mut Vector<C, T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter_mut()
+    }
 }
 
 impl<const C: usize, T> Vector<C, T>
@@ -173,7 +221,62 @@ where
         self.elementwise_binary(rhs, |a, b| a.min(b))
     }
     pub fn elementwise_max(&self, rhs: Self) -> Self {
-        self.elementwise_binary(rhs, |a, b| a.min(b))
+        self.elementwise_binary(rhs, |a, b| a.max(b))
+    }
+    pub fn elementwise_clamp(&self, min: Self, max: Self) -> Self {
+        self.elementwise_max(min).elementwise_min(max)
+    }
+}
+
+impl<const C: usize, T> Vector<C, T>
+where
+    T: Copy,
+    T: AbsoluteValue,
+{
+    /// `None` if any component overflows, e.g. `i32::MIN`.
+    pub fn elementwise_abs(&self) -> Option<Self> {
+        let mut result = self.values;
+        for x in result.iter_mut() {
+            *x = x.abs()?;
+        }
+        Some(Self::new(result))
+    }
+}
+
+impl<const C: usize, T> Vector<C, T>
+where
+    T: Copy,
+    T: PartialOrd,
+{
+    pub fn elementwise_compare<F: Fn(T, T) -> bool>(&self, rhs: Self, f: F) -> Vector<C, bool> {
+        let mut result = [false; C];
+        #[allow(clippy::needless_range_loop)]
+        for x in 0..C {
+            result[x] = f(self.values[x], rhs.values[x]);
+        }
+        Vector::new(result)
+    }
+    pub fn cmp_lt(&self, rhs: Self) -> Vector<C, bool> {
+        self.elementwise_compare(rhs, |a, b| a < b)
+    }
+    pub fn cmp_le(&self, rhs: Self) -> Vector<C, bool> {
+        self.elementwise_compare(rhs, |a, b| a <= b)
+    }
+    pub fn cmp_gt(&self, rhs: Self) -> Vector<C, bool> {
+        self.elementwise_compare(rhs, |a, b| a > b)
+    }
+    pub fn cmp_ge(&self, rhs: Self) -> Vector<C, bool> {
+        self.elementwise_compare(rhs, |a, b| a >= b)
+    }
+}
+
+// NOTE(lubo): `all` is already the splat constructor, hence `every`.
+impl<const C: usize> Vector<C, bool> {
+    pub fn any(&self) -> bool {
+        self.values.iter().any(|&x| x)
+    }
+    pub fn every(&self) -> bool {
+        self.values.iter().all(|&x| x)
     }
 }
 
@@ -191,26 +294,74 @@ where
 
 impl V2i32 {
     pub fn winding(&self, rhs: Self) -> i32 {
-        (self.x() * rhs.y()) - (self.y() * rhs.x())
+        self.wedge(rhs).signed_area()
     }
     pub fn perp(&self) -> Self {
         Self::from_xy(-self.y(), self.x())
     }
 }
 
-impl<const C: usize> Vector<C, f32> {
-    pub fn magn(&self) -> f32 {
+impl<const C: usize, T: Float> Vector<C, T> {
+    pub fn magn(&self) -> T {
         self.inner(*self).sqrt()
     }
     pub fn normalized(&self) -> Self {
         let magn = self.magn();
 
-        if magn > f32::EPSILON {
-            *self * (1.0 / magn)
+        if magn > T::epsilon() {
+            *self * (T::one() / magn)
         } else {
-            *self * 0.0
+            *self * T::zero()
         }
     }
+    /// `self` at `t = 0`, `rhs` at `t = 1`.
+    pub fn lerp(&self, rhs: Self, t: T) -> Self {
+        *self + (rhs - *self) * t
+    }
+    pub fn distance(&self, rhs: Self) -> T {
+        (rhs - *self).magn()
+    }
+}
+
+impl<const C: usize, T: Zero + Copy> Zero for Vector<C, T> {
+    fn zero() -> Self {
+        Self::all(T::zero())
+    }
+}
+
+impl<const C: usize, T: Bounded + Copy> Bounded for Vector<C, T> {
+    fn min_value() -> Self {
+        Self::all(T::min_value())
+    }
+    fn max_value() -> Self {
+        Self::all(T::max_value())
+    }
+}
+
+impl<const C: usize, T: CheckedOps + Copy> Vector<C, T> {
+    fn checked_elementwise(self, rhs: Self, f: impl Fn(T, T) -> Option<T>) -> Option<Self> {
+        let mut values = self.values;
+        for (a, b) in values.iter_mut().zip(rhs.values) {
+            *a = f(*a, b)?;
+        }
+        Some(Self::new(values))
+    }
+}
+
+/// Elementwise, `None` if any component fails.
+impl<const C: usize, T: CheckedOps + Copy> CheckedOps for Vector<C, T> {
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.checked_elementwise(rhs, T::checked_add)
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_elementwise(rhs, T::checked_sub)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.checked_elementwise(rhs, T::checked_mul)
+    }
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_elementwise(rhs, T::checked_div)
+    }
 }
 
 impl<const C: usize, T: Add<Output = T> + Copy> Add for Vector<C, T> {
@@ -281,6 +432,85 @@ impl<const C: usize, T: MulAssign + Copy> MulAssign<T> for Vector<C, T> {
     }
 }
 
+impl<const C: usize, T: Neg<Output = T> + Copy> Neg for Vector<C, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let mut values = self.values;
+
+        #[allow(clippy::needless_range_loop)]
+        for x in 0..C {
+            values[x] = -values[x];
+        }
+
+        Self::Output::new(values)
+    }
+}
+
+impl<const C: usize, T> Div<T> for Vector<C, T>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        let mut values = self.values;
+
+        #[allow(clippy::needless_range_loop)]
+        for x in 0..C {
+            values[x] = values[x] / rhs;
+        }
+
+        Self::Output::new(values)
+    }
+}
+impl<const C: usize, T: Div<Output = T> + Copy> Div for Vector<C, T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let mut values = self.values;
+
+        #[allow(clippy::needless_range_loop)]
+        for x in 0..C {
+            values[x] = self.values[x] / rhs.values[x];
+        }
+
+        Self::Output::new(values)
+    }
+}
+
+impl<const C: usize, T> Rem<T> for Vector<C, T>
+where
+    T: Rem<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        let mut values = self.values;
+
+        #[allow(clippy::needless_range_loop)]
+        for x in 0..C {
+            values[x] = values[x] % rhs;
+        }
+
+        Self::Output::new(values)
+    }
+}
+impl<const C: usize, T: Rem<Output = T> + Copy> Rem for Vector<C, T> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        let mut values = self.values;
+
+        #[allow(clippy::needless_range_loop)]
+        for x in 0..C {
+            values[x] = self.values[x] % rhs.values[x];
+        }
+
+        Self::Output::new(values)
+    }
+}
+
 impl<const C: usize, T> ModularDecompose<Vector<C, T>> for Vector<C, T>
 where
     T: ModularDecompose<T> + Copy,
@@ -298,6 +528,22 @@ where
     }
 }
 
+impl<const C: usize, T> Vector<C, T>
+where
+    T: ModularDecompose<T> + Copy,
+{
+    /// Componentwise residues, always non-negative for positive `n`.
+    pub fn rem_euclid(&self, n: Self) -> Self {
+        self.elementwise_binary(n, |a, n| a.mod_n(n))
+    }
+
+    /// Wrap a position onto a torus of size `dims`, every coordinate ends up in `0..dims`.
+    /// Same as [`Vector::rem_euclid`], named for the toroidal grid use.
+    pub fn wrap_to(&self, dims: Self) -> Self {
+        self.rem_euclid(dims)
+    }
+}
+
 pub struct Scalar<T> {
     pub value: T,
 }
@@ -422,10 +668,9 @@ impl<const N: usize> LinearIndex<Self> for Vector<N, $t> {
     fn index_unchecked(&self, i: Self) -> Option<usize> {
         let mut result: usize = 0;
         for j in (0..N).rev() {
-            let a: usize = self.values[j].try_into().unwrap();
-            result *= a;
-            let b: usize = i.values[j].try_into().unwrap();
-            result += b;
+            let a: usize = self.values[j].try_into().ok()?;
+            let b: usize = i.values[j].try_into().ok()?;
+            result = result.checked_mul(a)?.checked_add(b)?;
         }
         Some(result)
     }
@@ -433,15 +678,17 @@ impl<const N: usize> LinearIndex<Self> for Vector<N, $t> {
     fn unindex(&self, mut i: usize) -> Option<Self> {
         let mut result = Vector::new([0; N]);
         for j in 0..N {
-            result.values[j] = (i % self.values[j] as usize).try_into().unwrap();
-            i /= self.values[j] as usize;
+            let a: usize = self.values[j].try_into().ok()?;
+            result.values[j] = i.checked_rem(a)?.try_into().ok()?;
+            i /= a;
         }
         Some(result)
     }
 
-    unsafe fn cardinality(&self) -> Option<usize> {
-        let product: $t = self.values.iter().product();
-        Some(product as usize)
+    fn checked_cardinality(&self) -> Option<usize> {
+        self.values
+            .iter()
+            .try_fold(1usize, |acc, &a| acc.checked_mul(a.try_into().ok()?))
     }
 
     #[allow(unused_comparisons)]
@@ -513,6 +760,12 @@ impl<T: Copy> V2<T> {
     pub fn y(&self) -> T {
         self.values[1]
     }
+    pub fn yx(&self) -> V2<T> {
+        V2::from_xy(self.y(), self.x())
+    }
+    pub fn extend(&self, z: T) -> V3<T> {
+        V3::from_xyz(self.x(), self.y(), z)
+    }
 }
 
 macro_rules! basis_vectors {
@@ -580,6 +833,31 @@ impl<T: Copy> V3<T> {
     pub fn z(&self) -> T {
         self.values[2]
     }
+    pub fn xy(&self) -> V2<T> {
+        V2::from_xy(self.x(), self.y())
+    }
+    pub fn xz(&self) -> V2<T> {
+        V2::from_xy(self.x(), self.z())
+    }
+    pub fn yz(&self) -> V2<T> {
+        V2::from_xy(self.y(), self.z())
+    }
+    pub fn yx(&self) -> V2<T> {
+        V2::from_xy(self.y(), self.x())
+    }
+    pub fn zx(&self) -> V2<T> {
+        V2::from_xy(self.z(), self.x())
+    }
+    pub fn zy(&self) -> V2<T> {
+        V2::from_xy(self.z(), self.y())
+    }
+    pub fn extend(&self, w: T) -> V4<T> {
+        V4::from_xyzw(self.x(), self.y(), self.z(), w)
+    }
+    /// Drops `z`.
+    pub fn truncate(&self) -> V2<T> {
+        self.xy()
+    }
 }
 
 impl<T: Copy> V4<T> {
@@ -600,12 +878,124 @@ impl<T: Copy> V4<T> {
     pub fn w(&self) -> T {
         self.values[3]
     }
+    pub fn xy(&self) -> V2<T> {
+        V2::from_xy(self.x(), self.y())
+    }
+    pub fn xyz(&self) -> V3<T> {
+        V3::from_xyz(self.x(), self.y(), self.z())
+    }
+    /// Drops `w`.
+    pub fn truncate(&self) -> V3<T> {
+        self.xyz()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{linear_index::LinearIndex, modular::ModularDecompose};
+    use crate::{
+        linear_index::LinearIndex,
+        modular::ModularDecompose,
+        ord_float::{OrdF32, OrdF64},
+    };
+
+    #[test]
+    fn checked_ops() {
+        let a = V2::from_xy(100u8, 3);
+        assert_eq!(a.checked_add(V2::from_xy(55, 1)), Some(V2::from_xy(155, 4)));
+        assert_eq!(a.checked_add(V2::from_xy(156, 1)), None);
+        assert_eq!(a.checked_div(V2::from_xy(10, 0)), None);
+        assert_eq!(V3::<i8>::max_value(), V3::all(127));
+    }
+
+    #[test]
+    fn elementwise_comparisons() {
+        let a = V3::from_xyz(1, -5, 7);
+        let b = V3::from_xyz(2, -6, 7);
+        assert_eq!(a.elementwise_min(b), V3::from_xyz(1, -6, 7));
+        assert_eq!(a.elementwise_max(b), V3::from_xyz(2, -5, 7));
+        assert_eq!(
+            a.elementwise_clamp(V3::all(0), V3::all(5)),
+            V3::from_xyz(1, 0, 5)
+        );
+        assert_eq!(a.elementwise_abs(), Some(V3::from_xyz(1, 5, 7)));
+        assert_eq!(V2::from_xy(i32::MIN, 0).elementwise_abs(), None);
+        assert_eq!(a.cmp_lt(b), V3::from_xyz(true, false, false));
+        assert_eq!(a.cmp_le(b), V3::from_xyz(true, false, true));
+        assert_eq!(a.cmp_gt(b), V3::from_xyz(false, true, false));
+        assert_eq!(a.cmp_ge(b), V3::from_xyz(false, true, true));
+        assert!(a.cmp_le(b).any());
+        assert!(!a.cmp_le(b).every());
+        assert!(a.cmp_ge(V3::all(-5)).every());
+    }
+
+    #[test]
+    fn float_magnitude() {
+        let a = V2::from_xy(3.0f64, 4.0);
+        assert_eq!(a.magn(), 5.0);
+        assert!((a.normalized().magn() - 1.0).abs() < 1e-12);
+        assert_eq!(V2::from_xy(0.0f64, 0.0).normalized(), V2::from_xy(0.0, 0.0));
+        assert_eq!(a.lerp(V2::from_xy(5.0, 0.0), 0.5), V2::from_xy(4.0, 2.0));
+        assert_eq!(a.distance(V2::from_xy(0.0, 0.0)), 5.0);
+        assert_eq!(
+            V3::from_xyz(0.0f32, 2.0, 0.0).normalized(),
+            V3::from_xyz(0.0, 1.0, 0.0)
+        );
+
+        let b = V2::from_xy(OrdF64(6.0), OrdF64(8.0));
+        assert_eq!(b.magn(), OrdF64(10.0));
+        assert_eq!(
+            b.distance(V2::from_xy(OrdF64(3.0), OrdF64(4.0))),
+            OrdF64(5.0)
+        );
+        assert_eq!(
+            V2::from_xy(OrdF32(0.0), OrdF32(-2.0)).normalized().y(),
+            OrdF32(-1.0)
+        );
+    }
+
+    #[test]
+    fn index_and_iterate() {
+        let mut a = V3::from_xyz(1, 2, 3);
+        assert_eq!(a[0], 1);
+        assert_eq!(a[2], 3);
+        a[1] = 5;
+        assert_eq!(a, V3::from_xyz(1, 5, 3));
+
+        for x in &mut a {
+            *x *= 2;
+        }
+        a.iter_mut().for_each(|x| *x += 1);
+        assert_eq!(a.iter().sum::<i32>(), 21);
+        assert_eq!((&a).into_iter().max(), Some(&11));
+        assert_eq!(a.into_iter().collect::<Vec<_>>(), vec![3, 11, 7]);
+    }
+
+    #[test]
+    fn neg_div_rem() {
+        let a = V3::from_xyz(7, -9, 12);
+        assert_eq!(-a, V3::from_xyz(-7, 9, -12));
+        assert_eq!(a / 2, V3::from_xyz(3, -4, 6));
+        assert_eq!(a % 5, V3::from_xyz(2, -4, 2));
+        assert_eq!(a / V3::from_xyz(7, 3, -4), V3::from_xyz(1, -3, -3));
+        assert_eq!(a % V3::from_xyz(4, 4, 5), V3::from_xyz(3, -1, 2));
+        assert_eq!(V2::from_xy(1.0, -3.0) / 2.0, V2::from_xy(0.5, -1.5));
+    }
+
+    #[test]
+    fn swizzles() {
+        let a = V3::from_xyz(1, 2, 3);
+        assert_eq!(a.xy(), V2::from_xy(1, 2));
+        assert_eq!(a.xz(), V2::from_xy(1, 3));
+        assert_eq!(a.yz(), V2::from_xy(2, 3));
+        assert_eq!(a.zy(), V2::from_xy(3, 2));
+        assert_eq!(a.xy().yx(), V2::from_xy(2, 1));
+        assert_eq!(a.truncate().extend(7), V3::from_xyz(1, 2, 7));
+        let b = a.extend(4);
+        assert_eq!(b, V4::from_xyzw(1, 2, 3, 4));
+        assert_eq!(b.truncate(), a);
+        assert_eq!(b.xy(), V2::from_xy(1, 2));
+    }
 
     #[test]
     fn v3_eq() {
@@ -628,6 +1018,17 @@ mod tests {
         let pixel_index = 4 * 8 + 4;
         assert_eq!(Some(pixel), bitmap.unindex(pixel_index));
         assert_eq!(Some(pixel_index), bitmap.index(pixel));
+        assert_eq!(Some(64), bitmap.checked_cardinality());
+    }
+
+    #[test]
+    fn linear_index_overflow() {
+        let huge = V3::from_xyz(u64::MAX, u64::MAX, 2);
+        assert_eq!(None, huge.checked_cardinality());
+        assert_eq!(None, huge.index(V3::from_xyz(1, 1, 1)));
+        assert_eq!(None, V2::from_xy(-3, 4).checked_cardinality());
+        assert_eq!(None, V2::from_xy(-3, 4).index_unchecked(V2::from_xy(1, 1)));
+        assert_eq!(None, V2::from_xy(0, 4).unindex(3));
     }
 
     #[test]
@@ -648,6 +1049,32 @@ mod tests {
         assert_eq!(V2::from_xy(1, 1), a_residue);
     }
 
+    #[test]
+    fn rem_euclid() {
+        let dims = V2::from_xy(5, 3);
+        assert_eq!(V2::from_xy(-1, 7).rem_euclid(dims), V2::from_xy(4, 1));
+        assert_eq!(V2::from_xy(-10, -3).rem_euclid(dims), V2::from_xy(0, 0));
+        assert_eq!(
+            V3::from_xyz(-1i64, -6, 13).rem_euclid(V3::all(6)),
+            V3::from_xyz(5, 0, 1)
+        );
+        assert_eq!(
+            Vector::new([7usize, 2]).rem_euclid(Vector::new([4, 4])),
+            Vector::new([3, 2])
+        );
+    }
+
+    #[test]
+    fn wrap_to() {
+        let dims = V2::from_xy(5, 3);
+        assert_eq!(V2::from_xy(4, 2).wrap_to(dims), V2::from_xy(4, 2));
+        assert_eq!(V2::from_xy(-1, 3).wrap_to(dims), V2::from_xy(4, 0));
+        assert_eq!(V2::from_xy(-11, 8).wrap_to(dims), V2::from_xy(4, 2));
+        for p in [V2::from_xy(-7, 13), V2::from_xy(i32::MIN, i32::MAX)] {
+            assert_eq!(p.wrap_to(dims), p.rem_euclid(dims));
+        }
+    }
+
     #[test]
     fn v3_modular_decompose3() {
         let a = V2::from_xy(-1, 0);
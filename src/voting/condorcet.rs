@@ -0,0 +1,125 @@
+use super::{Ballot, BallotError};
+
+/// `matrix[a][b]` is the weight of ballots ranking candidate `a` above candidate `b`.
+pub fn pairwise_matrix(
+    candidates: usize,
+    ballots: &[Ballot],
+) -> Result<Vec<Vec<u64>>, BallotError> {
+    let mut matrix = vec![vec![0; candidates]; candidates];
+    for ballot in ballots {
+        ballot.validate(candidates)?;
+        let mut rank = vec![usize::MAX; candidates];
+        for (r, &c) in ballot.ranking.iter().enumerate() {
+            rank[c] = r;
+        }
+        for a in 0..candidates {
+            for b in 0..candidates {
+                if rank[a] < rank[b] {
+                    matrix[a][b] += ballot.weight;
+                }
+            }
+        }
+    }
+    Ok(matrix)
+}
+
+/// Candidate beating every other one head to head, if there is one.
+pub fn condorcet_winner(matrix: &[Vec<u64>]) -> Option<usize> {
+    (0..matrix.len()).find(|&a| (0..matrix.len()).all(|b| a == b || matrix[a][b] > matrix[b][a]))
+}
+
+/// Strength of the strongest path from `a` to `b`, where a path is as strong as its weakest
+/// pairwise win (Floyd–Warshall over widest paths).
+pub fn strongest_paths(matrix: &[Vec<u64>]) -> Vec<Vec<u64>> {
+    let n = matrix.len();
+    let mut p = vec![vec![0; n]; n];
+    for a in 0..n {
+        for b in 0..n {
+            if a != b && matrix[a][b] > matrix[b][a] {
+                p[a][b] = matrix[a][b];
+            }
+        }
+    }
+    for k in 0..n {
+        for a in 0..n {
+            for b in 0..n {
+                if a != b && a != k && b != k {
+                    p[a][b] = p[a][b].max(p[a][k].min(p[k][b]));
+                }
+            }
+        }
+    }
+    p
+}
+
+/// Winners by the Schulze method, those no other candidate beats along a stronger path.
+///
+/// Candidates are `0..=` the highest one ranked on any ballot. The winner is unique unless the
+/// ballots are tied, the Condorcet winner wins whenever there is one.
+pub fn schulze(ballots: &[Ballot]) -> Result<Vec<usize>, BallotError> {
+    let candidates = ballots
+        .iter()
+        .flat_map(|b| b.ranking.iter())
+        .max()
+        .map_or(0, |&c| c + 1);
+    let paths = strongest_paths(&pairwise_matrix(candidates, ballots)?);
+    Ok((0..candidates)
+        .filter(|&a| (0..candidates).all(|b| paths[a][b] >= paths[b][a]))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // NOTE(lubo): The example from Schulze's paper, candidates A to E.
+    fn wikipedia_ballots() -> Vec<Ballot> {
+        [
+            (5, [0, 2, 1, 4, 3]),
+            (5, [0, 3, 4, 2, 1]),
+            (8, [1, 4, 3, 0, 2]),
+            (3, [2, 0, 1, 4, 3]),
+            (7, [2, 0, 4, 1, 3]),
+            (2, [2, 1, 0, 3, 4]),
+            (7, [3, 2, 4, 1, 0]),
+            (8, [4, 1, 0, 3, 2]),
+        ]
+        .into_iter()
+        .map(|(w, r)| Ballot::with_weight(r.to_vec(), w))
+        .collect()
+    }
+
+    #[test]
+    fn pairwise() {
+        let matrix = pairwise_matrix(5, &wikipedia_ballots()).unwrap();
+        assert_eq!(matrix[0], vec![0, 20, 26, 30, 22]);
+        assert_eq!(matrix[3], vec![15, 12, 28, 0, 14]);
+        assert_eq!(condorcet_winner(&matrix), None);
+
+        let paths = strongest_paths(&matrix);
+        assert_eq!(paths[4], vec![25, 28, 28, 31, 0]);
+        assert_eq!(paths[0], vec![0, 28, 28, 30, 24]);
+    }
+
+    #[test]
+    fn schulze_winner() {
+        assert_eq!(schulze(&wikipedia_ballots()), Ok(vec![4]));
+
+        let ballots = [
+            Ballot::with_weight(vec![1, 0, 2], 3),
+            Ballot::with_weight(vec![1, 2], 2),
+            Ballot::with_weight(vec![0, 2], 2),
+        ];
+        let matrix = pairwise_matrix(3, &ballots).unwrap();
+        assert_eq!(condorcet_winner(&matrix), Some(1));
+        assert_eq!(schulze(&ballots), Ok(vec![1]));
+
+        let tied = [Ballot::new(vec![0, 1]), Ballot::new(vec![1, 0])];
+        assert_eq!(schulze(&tied), Ok(vec![0, 1]));
+        assert_eq!(schulze(&[]), Ok(vec![]));
+        assert_eq!(
+            schulze(&[Ballot::new(vec![0, 0])]),
+            Err(BallotError::Duplicate(0))
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use std::fmt::Display;
+
+use super::{Ballot, BallotError};
+
+/// How to pick the candidate to eliminate when several share the lowest tally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TieBreak {
+    /// Eliminate the tied candidate with the lowest index.
+    #[default]
+    Lowest,
+    /// Eliminate the tied candidate with the highest index.
+    Highest,
+    /// Eliminate the tied candidate with the fewest votes in the latest earlier round where the
+    /// tied candidates differ, falling back to [`TieBreak::Lowest`].
+    Backwards,
+}
+
+/// One counting round, `tallies[c]` is `None` once candidate `c` has been eliminated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IrvRound {
+    pub tallies: Vec<Option<u64>>,
+    /// Weight of ballots with no continuing candidate left.
+    pub exhausted: u64,
+    pub eliminated: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IrvReport {
+    pub rounds: Vec<IrvRound>,
+    /// `None` only when there are no candidates.
+    pub winner: Option<usize>,
+}
+
+/// Instant-runoff tally of ranked `ballots` over candidates `0..candidates`.
+///
+/// Each round every ballot counts for its highest ranked continuing candidate. A candidate with a
+/// strict majority of the non-exhausted ballots (or the last one left) wins, otherwise the one
+/// with the fewest votes is eliminated, ties resolved by `tie_break`.
+pub fn instant_runoff(
+    candidates: usize,
+    ballots: &[Ballot],
+    tie_break: TieBreak,
+) -> Result<IrvReport, BallotError> {
+    for ballot in ballots {
+        ballot.validate(candidates)?;
+    }
+
+    let mut continuing = vec![true; candidates];
+    let mut rounds: Vec<IrvRound> = vec![];
+    loop {
+        let mut tallies: Vec<Option<u64>> = continuing
+            .iter()
+            .map(|&c| if c { Some(0) } else { None })
+            .collect();
+        let mut exhausted = 0;
+        for ballot in ballots {
+            match ballot.ranking.iter().find(|&&c| continuing[c]) {
+                Some(&c) => *tallies[c].as_mut().unwrap() += ballot.weight,
+                None => exhausted += ballot.weight,
+            }
+        }
+
+        let active: u64 = tallies.iter().flatten().sum();
+        let left = continuing.iter().filter(|&&c| c).count();
+        let leader = (0..candidates)
+            .filter(|&c| continuing[c])
+            .max_by_key(|&c| (tallies[c], std::cmp::Reverse(c)));
+        let winner = match leader {
+            None => Some(None),
+            Some(c) if left == 1 || tallies[c].unwrap() * 2 > active => Some(Some(c)),
+            Some(_) => None,
+        };
+        if let Some(winner) = winner {
+            rounds.push(IrvRound {
+                tallies,
+                exhausted,
+                eliminated: None,
+            });
+            return Ok(IrvReport { rounds, winner });
+        }
+
+        let lowest = tallies.iter().flatten().min().copied();
+        let tied: Vec<usize> = (0..candidates)
+            .filter(|&c| tallies[c].is_some() && tallies[c] == lowest)
+            .collect();
+        let eliminated = break_tie(&tied, &rounds, tie_break);
+        continuing[eliminated] = false;
+        rounds.push(IrvRound {
+            tallies,
+            exhausted,
+            eliminated: Some(eliminated),
+        });
+    }
+}
+
+fn break_tie(tied: &[usize], rounds: &[IrvRound], tie_break: TieBreak) -> usize {
+    match tie_break {
+        TieBreak::Lowest => tied[0],
+        TieBreak::Highest => tied[tied.len() - 1],
+        TieBreak::Backwards => {
+            for round in rounds.iter().rev() {
+                let fewest = tied.iter().map(|&c| round.tallies[c]).min().unwrap();
+                let still_tied: Vec<usize> = tied
+                    .iter()
+                    .copied()
+                    .filter(|&c| round.tallies[c] == fewest)
+                    .collect();
+                if still_tied.len() < tied.len() {
+                    return break_tie(&still_tied, rounds, TieBreak::Lowest);
+                }
+            }
+            tied[0]
+        }
+    }
+}
+
+impl Display for IrvReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, round) in self.rounds.iter().enumerate() {
+            write!(f, "Round {}:", i + 1)?;
+            for (c, tally) in round.tallies.iter().enumerate() {
+                if let Some(tally) = tally {
+                    write!(f, " {c}={tally}")?;
+                }
+            }
+            if round.exhausted > 0 {
+                write!(f, ", exhausted {}", round.exhausted)?;
+            }
+            if let Some(c) = round.eliminated {
+                write!(f, ", eliminated {c}")?;
+            }
+            writeln!(f)?;
+        }
+        match self.winner {
+            Some(c) => writeln!(f, "Winner: {c}"),
+            None => writeln!(f, "No winner"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runoff() {
+        let ballots = vec![
+            Ballot::with_weight(vec![0, 1], 8),
+            Ballot::with_weight(vec![1, 0], 5),
+            Ballot::with_weight(vec![2, 1], 4),
+            Ballot::with_weight(vec![3], 2),
+        ];
+        let report = instant_runoff(4, &ballots, TieBreak::Lowest).unwrap();
+        assert_eq!(report.winner, Some(1));
+        assert_eq!(
+            report.to_string(),
+            "Round 1: 0=8 1=5 2=4 3=2, eliminated 3\n\
+             Round 2: 0=8 1=5 2=4, exhausted 2, eliminated 2\n\
+             Round 3: 0=8 1=9, exhausted 2\n\
+             Winner: 1\n"
+        );
+    }
+
+    #[test]
+    fn ties() {
+        let ballots = vec![
+            Ballot::with_weight(vec![0], 4),
+            Ballot::with_weight(vec![1, 0], 3),
+            Ballot::with_weight(vec![2, 1], 2),
+            Ballot::with_weight(vec![3, 2], 1),
+        ];
+        // NOTE(lubo): Once 3 is out, 1 and 2 are tied at 3, but 1 led the round before.
+        let lowest = instant_runoff(4, &ballots, TieBreak::Lowest).unwrap();
+        assert_eq!(lowest.rounds[1].eliminated, Some(1));
+        assert_eq!(lowest.winner, Some(0));
+        let highest = instant_runoff(4, &ballots, TieBreak::Highest).unwrap();
+        assert_eq!(highest.rounds[1].eliminated, Some(2));
+        assert_eq!(highest.winner, Some(1));
+        let backwards = instant_runoff(4, &ballots, TieBreak::Backwards).unwrap();
+        assert_eq!(backwards.rounds[1].eliminated, Some(2));
+        assert_eq!(backwards.winner, Some(1));
+
+        let ballots = [Ballot::new(vec![1]), Ballot::new(vec![0])];
+        let report = instant_runoff(3, &ballots, TieBreak::Backwards).unwrap();
+        assert_eq!(report.rounds[0].eliminated, Some(2));
+        assert_eq!(report.rounds[1].eliminated, Some(0));
+        assert_eq!(report.winner, Some(1));
+    }
+
+    #[test]
+    fn invalid_ballots() {
+        let ballot = Ballot::new(vec![0, 3]);
+        assert_eq!(
+            instant_runoff(3, &[ballot], TieBreak::Lowest),
+            Err(BallotError::UnknownCandidate(3))
+        );
+        let ballot = Ballot::new(vec![1, 0, 1]);
+        assert_eq!(ballot.validate(2), Err(BallotError::Duplicate(1)));
+        assert_eq!(
+            instant_runoff(0, &[], TieBreak::Lowest).unwrap().winner,
+            None
+        );
+    }
+}
@@ -0,0 +1,84 @@
+//! Disproportionality of an apportionment. Votes and seats are given as per party totals (or
+//! shares) and normalized first, indices come out as fractions, `0.05` is 5 percentage points.
+
+fn normalized(shares: &[f64]) -> Vec<f64> {
+    let total: f64 = shares.iter().sum();
+    if total == 0.0 {
+        return vec![0.0; shares.len()];
+    }
+    shares.iter().map(|x| x / total).collect()
+}
+
+fn differences(votes: &[f64], seats: &[f64]) -> impl Iterator<Item = f64> {
+    assert_eq!(votes.len(), seats.len());
+    normalized(votes)
+        .into_iter()
+        .zip(normalized(seats))
+        .map(|(v, s)| v - s)
+}
+
+/// Gallagher least-squares index, `sqrt(sum((v - s)^2) / 2)`.
+pub fn gallagher(votes: &[f64], seats: &[f64]) -> f64 {
+    (differences(votes, seats).map(|d| d * d).sum::<f64>() / 2.0).sqrt()
+}
+
+/// Loosemore–Hanby index, `sum(|v - s|) / 2`.
+pub fn loosemore_hanby(votes: &[f64], seats: &[f64]) -> f64 {
+    differences(votes, seats).map(f64::abs).sum::<f64>() / 2.0
+}
+
+/// Laakso–Taagepera effective number of parties, `1 / sum(p^2)`. Zero for no shares.
+pub fn effective_number_of_parties(shares: &[f64]) -> f64 {
+    let concentration: f64 = normalized(shares).iter().map(|p| p * p).sum();
+    if concentration == 0.0 {
+        0.0
+    } else {
+        1.0 / concentration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::voting::seats_per;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn indices() {
+        let votes = [40.0, 35.0, 25.0];
+        let seats = [5.0, 3.0, 2.0];
+        assert!(close(loosemore_hanby(&votes, &seats), 0.1));
+        assert!(close(gallagher(&votes, &seats), (0.015f64 / 2.0).sqrt()));
+        assert!(close(gallagher(&votes, &[4.0, 3.5, 2.5]), 0.0));
+        assert!(close(loosemore_hanby(&[1.0, 0.0], &[0.0, 1.0]), 1.0));
+    }
+
+    #[test]
+    fn effective_parties() {
+        assert!(close(
+            effective_number_of_parties(&[1.0, 1.0, 1.0, 1.0]),
+            4.0
+        ));
+        assert!(close(effective_number_of_parties(&[7.0]), 1.0));
+        assert!(close(
+            effective_number_of_parties(&[0.5, 0.3, 0.2]),
+            1.0 / 0.38
+        ));
+        assert_eq!(effective_number_of_parties(&[]), 0.0);
+    }
+
+    #[test]
+    fn compare_apportionment() {
+        let votes = [1.0, 2.0, 4.0];
+        let seats: Vec<f64> = seats_per(5, votes.iter().map(|&v| v as f32).collect())
+            .into_iter()
+            .map(|s| s as f64)
+            .collect();
+        let naive = [0.0, 1.0, 4.0];
+        assert!(gallagher(&votes, &seats) < gallagher(&votes, &naive));
+        assert!(loosemore_hanby(&votes, &seats) < loosemore_hanby(&votes, &naive));
+    }
+}
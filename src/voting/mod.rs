@@ -0,0 +1,59 @@
+pub mod condorcet;
+pub mod irv;
+pub mod metrics;
+pub mod shares;
+
+pub use irv::{IrvReport, IrvRound, TieBreak, instant_runoff};
+pub use shares::{seats_per, winner};
+
+use std::fmt::Display;
+
+/// Ranked ballot, candidates `0..candidates` in order of preference, best first.
+///
+/// Candidates left off the ballot are ranked below every listed one. `weight` counts identical
+/// ballots.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ballot {
+    pub ranking: Vec<usize>,
+    pub weight: u64,
+}
+
+impl Ballot {
+    pub fn new(ranking: Vec<usize>) -> Self {
+        Self { ranking, weight: 1 }
+    }
+
+    pub fn with_weight(ranking: Vec<usize>, weight: u64) -> Self {
+        Self { ranking, weight }
+    }
+
+    /// Every ranked candidate is below `candidates` and listed once.
+    pub fn validate(&self, candidates: usize) -> Result<(), BallotError> {
+        let mut seen = vec![false; candidates];
+        for &c in &self.ranking {
+            match seen.get_mut(c) {
+                None => return Err(BallotError::UnknownCandidate(c)),
+                Some(true) => return Err(BallotError::Duplicate(c)),
+                Some(s) => *s = true,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BallotError {
+    UnknownCandidate(usize),
+    Duplicate(usize),
+}
+
+impl Display for BallotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BallotError::UnknownCandidate(c) => write!(f, "Candidate {c} is not running."),
+            BallotError::Duplicate(c) => write!(f, "Candidate {c} is ranked more than once."),
+        }
+    }
+}
+
+impl std::error::Error for BallotError {}
@@ -0,0 +1,43 @@
+pub fn winner(a: f32, b: f32) -> bool {
+    a > b
+}
+
+/// Apportion `seats` proportionally to `shares` by the highest averages (D'Hondt) method.
+///
+/// Seats are handed out one by one to the option with the best `share / (seats + 1)`, ties go to
+/// the option listed first.
+pub fn seats_per(seats: usize, shares: Vec<f32>) -> Vec<usize> {
+    let mut seats_iterative: Vec<usize> = shares.iter().map(|_| 0).collect();
+    if shares.is_empty() {
+        return seats_iterative;
+    }
+
+    for _ in 0..seats {
+        let mut best_option = 0;
+        let mut best_score = f32::NEG_INFINITY;
+        for (option_id, share) in shares.iter().enumerate() {
+            let score = share / (seats_iterative[option_id] + 1) as f32;
+            if score > best_score {
+                best_option = option_id;
+                best_score = score;
+            }
+        }
+        seats_iterative[best_option] += 1;
+    }
+
+    seats_iterative
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seats_per_test() {
+        let seats = 5;
+        let seats_per = seats_per(5, vec![1.0, 2.0, 4.0]);
+        assert_eq!(seats_per.iter().sum::<usize>(), seats);
+        assert_eq!(seats_per, vec![1, 1, 3]);
+        assert!(winner(2.0, 1.0));
+    }
+}